@@ -17,7 +17,15 @@ use esp_hal::{
     prelude::*,
     system::SystemControl,
 };
-use lilygo_epd47::{pin_config, Battery, Display, DrawMode};
+use lilygo_epd47::{
+    pin_config,
+    Battery,
+    BatteryHistory,
+    Display,
+    DisplayConfig,
+    DrawMode,
+    PowerMode,
+};
 use u8g2_fonts::FontRenderer;
 
 static FONT: FontRenderer = FontRenderer::new::<u8g2_fonts::fonts::u8g2_font_spleen32x64_mr>();
@@ -40,19 +48,30 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let mut battery = Battery::new(io.pins.gpio14, peripherals.ADC2);
+    let mut history: BatteryHistory<32> = BatteryHistory::new();
 
     let delay = Delay::new(&clocks);
 
-    display.power_on();
-    delay.delay_millis(10);
+    // power the display on only while updating the screen
+    display.set_power_mode(PowerMode::Auto);
 
     loop {
         display.clear().unwrap();
+        let timestamp = esp_hal::time::current_time()
+            .duration_since_epoch()
+            .to_secs() as u32;
+        let voltage = battery.record(&mut history, timestamp);
         FONT.render_aligned(
-            format_args!("Voltage: {}V", battery.read()),
+            format_args!(
+                "Voltage: {}V ({:?})",
+                voltage,
+                history.power_status().source
+            ),
             Point::new(
                 display.bounding_box().center().x,
                 display.bounding_box().center().y,
@@ -66,6 +85,23 @@ fn main() -> ! {
             &mut display,
         )
         .unwrap();
+        if let Some(estimate) = history.estimate() {
+            FONT.render_aligned(
+                format_args!("{}", estimate),
+                Point::new(
+                    display.bounding_box().center().x,
+                    display.bounding_box().center().y + 80,
+                ),
+                u8g2_fonts::types::VerticalPosition::Baseline,
+                u8g2_fonts::types::HorizontalAlignment::Center,
+                u8g2_fonts::types::FontColor::WithBackground {
+                    fg: Gray4::BLACK,
+                    bg: Gray4::WHITE,
+                },
+                &mut display,
+            )
+            .unwrap();
+        }
 
         display.flush(DrawMode::BlackOnWhite).unwrap();
         delay.delay_millis(5000);
@@ -17,7 +17,7 @@ use esp_hal::{
     prelude::*,
     system::SystemControl,
 };
-use lilygo_epd47::{Display, DrawMode, PinConfig};
+use lilygo_epd47::{refresh::RefreshPolicy, Display, DisplayConfig, DrawMode, PinConfig};
 use u8g2_fonts::FontRenderer;
 
 static FONT: FontRenderer = FontRenderer::new::<u8g2_fonts::fonts::u8g2_font_spleen32x64_mr>();
@@ -55,7 +55,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let delay = Delay::new(&clocks);
 
@@ -63,6 +65,8 @@ fn main() -> ! {
     display.power_on();
     delay.delay_millis(10);
     display.clear().unwrap();
+    // Refresh the area of the counter every 30s to get rid of ghosting
+    display.set_refresh_policy(RefreshPolicy::NEVER.with_region_limit(60));
 
     let mut counter = 0;
     loop {
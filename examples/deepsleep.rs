@@ -4,7 +4,11 @@
 extern crate alloc;
 extern crate lilygo_epd47;
 
-use core::{format_args, time::Duration};
+use core::{
+    format_args,
+    ptr::{addr_of, addr_of_mut},
+    time::Duration,
+};
 
 use embedded_graphics::prelude::*;
 use embedded_graphics_core::{
@@ -28,7 +32,7 @@ use esp_hal::{
     system::SystemControl,
     Cpu,
 };
-use lilygo_epd47::{pin_config, Display, DrawMode};
+use lilygo_epd47::{pin_config, refresh::RefreshPolicy, Display, DisplayConfig, DrawMode};
 use u8g2_fonts::FontRenderer;
 
 static FONT: FontRenderer = FontRenderer::new::<u8g2_fonts::fonts::u8g2_font_spleen16x32_mr>();
@@ -45,6 +49,17 @@ static mut LAST_RECT: Rectangle = Rectangle {
     },
 };
 
+/// Snapshot of the display state, see `Display::snapshot`
+#[ram(rtc_fast)]
+static mut SNAPSHOT: [u8; 4096] = [0; 4096];
+
+#[ram(rtc_fast)]
+static mut SNAPSHOT_LEN: usize = 0;
+
+/// RTC time (in microseconds) the snapshot has been taken at
+#[ram(rtc_fast)]
+static mut SNAPSHOT_TIME: u64 = 0;
+
 #[entry]
 fn main() -> ! {
     esp_println::logger::init_logger_from_env();
@@ -63,7 +78,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let mut delay = Delay::new(&clocks);
     let mut rtc = Rtc::new(peripherals.LPWR, None);
@@ -74,11 +91,16 @@ fn main() -> ! {
     // turn screen on
     display.power_on();
     delay.delay_millis(20);
-    // clear
+    // fully refresh the screen after 8 partial flushes (every 4th cycle), the
+    // counters are part of the snapshot
+    display.set_refresh_policy(RefreshPolicy::after_flushes(8));
     let cycle = unsafe { CYCLE };
     let last_rect = unsafe { LAST_RECT };
+    let snapshot = unsafe { &(*addr_of!(SNAPSHOT))[..SNAPSHOT_LEN] };
+    // the RTC keeps running in deep sleep
+    let asleep = Duration::from_micros(rtc.get_time_us().saturating_sub(unsafe { SNAPSHOT_TIME }));
 
-    if cycle > 0 && cycle % 5 != 0 {
+    if cycle > 0 && display.restore(snapshot, asleep).is_ok() {
         display.fill_solid(&last_rect, Gray4::WHITE).unwrap();
         display.flush(DrawMode::WhiteOnBlack).unwrap();
     } else {
@@ -113,7 +135,20 @@ fn main() -> ! {
     display.flush(DrawMode::BlackOnWhite).unwrap();
     // turn screen off
     display.power_off();
+    // keep the display state, the screen is cleared on the next wake if it
+    // does not fit
+    let snapshot = display.snapshot();
+    let buffer = unsafe { &mut *addr_of_mut!(SNAPSHOT) };
+    let snapshot_len = match snapshot.len() <= buffer.len() {
+        true => {
+            buffer[..snapshot.len()].copy_from_slice(&snapshot);
+            snapshot.len()
+        }
+        false => 0,
+    };
     unsafe {
+        SNAPSHOT_LEN = snapshot_len;
+        SNAPSHOT_TIME = rtc.get_time_us();
         if let Some(rect) = rect {
             LAST_RECT = rect;
         }
@@ -20,7 +20,7 @@ use esp_hal::{
     prelude::*,
     system::SystemControl,
 };
-use lilygo_epd47::{pin_config, Display, DrawMode};
+use lilygo_epd47::{pin_config, Display, DisplayConfig, DrawMode};
 
 #[entry]
 fn main() -> ! {
@@ -40,7 +40,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let delay = Delay::new(&clocks);
     display.power_on();
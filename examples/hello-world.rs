@@ -28,7 +28,7 @@ use esp_hal::{
     system::SystemControl,
 };
 use esp_println::println;
-use lilygo_epd47::{pin_config, Display, DrawMode};
+use lilygo_epd47::{pin_config, Display, DisplayConfig, DrawMode};
 use tinybmp::Bmp;
 use u8g2_fonts::U8g2TextStyle;
 
@@ -50,7 +50,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let delay = Delay::new(&clocks);
 
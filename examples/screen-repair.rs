@@ -14,7 +14,7 @@ use esp_hal::{
     prelude::*,
     system::SystemControl,
 };
-use lilygo_epd47::{pin_config, Display};
+use lilygo_epd47::{pin_config, Display, DisplayConfig};
 
 #[entry]
 fn main() -> ! {
@@ -34,7 +34,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
 
     let delay = Delay::new(&clocks);
     display.power_on();
@@ -18,7 +18,7 @@ use esp_hal::{
     prelude::*,
     system::SystemControl,
 };
-use lilygo_epd47::{pin_config, Display, DrawMode};
+use lilygo_epd47::{pin_config, Display, DisplayConfig, DrawMode};
 
 #[entry]
 fn main() -> ! {
@@ -36,7 +36,9 @@ fn main() -> ! {
         peripherals.LCD_CAM,
         peripherals.RMT,
         &clocks,
-    );
+        DisplayConfig::default(),
+    )
+    .unwrap();
     // Turn the display on
     display.power_on();
     delay.delay_millis(10);
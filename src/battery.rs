@@ -6,6 +6,8 @@ use esp_hal::{
     prelude::nb,
 };
 
+use crate::BatteryHistory;
+
 pub struct Battery<'a, PIN>
 where
     PIN: AdcChannel + AnalogPin,
@@ -45,4 +47,16 @@ where
 
         (((v as f32) * 2.0) / 1000.0) * self.correction_factor
     }
+
+    /// Read the current voltage and record it in the provided history. The
+    /// timestamp is in seconds, see [`BatteryHistory::push`].
+    pub fn record<const N: usize>(
+        &mut self,
+        history: &mut BatteryHistory<N>,
+        timestamp: u32,
+    ) -> f32 {
+        let voltage = self.read();
+        history.push(timestamp, voltage);
+        voltage
+    }
 }
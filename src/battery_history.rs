@@ -0,0 +1,364 @@
+use core::{fmt, time::Duration};
+
+/// Approximate open circuit voltage (mV) to state of charge (%) curve of a
+/// single cell LiPo battery. Must be sorted by voltage in descending order.
+const DISCHARGE_CURVE: &[(u16, u8)] = &[
+    (4200, 100),
+    (4150, 95),
+    (4110, 90),
+    (4080, 85),
+    (4020, 80),
+    (3980, 75),
+    (3950, 70),
+    (3910, 65),
+    (3870, 60),
+    (3850, 55),
+    (3840, 50),
+    (3820, 45),
+    (3800, 40),
+    (3790, 35),
+    (3770, 30),
+    (3750, 25),
+    (3730, 20),
+    (3710, 15),
+    (3690, 10),
+    (3610, 5),
+    (3270, 0),
+];
+
+/// A single battery reading.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Reading {
+    /// Time of the reading in seconds. The epoch is up to the caller, but it
+    /// must be monotonic across deep sleep (e.g. the RTC time).
+    pub timestamp: u32,
+    /// Battery voltage in millivolts.
+    pub millivolts: u16,
+}
+
+impl Reading {
+    /// Estimated state of charge in percent.
+    pub fn state_of_charge(&self) -> u8 {
+        state_of_charge(self.millivolts)
+    }
+}
+
+/// Estimated state of charge in percent for the provided voltage in
+/// millivolts.
+pub fn state_of_charge(millivolts: u16) -> u8 {
+    let (max_mv, max_soc) = DISCHARGE_CURVE[0];
+    if millivolts >= max_mv {
+        return max_soc;
+    }
+    for window in DISCHARGE_CURVE.windows(2) {
+        let (upper_mv, upper_soc) = window[0];
+        let (lower_mv, lower_soc) = window[1];
+        if millivolts >= lower_mv {
+            // linear interpolation between the two points of the curve
+            let span = (upper_soc - lower_soc) as u32;
+            let offset = (millivolts - lower_mv) as u32 * span / (upper_mv - lower_mv) as u32;
+            return lower_soc + offset as u8;
+        }
+    }
+    0
+}
+
+/// Remaining runtime estimated from a [`BatteryHistory`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RuntimeEstimate {
+    /// Estimated state of charge in percent.
+    pub state_of_charge: u8,
+    /// Discharge rate in percent per hour.
+    pub discharge_rate: f32,
+    /// Estimated time until the battery is empty.
+    pub remaining: Duration,
+}
+
+impl fmt::Display for RuntimeEstimate {
+    /// Human readable representation, e.g. `about 12 days left`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const HOUR: u64 = 60 * 60;
+        const DAY: u64 = 24 * HOUR;
+
+        let secs = self.remaining.as_secs();
+        match secs {
+            s if s < HOUR => write!(f, "less than an hour left"),
+            s if s < 2 * DAY => {
+                let hours = (s + HOUR / 2) / HOUR;
+                write!(f, "about {} hour{} left", hours, plural(hours))
+            }
+            s => {
+                let days = (s + DAY / 2) / DAY;
+                write!(f, "about {} day{} left", days, plural(days))
+            }
+        }
+    }
+}
+
+fn plural(value: u64) -> &'static str {
+    match value {
+        1 => "",
+        _ => "s",
+    }
+}
+
+/// Source the board is currently powered from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerSource {
+    /// Powered via USB, a battery might be charged at the same time.
+    Usb,
+    /// Running from the battery.
+    Battery,
+    /// Not enough data to tell.
+    Unknown,
+}
+
+/// Charging state of the battery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChargeState {
+    /// Voltage is rising while on USB power.
+    Charging,
+    /// Voltage is at (or above) the charge termination level and stable.
+    Charged,
+    /// Voltage is falling.
+    Discharging,
+    /// Not enough data to tell.
+    Unknown,
+}
+
+/// Power status derived from a [`BatteryHistory`].
+///
+/// The V2.3 board has no charger status line, the status is a heuristic based
+/// on the voltage level and trend. Expect it to lag behind plugging or
+/// unplugging USB by a reading or two.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PowerStatus {
+    /// Source the board is powered from.
+    pub source: PowerSource,
+    /// Charging state of the battery.
+    pub charge: ChargeState,
+}
+
+impl PowerStatus {
+    const UNKNOWN: Self = PowerStatus {
+        source: PowerSource::Unknown,
+        charge: ChargeState::Unknown,
+    };
+
+    /// Whether the board is (most likely) powered via USB.
+    pub fn is_usb(&self) -> bool {
+        self.source == PowerSource::Usb
+    }
+}
+
+/// Ring buffer of periodic battery readings.
+///
+/// The buffer is plain data and can be constructed in a const context, so it
+/// can be placed in RTC fast memory to survive deep sleep:
+///
+/// ```rust no_run
+/// use esp_hal::prelude::*;
+/// use lilygo_epd47::BatteryHistory;
+///
+/// #[ram(rtc_fast)]
+/// static mut HISTORY: BatteryHistory<48> = BatteryHistory::new();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct BatteryHistory<const N: usize> {
+    readings: [Reading; N],
+    head: usize,
+    len: usize,
+}
+
+impl<const N: usize> Default for BatteryHistory<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> BatteryHistory<N> {
+    /// Minimum time span (in seconds) the readings have to cover before an
+    /// estimate is provided.
+    pub const MIN_SPAN: u32 = 10 * 60;
+    /// Voltage (mV) above which no LiPo cell can be connected, the charger
+    /// output is measured instead.
+    pub const USB_MILLIVOLTS: u16 = 4300;
+    /// Voltage (mV) of a fully charged cell.
+    pub const CHARGED_MILLIVOLTS: u16 = 4150;
+    /// Voltage change (mV) between two consecutive readings which is
+    /// considered as plugging / unplugging USB.
+    pub const STEP_MILLIVOLTS: u16 = 60;
+    /// Voltage change (mV) over the span of the history within which the
+    /// voltage is considered stable, about the resolution of the ADC
+    /// readings. A discharging cell drops only a few millivolts per hour when
+    /// idle, so the allowed trend shrinks as the history grows.
+    pub const STABLE_MILLIVOLTS: u16 = 10;
+
+    /// Create an empty history.
+    pub const fn new() -> Self {
+        BatteryHistory {
+            readings: [Reading {
+                timestamp: 0,
+                millivolts: 0,
+            }; N],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Number of recorded readings.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the history contains no readings.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all readings.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Record a new reading. The voltage is expected in volts as returned by
+    /// [`crate::Battery::read`].
+    ///
+    /// If the timestamp is older than the latest reading (e.g. the RTC has been
+    /// reset) the history is cleared before the reading is recorded. Once the
+    /// buffer is full, the oldest reading is overwritten.
+    pub fn push(&mut self, timestamp: u32, voltage: f32) {
+        if N == 0 {
+            return;
+        }
+        if matches!(self.latest(), Some(latest) if latest.timestamp > timestamp) {
+            self.clear();
+        }
+        let millivolts = (voltage * 1000.0).clamp(0.0, u16::MAX as f32) as u16;
+        self.readings[self.head] = Reading {
+            timestamp,
+            millivolts,
+        };
+        self.head = (self.head + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// The most recent reading.
+    pub fn latest(&self) -> Option<Reading> {
+        if self.len == 0 {
+            return None;
+        }
+        Some(self.readings[(self.head + N - 1) % N])
+    }
+
+    /// Iterate over the readings from the oldest to the newest one.
+    pub fn iter(&self) -> impl Iterator<Item = Reading> + '_ {
+        let start = (self.head + N - self.len) % N.max(1);
+        (0..self.len).map(move |i| self.readings[(start + i) % N])
+    }
+
+    /// Voltage trend in millivolts per hour, estimated by linear regression
+    /// over all readings. Negative values indicate a discharging battery.
+    ///
+    /// Returns `None` if there are less than two readings or the readings
+    /// cover less than [`Self::MIN_SPAN`].
+    pub fn voltage_trend(&self) -> Option<f32> {
+        self.regression(|r| r.millivolts as f32)
+            .map(|(slope, _)| slope * 3600.0)
+    }
+
+    /// Estimate the remaining runtime based on the recorded readings.
+    ///
+    /// Returns `None` if there is not enough data (see
+    /// [`Self::voltage_trend`]) or the battery is not discharging.
+    pub fn estimate(&self) -> Option<RuntimeEstimate> {
+        let (slope, soc) = self.regression(|r| r.state_of_charge() as f32)?;
+        // percent per second
+        if slope >= 0.0 {
+            return None;
+        }
+        let soc = soc.clamp(0.0, 100.0);
+        let remaining = soc / -slope;
+        Some(RuntimeEstimate {
+            state_of_charge: soc as u8,
+            discharge_rate: -slope * 3600.0,
+            remaining: Duration::from_secs(remaining as u64),
+        })
+    }
+
+    /// Determine the power source and charging state from the level and trend
+    /// of the recorded readings.
+    pub fn power_status(&self) -> PowerStatus {
+        let Some(latest) = self.latest() else {
+            return PowerStatus::UNKNOWN;
+        };
+        let usb = |charge| PowerStatus {
+            source: PowerSource::Usb,
+            charge,
+        };
+        let battery = PowerStatus {
+            source: PowerSource::Battery,
+            charge: ChargeState::Discharging,
+        };
+        if latest.millivolts >= Self::USB_MILLIVOLTS {
+            return usb(ChargeState::Charged);
+        }
+        // A sudden step is more recent information than the overall trend.
+        if let Some(previous) = self.len.checked_sub(2).and_then(|i| self.iter().nth(i)) {
+            let step = latest.millivolts as i32 - previous.millivolts as i32;
+            if step >= Self::STEP_MILLIVOLTS as i32 {
+                return usb(ChargeState::Charging);
+            }
+            if step <= -(Self::STEP_MILLIVOLTS as i32) {
+                return battery;
+            }
+        }
+        let stable = self.stable_trend();
+        match self.voltage_trend() {
+            Some(trend) if trend > stable => usb(ChargeState::Charging),
+            Some(trend) if trend < -stable => battery,
+            Some(_) if latest.millivolts >= Self::CHARGED_MILLIVOLTS => usb(ChargeState::Charged),
+            _ => PowerStatus::UNKNOWN,
+        }
+    }
+
+    /// Voltage trend (mV per hour) within which the voltage is considered
+    /// stable, [`Self::STABLE_MILLIVOLTS`] over the span of the readings.
+    fn stable_trend(&self) -> f32 {
+        let span = match (self.iter().next(), self.latest()) {
+            (Some(first), Some(latest)) => latest.timestamp - first.timestamp,
+            _ => 0,
+        };
+        Self::STABLE_MILLIVOLTS as f32 * 3600.0 / span.max(Self::MIN_SPAN) as f32
+    }
+
+    /// Least squares fit of `value` over time. Returns the slope (per second)
+    /// and the fitted value at the time of the latest reading.
+    fn regression(&self, value: impl Fn(&Reading) -> f32) -> Option<(f32, f32)> {
+        let first = self.iter().next()?;
+        let latest = self.latest()?;
+        if self.len < 2 || latest.timestamp - first.timestamp < Self::MIN_SPAN {
+            return None;
+        }
+        // Work relative to the first reading to keep the f32 precision.
+        let n = self.len as f32;
+        let (sum_t, sum_v) = self.iter().fold((0.0, 0.0), |(sum_t, sum_v), r| {
+            (
+                sum_t + (r.timestamp - first.timestamp) as f32,
+                sum_v + value(&r),
+            )
+        });
+        let (mean_t, mean_v) = (sum_t / n, sum_v / n);
+        let (cov, var) = self.iter().fold((0.0, 0.0), |(cov, var), r| {
+            let dt = (r.timestamp - first.timestamp) as f32 - mean_t;
+            (cov + dt * (value(&r) - mean_v), var + dt * dt)
+        });
+        if var == 0.0 {
+            return None;
+        }
+        let slope = cov / var;
+        let latest_t = (latest.timestamp - first.timestamp) as f32;
+        Some((slope, mean_v + slope * (latest_t - mean_t)))
+    }
+}
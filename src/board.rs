@@ -0,0 +1,39 @@
+//! Board support.
+//!
+//! The board is selected at compile time through one of the `board-*` cargo
+//! features. Each board provides the [`PinConfig`] passed to
+//! [`crate::Display::new`], the [`PowerSequence`] of the panel rails and the
+//! control lines (power, mode, output enable, latch) used by the panel driver.
+
+#[cfg(all(feature = "board-v23", feature = "board-s3-pro"))]
+compile_error!("The features `board-v23` and `board-s3-pro` are mutually exclusive");
+
+#[cfg(not(any(feature = "board-v23", feature = "board-s3-pro")))]
+compile_error!("Select a board using either the `board-v23` or the `board-s3-pro` feature");
+
+#[cfg(feature = "board-s3-pro")]
+mod s3_pro;
+#[cfg(feature = "board-v23")]
+mod v23;
+
+#[cfg(feature = "board-s3-pro")]
+pub(crate) use s3_pro::{emergency_power_off, Control, Parts, TxPins};
+#[cfg(feature = "board-s3-pro")]
+pub use s3_pro::{I2cBus, PinConfig, PowerSequence};
+#[cfg(feature = "board-v23")]
+pub(crate) use v23::{emergency_power_off, Control, Parts, TxPins};
+#[cfg(feature = "board-v23")]
+pub use v23::{PinConfig, PowerSequence};
+
+/// Busy wait for the number of CPU cycles, for code running without the
+/// clocks, e.g. [`emergency_power_off`].
+#[inline(always)]
+fn busy_delay(wait_cycles: u32) {
+    let target = cycles() + wait_cycles as u64;
+    while cycles() < target {}
+}
+
+#[inline(always)]
+fn cycles() -> u64 {
+    esp_hal::xtensa_lx::timer::get_cycle_count() as u64
+}
@@ -0,0 +1,318 @@
+//! LilyGo T5 4.7 inch S3 Pro (ESP32-S3).
+//!
+//! The panel rails are generated by a TPS651851 PMIC. Its power-up and wake-up
+//! lines as well as the output enable and mode lines of the panel are
+//! connected to a PCA9535 I/O expander. Both are attached to the same I2C bus.
+
+use esp_hal::{
+    clock::Clocks,
+    delay::Delay,
+    gpio::{GpioPin, Level, Output, OutputOpenDrain, OutputPin, Pull},
+    i2c::I2C,
+    lcd_cam::lcd::i8080,
+    peripherals::{self, I2C0},
+    prelude::_fugit_RateExtU32,
+    Blocking,
+};
+
+use super::busy_delay;
+use crate::tps65185::{self, Tps65185};
+
+/// I2C address of the PCA9535 I/O expander.
+const EXPANDER_ADDRESS: u8 = 0x20;
+const EXPANDER_REG_INPUT_1: u8 = 0x01;
+const EXPANDER_REG_OUTPUT_1: u8 = 0x03;
+const EXPANDER_REG_CONFIG_1: u8 = 0x07;
+
+// Pins of port 1 of the I/O expander
+const CFG_OUTPUT_ENABLE: u8 = 1 << 0;
+const CFG_MODE: u8 = 1 << 1;
+const CFG_POWER_UP: u8 = 1 << 3;
+const CFG_VCOM_CTRL: u8 = 1 << 4;
+const CFG_WAKEUP: u8 = 1 << 5;
+const CFG_POWER_GOOD: u8 = 1 << 6;
+const CFG_INTERRUPT: u8 = 1 << 7;
+
+/// Timings (in microseconds) of the power sequence of the panel rails. The
+/// sequence of the individual rails is handled by the PMIC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PowerSequence {
+    /// Interval between polls of the power good line while powering on.
+    pub power_good_poll: u32,
+    /// Maximum time to wait for the power good line.
+    pub power_good_timeout: u32,
+    /// Power up line low to wake up line low while powering off.
+    pub power_down_to_sleep: u32,
+}
+
+impl Default for PowerSequence {
+    fn default() -> Self {
+        PowerSequence {
+            power_good_poll: 100,
+            power_good_timeout: 100_000,
+            power_down_to_sleep: 100,
+        }
+    }
+}
+
+/// The I2C bus shared by the PMIC and the I/O expander.
+pub type I2cBus<'a> = I2C<'a, I2C0, Blocking>;
+
+/// The pins of the board.
+///
+/// The pin of the CKV line defaults to the wiring of the board but can be any
+/// output pin.
+pub struct PinConfig<CKV = GpioPin<48>> {
+    pub data0: GpioPin<5>,
+    pub data1: GpioPin<6>,
+    pub data2: GpioPin<7>,
+    pub data3: GpioPin<15>,
+    pub data4: GpioPin<16>,
+    pub data5: GpioPin<17>,
+    pub data6: GpioPin<18>,
+    pub data7: GpioPin<8>,
+    pub leh: GpioPin<42>,
+    pub stv: GpioPin<45>,
+    pub lcd_dc: GpioPin<41>,
+    pub lcd_wrx: GpioPin<4>,
+    pub rmt: CKV,
+    pub sda: GpioPin<39>,
+    pub scl: GpioPin<40>,
+    pub i2c: peripherals::I2C0,
+}
+
+pub(crate) type TxPins<'a> = i8080::TxEightBits<
+    'a,
+    GpioPin<5>,
+    GpioPin<6>,
+    GpioPin<7>,
+    GpioPin<15>,
+    GpioPin<16>,
+    GpioPin<17>,
+    GpioPin<18>,
+    GpioPin<8>,
+>;
+
+/// The pins split up by their usage.
+pub(crate) struct Parts<'a, CKV> {
+    pub(crate) tx_pins: TxPins<'a>,
+    pub(crate) lcd_dc: GpioPin<41>,
+    pub(crate) lcd_wrx: GpioPin<4>,
+    pub(crate) ckv: CKV,
+    pub(crate) control: Control<'a>,
+}
+
+impl<CKV: OutputPin> PinConfig<CKV> {
+    pub(crate) fn split<'a>(self, clocks: &'a Clocks) -> Parts<'a, CKV> {
+        Parts {
+            tx_pins: i8080::TxEightBits::new(
+                self.data0, self.data1, self.data2, self.data3, self.data4, self.data5, self.data6,
+                self.data7,
+            ),
+            lcd_dc: self.lcd_dc,
+            lcd_wrx: self.lcd_wrx,
+            ckv: self.rmt,
+            control: Control {
+                pmic: Tps65185::new(I2C::new(self.i2c, self.sda, self.scl, 400.kHz(), clocks)),
+                delay: Delay::new(clocks),
+                leh: Output::new(self.leh, Level::Low),
+                stv: Output::new(self.stv, Level::High),
+                port: 0,
+                vcom: None,
+                sequence: PowerSequence::default(),
+            },
+        }
+    }
+}
+
+pub(crate) struct Control<'a> {
+    pmic: Tps65185<I2cBus<'a>>,
+    delay: Delay,
+    leh: Output<'a, GpioPin<42>>,
+    stv: Output<'a, GpioPin<45>>,
+    /// Shadow of the output register of port 1 of the I/O expander.
+    port: u8,
+    /// VCOM in millivolts, the PMIC loses the setting in sleep mode.
+    vcom: Option<u16>,
+    sequence: PowerSequence,
+}
+
+impl<'a> Control<'a> {
+    fn write_port(&mut self) -> crate::Result<()> {
+        let port = self.port;
+        self.pmic
+            .i2c()
+            .write(EXPANDER_ADDRESS, &[EXPANDER_REG_OUTPUT_1, port])
+            .map_err(crate::Error::I2c)
+    }
+
+    fn set_port(&mut self, mask: u8, value: bool) -> crate::Result<()> {
+        match value {
+            true => self.port |= mask,
+            false => self.port &= !mask,
+        }
+        self.write_port()
+    }
+
+    fn read_port(&mut self) -> crate::Result<u8> {
+        let mut buffer = [0u8; 1];
+        self.pmic
+            .i2c()
+            .write_read(EXPANDER_ADDRESS, &[EXPANDER_REG_INPUT_1], &mut buffer)
+            .map_err(crate::Error::I2c)?;
+        Ok(buffer[0])
+    }
+
+    pub(crate) fn set_power_sequence(&mut self, sequence: PowerSequence) {
+        self.sequence = sequence;
+    }
+
+    pub(crate) fn power_sequence(&self) -> PowerSequence {
+        self.sequence
+    }
+
+    pub(crate) fn power_on(&mut self) -> crate::Result<()> {
+        // The expander might have been reset in the meantime (e.g. deep sleep),
+        // always (re-)configure the direction of the pins.
+        self.pmic
+            .i2c()
+            .write(
+                EXPANDER_ADDRESS,
+                &[EXPANDER_REG_CONFIG_1, CFG_POWER_GOOD | CFG_INTERRUPT],
+            )
+            .map_err(crate::Error::I2c)?;
+        self.set_port(CFG_WAKEUP, true)?;
+        if let Some(vcom) = self.vcom {
+            self.pmic.set_vcom(vcom).map_err(crate::Error::Pmic)?;
+        }
+        self.set_port(CFG_POWER_UP, true)?;
+        self.set_port(CFG_VCOM_CTRL, true)?;
+        let polls = self.sequence.power_good_timeout / self.sequence.power_good_poll.max(1);
+        for _ in 0..=polls {
+            if self.read_port()? & CFG_POWER_GOOD != 0 {
+                self.pmic
+                    .power_up(&mut self.delay)
+                    .map_err(crate::Error::Pmic)?;
+                self.stv.set_high();
+                return Ok(());
+            }
+            self.delay.delay_micros(self.sequence.power_good_poll);
+        }
+        Err(crate::Error::Timeout)
+    }
+
+    pub(crate) fn power_off(&mut self) -> crate::Result<()> {
+        self.port &= !(CFG_VCOM_CTRL | CFG_POWER_UP | CFG_MODE | CFG_OUTPUT_ENABLE);
+        self.write_port()?;
+        self.delay.delay_micros(self.sequence.power_down_to_sleep);
+        self.set_port(CFG_WAKEUP, false)?;
+        self.stv.set_low();
+        Ok(())
+    }
+
+    /// Panel temperature in degrees celsius.
+    pub(crate) fn temperature(&mut self) -> crate::Result<Option<i8>> {
+        self.pmic
+            .temperature(&mut self.delay)
+            .map(Some)
+            .map_err(crate::Error::Pmic)
+    }
+
+    pub(crate) fn set_vcom(&mut self, millivolts: u16) -> crate::Result<()> {
+        if millivolts > Tps65185::<I2cBus>::MAX_VCOM {
+            return Err(crate::Error::Pmic(tps65185::Error::InvalidVcom));
+        }
+        self.vcom = Some(millivolts);
+        // The PMIC only responds while awake, otherwise the value is applied
+        // on power on.
+        if self.port & CFG_WAKEUP != 0 {
+            self.pmic.set_vcom(millivolts).map_err(crate::Error::Pmic)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn vcom(&mut self) -> crate::Result<u16> {
+        match self.vcom {
+            Some(vcom) => Ok(vcom),
+            None => self.pmic.vcom().map_err(crate::Error::Pmic),
+        }
+    }
+
+    pub(crate) fn pmic(&mut self) -> &mut Tps65185<I2cBus<'a>> {
+        &mut self.pmic
+    }
+
+    pub(crate) fn set_mode(&mut self, mode: bool) -> crate::Result<()> {
+        self.set_port(CFG_MODE, mode)
+    }
+
+    pub(crate) fn set_stv(&mut self, stv: bool) -> crate::Result<()> {
+        self.stv.set_level(match stv {
+            true => Level::High,
+            false => Level::Low,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn set_output_enable(&mut self, output_enable: bool) -> crate::Result<()> {
+        self.set_port(CFG_OUTPUT_ENABLE, output_enable)
+    }
+
+    pub(crate) fn latch_row(&mut self) {
+        self.leh.set_high();
+        self.leh.set_low();
+    }
+}
+
+/// Power off the panel without a [`Control`] instance, e.g. from a panic
+/// handler. The I2C driver might be in an unknown state, the output port of
+/// the I/O expander is written by bit-banging the bus (~50 kHz). Pulling the
+/// power-up and wake-up lines low makes the PMIC power down the rails.
+///
+/// # Safety
+///
+/// Steals the I2C pins.
+pub(crate) unsafe fn emergency_power_off() {
+    let mut sda = OutputOpenDrain::new(GpioPin::<39>::steal(), Level::High, Pull::Up);
+    let mut scl = OutputOpenDrain::new(GpioPin::<40>::steal(), Level::High, Pull::Up);
+    // 10us at 240 MHz, the clocks are unknown at this point and lower CPU
+    // frequencies only slow down the bus
+    let half_period = || busy_delay(10 * 240);
+    let level = |high| match high {
+        true => Level::High,
+        false => Level::Low,
+    };
+
+    // bus recovery, in case the panic interrupted a transfer
+    for _ in 0..9 {
+        scl.set_low();
+        half_period();
+        scl.set_high();
+        half_period();
+    }
+    // start
+    sda.set_low();
+    half_period();
+    scl.set_low();
+    for byte in [EXPANDER_ADDRESS << 1, EXPANDER_REG_OUTPUT_1, 0] {
+        for bit in (0..8).rev() {
+            sda.set_level(level(byte & (1 << bit) != 0));
+            half_period();
+            scl.set_high();
+            half_period();
+            scl.set_low();
+        }
+        // acknowledge, there is nothing to do on a missing one anyway
+        sda.set_high();
+        half_period();
+        scl.set_high();
+        half_period();
+        scl.set_low();
+    }
+    // stop
+    sda.set_low();
+    half_period();
+    scl.set_high();
+    half_period();
+    sda.set_high();
+}
@@ -0,0 +1,349 @@
+//! LilyGo T5 4.7 inch V2.3 (ESP32-S3).
+//!
+//! The control lines of the panel as well as the power rails are driven by a
+//! shift register.
+
+use alloc::boxed::Box;
+use core::{
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+use esp_hal::{
+    clock::Clocks,
+    delay::Delay,
+    gpio::{AnyOutput, CreateErasedPin, GpioPin, Level, OutputPin},
+    lcd_cam::lcd::i8080,
+    peripheral::Peripheral,
+};
+
+use super::busy_delay;
+
+struct ConfigRegister {
+    latch_enable: bool,
+    power_disable: bool,
+    pos_power_enable: bool,
+    neg_power_enable: bool,
+    stv: bool,
+    power_enable: bool, /* scan_direction, see https://github.com/vroland/epdiy/blob/main/src/board/epd_board_lilygo_t5_47.c#L199 */
+    mode: bool,
+    output_enable: bool,
+}
+
+impl Default for ConfigRegister {
+    fn default() -> Self {
+        ConfigRegister {
+            latch_enable: false,
+            power_disable: true,
+            pos_power_enable: false,
+            neg_power_enable: false,
+            stv: true,
+            power_enable: false,
+            mode: false,
+            output_enable: false,
+        }
+    }
+}
+
+struct ConfigWriter<'a> {
+    pin_data: AnyOutput<'a>,
+    pin_clk: AnyOutput<'a>,
+    pin_str: AnyOutput<'a>,
+    config: ConfigRegister,
+}
+
+impl<'a> ConfigWriter<'a> {
+    fn new<DATA, CLK, STR>(
+        data: impl Peripheral<P = DATA> + 'a,
+        clk: impl Peripheral<P = CLK> + 'a,
+        str: impl Peripheral<P = STR> + 'a,
+    ) -> Self
+    where
+        DATA: OutputPin + CreateErasedPin,
+        CLK: OutputPin + CreateErasedPin,
+        STR: OutputPin + CreateErasedPin,
+    {
+        ConfigWriter {
+            pin_data: AnyOutput::new(data, Level::High),
+            pin_clk: AnyOutput::new(clk, Level::High),
+            pin_str: AnyOutput::new(str, Level::Low),
+            config: ConfigRegister::default(),
+        }
+    }
+
+    fn write(&mut self) {
+        self.pin_str.set_low();
+        self.write_bool(self.config.output_enable);
+        self.write_bool(self.config.mode);
+        self.write_bool(self.config.power_enable);
+        self.write_bool(self.config.stv);
+        self.write_bool(self.config.neg_power_enable);
+        self.write_bool(self.config.pos_power_enable);
+        self.write_bool(self.config.power_disable);
+        self.write_bool(self.config.latch_enable);
+        self.pin_str.set_high();
+    }
+
+    #[inline(always)]
+    fn write_bool(&mut self, v: bool) {
+        self.pin_clk.set_low();
+        self.pin_data.set_level(match v {
+            true => Level::High,
+            false => Level::Low,
+        });
+        self.pin_clk.set_high();
+    }
+}
+
+/// Timings (in microseconds) of the power sequence of the panel rails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PowerSequence {
+    /// Power enable to negative rails enable.
+    pub enable_to_negative: u32,
+    /// Negative rails to positive rails enable.
+    pub negative_to_positive: u32,
+    /// Positive rails enable to STV high.
+    pub positive_to_stv: u32,
+    /// Positive rails to negative rails disable.
+    pub positive_off_to_negative_off: u32,
+    /// Negative rails disable to power disable.
+    pub negative_off_to_disable: u32,
+}
+
+impl Default for PowerSequence {
+    fn default() -> Self {
+        PowerSequence {
+            enable_to_negative: 100,
+            negative_to_positive: 500,
+            positive_to_stv: 100,
+            positive_off_to_negative_off: 10,
+            negative_off_to_disable: 100,
+        }
+    }
+}
+
+/// The pins of the board.
+///
+/// The pins of the CKV line and of the shift register default to the wiring of
+/// the board but can be any output pins.
+pub struct PinConfig<CKV = GpioPin<38>, DATA = GpioPin<13>, CLK = GpioPin<12>, STR = GpioPin<0>> {
+    pub data0: GpioPin<6>,
+    pub data1: GpioPin<7>,
+    pub data2: GpioPin<4>,
+    pub data3: GpioPin<5>,
+    pub data4: GpioPin<2>,
+    pub data5: GpioPin<3>,
+    pub data6: GpioPin<8>,
+    pub data7: GpioPin<1>,
+    pub cfg_data: DATA,
+    pub cfg_clk: CLK,
+    pub cfg_str: STR,
+    pub lcd_dc: GpioPin<40>,
+    pub lcd_wrx: GpioPin<41>,
+    pub rmt: CKV,
+}
+
+pub(crate) type TxPins<'a> = i8080::TxEightBits<
+    'a,
+    GpioPin<6>,
+    GpioPin<7>,
+    GpioPin<4>,
+    GpioPin<5>,
+    GpioPin<2>,
+    GpioPin<3>,
+    GpioPin<8>,
+    GpioPin<1>,
+>;
+
+/// The pins split up by their usage.
+pub(crate) struct Parts<'a, CKV> {
+    pub(crate) tx_pins: TxPins<'a>,
+    pub(crate) lcd_dc: GpioPin<40>,
+    pub(crate) lcd_wrx: GpioPin<41>,
+    pub(crate) ckv: CKV,
+    pub(crate) control: Control<'a>,
+}
+
+impl<CKV, DATA, CLK, STR> PinConfig<CKV, DATA, CLK, STR>
+where
+    CKV: OutputPin,
+    DATA: OutputPin + CreateErasedPin,
+    CLK: OutputPin + CreateErasedPin,
+    STR: OutputPin + CreateErasedPin,
+{
+    pub(crate) fn split<'a>(self, clocks: &'a Clocks) -> Parts<'a, CKV>
+    where
+        DATA: 'a,
+        CLK: 'a,
+        STR: 'a,
+    {
+        Parts {
+            tx_pins: i8080::TxEightBits::new(
+                self.data0, self.data1, self.data2, self.data3, self.data4, self.data5, self.data6,
+                self.data7,
+            ),
+            lcd_dc: self.lcd_dc,
+            lcd_wrx: self.lcd_wrx,
+            ckv: self.rmt,
+            control: Control::new(self.cfg_data, self.cfg_clk, self.cfg_str, clocks),
+        }
+    }
+}
+
+/// Shift register writer of the current [`Control`], used by
+/// [`emergency_power_off`] to reach the configured pins.
+static CONFIG_WRITER: AtomicPtr<ConfigWriter<'static>> = AtomicPtr::new(ptr::null_mut());
+
+pub(crate) struct Control<'a> {
+    /// Boxed to keep its address for [`CONFIG_WRITER`].
+    cfg_writer: Box<ConfigWriter<'a>>,
+    delay: Delay,
+    sequence: PowerSequence,
+}
+
+impl<'a> Control<'a> {
+    fn new<DATA, CLK, STR>(data: DATA, clk: CLK, str: STR, clocks: &Clocks) -> Self
+    where
+        DATA: OutputPin + CreateErasedPin + 'a,
+        CLK: OutputPin + CreateErasedPin + 'a,
+        STR: OutputPin + CreateErasedPin + 'a,
+    {
+        // init panel config writer (?)
+        let mut cfg_writer = Box::new(ConfigWriter::new(data, clk, str));
+        cfg_writer.write();
+        let writer: *mut ConfigWriter<'a> = &mut *cfg_writer;
+        CONFIG_WRITER.store(writer.cast(), Ordering::Release);
+        Control {
+            cfg_writer,
+            delay: Delay::new(clocks),
+            sequence: PowerSequence::default(),
+        }
+    }
+
+    pub(crate) fn set_power_sequence(&mut self, sequence: PowerSequence) {
+        self.sequence = sequence;
+    }
+
+    pub(crate) fn power_sequence(&self) -> PowerSequence {
+        self.sequence
+    }
+
+    pub(crate) fn power_on(&mut self) -> crate::Result<()> {
+        self.cfg_writer.config.power_enable = true;
+        self.cfg_writer.config.power_disable = false;
+        self.cfg_writer.write();
+        self.delay.delay_micros(self.sequence.enable_to_negative);
+        self.cfg_writer.config.neg_power_enable = true;
+        self.cfg_writer.write();
+        self.delay.delay_micros(self.sequence.negative_to_positive);
+        self.cfg_writer.config.pos_power_enable = true;
+        self.cfg_writer.write();
+        self.delay.delay_micros(self.sequence.positive_to_stv);
+        self.cfg_writer.config.stv = true;
+        self.cfg_writer.write();
+        Ok(())
+    }
+
+    pub(crate) fn power_off(&mut self) -> crate::Result<()> {
+        self.cfg_writer.config.power_enable = false;
+        self.cfg_writer.config.pos_power_enable = false;
+        self.cfg_writer.write();
+        self.delay
+            .delay_micros(self.sequence.positive_off_to_negative_off);
+        self.cfg_writer.config.neg_power_enable = false;
+        self.cfg_writer.write();
+        self.delay
+            .delay_micros(self.sequence.negative_off_to_disable);
+        self.cfg_writer.config.power_disable = true;
+        self.cfg_writer.config.mode = false;
+        // self.cfg_writer.write();
+        self.cfg_writer.config.stv = false;
+        self.cfg_writer.write();
+        Ok(())
+    }
+
+    /// The board has no temperature sensor.
+    pub(crate) fn temperature(&mut self) -> crate::Result<Option<i8>> {
+        Ok(None)
+    }
+
+    pub(crate) fn set_mode(&mut self, mode: bool) -> crate::Result<()> {
+        self.cfg_writer.config.mode = mode;
+        self.cfg_writer.write();
+        Ok(())
+    }
+
+    pub(crate) fn set_stv(&mut self, stv: bool) -> crate::Result<()> {
+        self.cfg_writer.config.stv = stv;
+        self.cfg_writer.write();
+        Ok(())
+    }
+
+    pub(crate) fn set_output_enable(&mut self, output_enable: bool) -> crate::Result<()> {
+        self.cfg_writer.config.output_enable = output_enable;
+        self.cfg_writer.write();
+        Ok(())
+    }
+
+    pub(crate) fn latch_row(&mut self) {
+        self.cfg_writer.config.latch_enable = true;
+        self.cfg_writer.write();
+
+        self.cfg_writer.config.latch_enable = false;
+        self.cfg_writer.write();
+    }
+}
+
+impl<'a> Drop for Control<'a> {
+    fn drop(&mut self) {
+        let writer: *mut ConfigWriter<'a> = &mut *self.cfg_writer;
+        let _ = CONFIG_WRITER.compare_exchange(
+            writer.cast(),
+            ptr::null_mut(),
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        );
+    }
+}
+
+/// Power off the panel without a [`Control`] instance, e.g. from a panic
+/// handler. Uses the shift register pins of the current [`Control`], or the
+/// default pins if there is none. The rails are disabled in the same order
+/// as by [`Control::power_off`], using the default [`PowerSequence`].
+///
+/// # Safety
+///
+/// Takes over the pins of the shift register.
+pub(crate) unsafe fn emergency_power_off() {
+    let mut fallback;
+    let writer = match CONFIG_WRITER.load(Ordering::Acquire).as_mut() {
+        Some(writer) => writer,
+        None => {
+            fallback = ConfigWriter::new(
+                GpioPin::<13>::steal(),
+                GpioPin::<12>::steal(),
+                GpioPin::<0>::steal(),
+            );
+            &mut fallback
+        }
+    };
+    let sequence = PowerSequence::default();
+    // the clocks are unknown at this point, wait as if running at 240 MHz,
+    // lower CPU frequencies only make the delays longer
+    let delay = |micros: u32| busy_delay(micros * 240);
+
+    writer.config.power_enable = false;
+    writer.config.pos_power_enable = false;
+    writer.write();
+    delay(sequence.positive_off_to_negative_off);
+    writer.config.neg_power_enable = false;
+    writer.write();
+    delay(sequence.negative_off_to_disable);
+    writer.config.stv = false;
+    writer.config.output_enable = false;
+    writer.write();
+    writer.config.power_disable = true;
+    writer.config.mode = false;
+    writer.config.latch_enable = false;
+    writer.write();
+}
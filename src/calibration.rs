@@ -0,0 +1,371 @@
+//! Panel calibration.
+//!
+//! Ghosting and uneven gray levels vary from panel to panel. On boards with a
+//! programmable VCOM (S3 Pro) the calibration finds a suitable VCOM, on the
+//! V2.3 it tunes the contrast cycle timings instead. The result is persisted
+//! through a [`CalibrationStorage`] and applied on startup using
+//! [`Display::load_calibration`].
+//!
+//! Independent of that, the gray levels can be spaced evenly. Level `v` is
+//! darkened during the first `15 - v` frames of a flush, so the output times
+//! of the frames ([`DisplayConfig::contrast_cycles`]) define how dark each
+//! level gets. The [grayscale pattern](Display::draw_grayscale_pattern)
+//! shows all 16 levels, either measure their lightness (e.g. L* using a
+//! colorimeter) and derive a new table using [`linearize_levels`], or pick
+//! the most even one of the [`grayscale_candidates`] visually using
+//! [`Display::calibrate_grayscale`].
+//!
+//! [`DisplayConfig::contrast_cycles`]: crate::DisplayConfig::contrast_cycles
+
+use crate::{
+    config::{FRAME_COUNT, MAX_PULSE},
+    display::{Display, DrawMode},
+    Error,
+    Result,
+};
+
+/// Calibration data of a panel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    /// VCOM in millivolts, `None` on boards without a programmable VCOM.
+    pub vcom: Option<u16>,
+    /// Scale of the contrast cycle timings in percent.
+    pub contrast_scale: u8,
+    /// Output times of the grayscale frames, `None` to use the contrast
+    /// cycles of the [`DisplayConfig`](crate::DisplayConfig).
+    pub grayscale_cycles: Option<[u16; FRAME_COUNT]>,
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Calibration {
+            vcom: None,
+            contrast_scale: 100,
+            grayscale_cycles: None,
+        }
+    }
+}
+
+impl Calibration {
+    /// Size of the serialized calibration.
+    pub const SIZE: usize = 4 + 2 * FRAME_COUNT;
+    const VERSION: u8 = 2;
+    /// Version without grayscale cycles, still accepted by
+    /// [`Calibration::from_bytes`].
+    const VERSION_1: u8 = 1;
+    const NO_VCOM: u16 = u16::MAX;
+    const NO_CYCLES: u16 = u16::MAX;
+
+    /// Serialize the calibration, e.g. to store it in flash.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        let [vcom_low, vcom_high] = self.vcom.unwrap_or(Self::NO_VCOM).to_le_bytes();
+        bytes[..4].copy_from_slice(&[Self::VERSION, self.contrast_scale, vcom_low, vcom_high]);
+        let cycles = self
+            .grayscale_cycles
+            .unwrap_or([Self::NO_CYCLES; FRAME_COUNT]);
+        for (bytes, cycles) in bytes[4..].chunks_exact_mut(2).zip(cycles) {
+            bytes.copy_from_slice(&cycles.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserialize a calibration created by [`Calibration::to_bytes`].
+    ///
+    /// Returns `None` if the data is invalid (e.g. erased flash).
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (version, contrast_scale, vcom) = match bytes {
+            [version @ (Self::VERSION | Self::VERSION_1), contrast_scale @ 1..=u8::MAX, vcom_low, vcom_high, ..] => {
+                (
+                    *version,
+                    *contrast_scale,
+                    u16::from_le_bytes([*vcom_low, *vcom_high]),
+                )
+            }
+            _ => return None,
+        };
+        let grayscale_cycles = match version {
+            Self::VERSION => {
+                let mut cycles = [0u16; FRAME_COUNT];
+                let data = bytes.get(4..Self::SIZE)?;
+                for (cycles, data) in cycles.iter_mut().zip(data.chunks_exact(2)) {
+                    *cycles = u16::from_le_bytes([data[0], data[1]]);
+                }
+                match cycles {
+                    [Self::NO_CYCLES, ..] if cycles.iter().all(|&c| c == Self::NO_CYCLES) => None,
+                    _ if cycles.iter().all(|c| (1..=MAX_PULSE).contains(c)) => Some(cycles),
+                    _ => return None,
+                }
+            }
+            _ => None,
+        };
+        Some(Calibration {
+            vcom: (vcom != Self::NO_VCOM).then_some(vcom),
+            contrast_scale,
+            grayscale_cycles,
+        })
+    }
+
+    /// Candidates tried by [`Display::calibrate`], in order.
+    pub fn candidates() -> impl Iterator<Item = Calibration> {
+        #[cfg(feature = "board-s3-pro")]
+        let candidates = (1000..=2500).step_by(100).map(|vcom| Calibration {
+            vcom: Some(vcom),
+            ..Default::default()
+        });
+        #[cfg(not(feature = "board-s3-pro"))]
+        let candidates = (60..=160).step_by(10).map(|contrast_scale| Calibration {
+            contrast_scale,
+            ..Default::default()
+        });
+        candidates
+    }
+}
+
+/// Total output time of each gray level (in ticks of 0.1µs), level `v` is
+/// darkened during the first `15 - v` frames.
+fn level_times(cycles: &[u16; FRAME_COUNT]) -> [u32; 16] {
+    let mut times = [0u32; 16];
+    for level in (0..15).rev() {
+        times[level] = times[level + 1] + cycles[14 - level] as u32;
+    }
+    times
+}
+
+/// Output times of the frames producing the total level times.
+fn frame_cycles(times: &[u32; 16]) -> [u16; FRAME_COUNT] {
+    let mut cycles = [0u16; FRAME_COUNT];
+    for (frame, cycles) in cycles.iter_mut().enumerate() {
+        let time = times[14 - frame].saturating_sub(times[15 - frame]);
+        *cycles = time.clamp(1, MAX_PULSE as u32) as u16;
+    }
+    cycles
+}
+
+/// Derive grayscale cycles with perceptually even steps from the lightness
+/// of the 16 levels drawn using `cycles`.
+///
+/// `lightness` holds the measured lightness of each level, from black (level
+/// 0) to white (level 15), in any perceptually uniform unit, e.g. L* (0 to
+/// 100). The total output time of black stays the same, the times of the
+/// levels in between are interpolated so that their lightness is spaced
+/// evenly between black and white. Repeat the measurement with the new table
+/// to refine it.
+///
+/// Returns [`Error::InvalidFormat`] if white is not lighter than black.
+pub fn linearize_levels(
+    cycles: &[u16; FRAME_COUNT],
+    lightness: &[u8; 16],
+) -> Result<[u16; FRAME_COUNT]> {
+    let (black, white) = (lightness[0] as u32, lightness[15] as u32);
+    if white <= black {
+        return Err(Error::InvalidFormat);
+    }
+    // levels measured darker than the previous one count as equally light
+    let mut measured = [0u32; 16];
+    let mut lightest = 0;
+    for (measured, &lightness) in measured.iter_mut().zip(lightness) {
+        lightest = lightest.max(lightness as u32);
+        *measured = lightest;
+    }
+    let times = level_times(cycles);
+    let mut new_times = times;
+    for (level, time) in new_times.iter_mut().enumerate().take(15).skip(1) {
+        let target = black + (white - black) * level as u32 / 15;
+        // the measured levels enclosing the target
+        let lower = (0..15).rfind(|&l| measured[l] <= target).unwrap_or(0);
+        let (l0, l1) = (measured[lower], measured[lower + 1]);
+        *time = match l1 > l0 {
+            true => {
+                let fraction = (target - l0).min(l1 - l0);
+                times[lower] - (times[lower] - times[lower + 1]) * fraction / (l1 - l0)
+            }
+            false => times[lower],
+        };
+    }
+    Ok(frame_cycles(&new_times))
+}
+
+/// Redistribute the output times of the gray levels, keeping black and
+/// white. A positive `bend` (up to 100) lightens the levels in between, a
+/// negative one (down to -100) darkens them.
+pub fn bend_levels(cycles: &[u16; FRAME_COUNT], bend: i8) -> [u16; FRAME_COUNT] {
+    const SCALE: i32 = 15 * 100;
+    let bend = bend.clamp(-100, 100) as i32;
+    let times = level_times(cycles);
+    let mut new_times = times;
+    for (level, time) in new_times.iter_mut().enumerate() {
+        let level = level as i32;
+        // position on the old level scale, in 1/SCALE levels
+        let position = (SCALE * level + bend * level * (15 - level)) as u32;
+        let (lower, fraction) = ((position / SCALE as u32) as usize, position % SCALE as u32);
+        *time = match lower {
+            15 => times[15],
+            _ => times[lower] - (times[lower] - times[lower + 1]) * fraction / SCALE as u32,
+        };
+    }
+    frame_cycles(&new_times)
+}
+
+/// Candidates tried by [`Display::calibrate_grayscale`], in order, from the
+/// darkest to the lightest gray levels.
+pub fn grayscale_candidates(
+    cycles: [u16; FRAME_COUNT],
+) -> impl Iterator<Item = [u16; FRAME_COUNT]> {
+    (-80..=80)
+        .step_by(20)
+        .map(move |bend| bend_levels(&cycles, bend as i8))
+}
+
+/// Persistent storage for the [`Calibration`], e.g. flash or NVS.
+///
+/// Implementations should return [`crate::Error::Storage`] if accessing the
+/// storage fails.
+pub trait CalibrationStorage {
+    /// Load the calibration, `None` if no calibration has been stored yet.
+    fn load(&mut self) -> Result<Option<Calibration>>;
+
+    /// Store the calibration.
+    fn store(&mut self, calibration: &Calibration) -> Result<()>;
+}
+
+impl<'a> Display<'a> {
+    /// Apply the provided calibration.
+    pub fn apply_calibration(&mut self, calibration: &Calibration) -> Result<()> {
+        #[cfg(feature = "board-s3-pro")]
+        if let Some(vcom) = calibration.vcom {
+            self.set_vcom(vcom)?;
+        }
+        self.set_contrast_scale(calibration.contrast_scale);
+        self.set_grayscale_cycles(calibration.grayscale_cycles)
+    }
+
+    /// Load the calibration from the storage and apply it.
+    pub fn load_calibration(
+        &mut self,
+        storage: &mut impl CalibrationStorage,
+    ) -> Result<Option<Calibration>> {
+        let calibration = storage.load()?;
+        if let Some(calibration) = &calibration {
+            self.apply_calibration(calibration)?;
+        }
+        Ok(calibration)
+    }
+
+    /// Guided calibration routine.
+    ///
+    /// For each of the [`Calibration::candidates`] the candidate is applied
+    /// and the [test pattern](Display::draw_test_pattern) is drawn. Afterwards
+    /// `accept` is called, return `true` once the gray levels are evenly
+    /// spaced and no ghosting is visible (e.g. wait for a button press). The
+    /// accepted calibration is stored and returned.
+    ///
+    /// If no candidate is accepted, the previously stored calibration and the
+    /// VCOM in effect before are restored and `None` is returned. The display
+    /// has to be powered on. The stored grayscale cycles are kept.
+    pub fn calibrate(
+        &mut self,
+        storage: &mut impl CalibrationStorage,
+        mut accept: impl FnMut(&Calibration) -> bool,
+    ) -> Result<Option<Calibration>> {
+        let previous = self.current_calibration(storage)?;
+        for candidate in Calibration::candidates() {
+            let candidate = Calibration {
+                grayscale_cycles: previous.grayscale_cycles,
+                ..candidate
+            };
+            self.apply_calibration(&candidate)?;
+            self.draw_test_pattern()?;
+            if accept(&candidate) {
+                storage.store(&candidate)?;
+                return Ok(Some(candidate));
+            }
+        }
+        self.apply_calibration(&previous)?;
+        Ok(None)
+    }
+
+    /// Guided grayscale calibration.
+    ///
+    /// Like [`Display::calibrate`], but tries the
+    /// [`grayscale_candidates`] derived from the current grayscale cycles,
+    /// drawing the [grayscale pattern](Display::draw_grayscale_pattern) for
+    /// each one. Return `true` from `accept` once the steps between the
+    /// levels look even. The accepted cycles are stored together with the
+    /// rest of the stored calibration.
+    pub fn calibrate_grayscale(
+        &mut self,
+        storage: &mut impl CalibrationStorage,
+        mut accept: impl FnMut(&Calibration) -> bool,
+    ) -> Result<Option<Calibration>> {
+        let stored = storage.load()?.unwrap_or_default();
+        let previous = self.current_calibration(storage)?;
+        for cycles in grayscale_candidates(self.grayscale_cycles()) {
+            let candidate = Calibration {
+                grayscale_cycles: Some(cycles),
+                ..stored
+            };
+            self.apply_calibration(&candidate)?;
+            self.draw_grayscale_pattern()?;
+            if accept(&candidate) {
+                storage.store(&candidate)?;
+                return Ok(Some(candidate));
+            }
+        }
+        self.apply_calibration(&previous)?;
+        Ok(None)
+    }
+
+    /// The stored calibration with the VCOM in effect, which is restored if
+    /// no candidate is accepted. Without a stored VCOM the last candidate
+    /// would stay active otherwise.
+    fn current_calibration(
+        &mut self,
+        storage: &mut impl CalibrationStorage,
+    ) -> Result<Calibration> {
+        #[allow(unused_mut)]
+        let mut calibration = storage.load()?.unwrap_or_default();
+        #[cfg(feature = "board-s3-pro")]
+        {
+            calibration.vcom = Some(self.vcom()?);
+        }
+        Ok(calibration)
+    }
+
+    /// Clear the screen and draw the calibration test pattern. The upper part
+    /// shows all 16 gray levels, the lower part a checkerboard which makes
+    /// ghosting visible.
+    pub fn draw_test_pattern(&mut self) -> Result<()> {
+        const SQUARE: u16 = 30;
+        let bar_width = Self::WIDTH / 16;
+        let split = Self::HEIGHT / 4 * 3;
+
+        self.clear()?;
+        for y in 0..Self::HEIGHT {
+            for x in 0..Self::WIDTH {
+                let color = match y < split {
+                    true => (x / bar_width) as u8,
+                    false if (x / SQUARE + y / SQUARE) % 2 == 0 => 0x0,
+                    false => 0xF,
+                };
+                self.set_pixel(x, y, color)?;
+            }
+        }
+        self.flush(DrawMode::BlackOnWhite)
+    }
+
+    /// Clear the screen and draw the 16 gray levels as bars from black (left)
+    /// to white (right), e.g. to measure their lightness for
+    /// [`linearize_levels`].
+    pub fn draw_grayscale_pattern(&mut self) -> Result<()> {
+        let bar_width = Self::WIDTH / 16;
+
+        self.clear()?;
+        for y in 0..Self::HEIGHT {
+            for x in 0..Self::WIDTH {
+                self.set_pixel(x, y, (x / bar_width) as u8)?;
+            }
+        }
+        self.flush(DrawMode::BlackOnWhite)
+    }
+}
@@ -0,0 +1,147 @@
+//! Timing and bus configuration of the display.
+//!
+//! The defaults match the panel of the LilyGo T5 4.7 inch. Faster timings
+//! speed up refreshes at the cost of contrast, slower timings do the
+//! opposite. The configuration is validated by
+//! [`Display::new`](crate::Display::new).
+//!
+//! The DMA transfers are not configurable: each row is sent on its own, in a
+//! single transfer of 248 bytes (240 bytes of pixels, padded). One descriptor
+//! covers up to 4092 bytes, so the descriptor and the buffer have a fixed
+//! size and live in internal RAM.
+
+use crate::{board::PowerSequence, Error, Result};
+
+/// Number of frames used to draw the 16 gray levels.
+pub const FRAME_COUNT: usize = 15;
+
+/// Longest pulse the RMT peripheral can generate (in ticks of 0.1µs).
+pub const MAX_PULSE: u16 = 0x7FFF;
+
+/// Configuration passed to [`Display::new`](crate::Display::new).
+///
+/// ```rust no_run
+/// use lilygo_epd47::DisplayConfig;
+///
+/// // faster clears and a shorter black waveform
+/// let config = DisplayConfig::new()
+///     .with_clear_cycles(2, 50)
+///     .with_contrast_cycles([
+///         20, 20, 15, 15, 20, 20, 20, 30, 30, 40, 40, 40, 80, 150, 200,
+///     ]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisplayConfig {
+    /// Clock of the i8080 bus in Hz, 1 MHz to 40 MHz.
+    pub bus_clock: u32,
+    /// Output time of the frames in black on white and white on white mode
+    /// (in ticks of 0.1µs), 1 to [`MAX_PULSE`].
+    pub contrast_cycles: [u16; FRAME_COUNT],
+    /// Output time of the frames in white on black mode. They apply whenever
+    /// the panel is lightened, i.e. to black on white content of an
+    /// [inverted](crate::Display::set_inverted) screen as well.
+    pub contrast_cycles_white: [u16; FRAME_COUNT],
+    /// High and low time of the pulse skipping a row, 1 to [`MAX_PULSE`].
+    pub skip_pulse: (u16, u16),
+    /// Number of black/white cycles of a clear, 1 to
+    /// [`DisplayConfig::MAX_CLEAR_CYCLES`].
+    pub clear_cycles: u16,
+    /// Output time of the frames of a clear, 1 to [`MAX_PULSE`].
+    pub clear_cycle_time: u16,
+    /// Timings of the power sequence of the panel rails.
+    pub power_sequence: PowerSequence,
+}
+
+impl Default for DisplayConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DisplayConfig {
+    /// Largest number of clear cycles.
+    pub const MAX_CLEAR_CYCLES: u16 = 100;
+
+    /// The default configuration.
+    pub fn new() -> Self {
+        DisplayConfig {
+            bus_clock: 10_000_000,
+            contrast_cycles: [
+                30, 30, 20, 20, 30, 30, 30, 40, 40, 50, 50, 50, 100, 200, 300,
+            ],
+            contrast_cycles_white: [10, 10, 8, 8, 8, 8, 8, 10, 10, 15, 15, 20, 20, 100, 300],
+            skip_pulse: (45, 5),
+            clear_cycles: 4,
+            clear_cycle_time: 50,
+            power_sequence: PowerSequence::default(),
+        }
+    }
+
+    /// Set the clock of the i8080 bus (in Hz).
+    pub fn with_bus_clock(self, hz: u32) -> Self {
+        DisplayConfig {
+            bus_clock: hz,
+            ..self
+        }
+    }
+
+    /// Set the output time of the frames in black on white and white on
+    /// white mode.
+    pub fn with_contrast_cycles(self, cycles: [u16; FRAME_COUNT]) -> Self {
+        DisplayConfig {
+            contrast_cycles: cycles,
+            ..self
+        }
+    }
+
+    /// Set the output time of the frames in white on black mode.
+    pub fn with_contrast_cycles_white(self, cycles: [u16; FRAME_COUNT]) -> Self {
+        DisplayConfig {
+            contrast_cycles_white: cycles,
+            ..self
+        }
+    }
+
+    /// Set the high and low time of the pulse skipping a row.
+    pub fn with_skip_pulse(self, high: u16, low: u16) -> Self {
+        DisplayConfig {
+            skip_pulse: (high, low),
+            ..self
+        }
+    }
+
+    /// Set the number of cycles and the output time of a clear.
+    pub fn with_clear_cycles(self, cycles: u16, cycle_time: u16) -> Self {
+        DisplayConfig {
+            clear_cycles: cycles,
+            clear_cycle_time: cycle_time,
+            ..self
+        }
+    }
+
+    /// Set the timings of the power sequence of the panel rails.
+    pub fn with_power_sequence(self, sequence: PowerSequence) -> Self {
+        DisplayConfig {
+            power_sequence: sequence,
+            ..self
+        }
+    }
+
+    /// Check that all values are within their valid ranges.
+    ///
+    /// Returns [`Error::InvalidConfig`] otherwise.
+    pub fn validate(&self) -> Result<()> {
+        let pulse = 1..=MAX_PULSE;
+        let valid = (1_000_000..=40_000_000).contains(&self.bus_clock)
+            && self.contrast_cycles.iter().all(|c| pulse.contains(c))
+            && self.contrast_cycles_white.iter().all(|c| pulse.contains(c))
+            && pulse.contains(&self.skip_pulse.0)
+            && pulse.contains(&self.skip_pulse.1)
+            && (1..=Self::MAX_CLEAR_CYCLES).contains(&self.clear_cycles)
+            && pulse.contains(&self.clear_cycle_time);
+        match valid {
+            true => Ok(()),
+            false => Err(Error::InvalidConfig),
+        }
+    }
+}
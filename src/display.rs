@@ -1,21 +1,23 @@
 use alloc::{boxed::Box, vec, vec::Vec};
 
-use esp_hal::{clock::Clocks, delay::Delay, peripheral::Peripheral, peripherals};
+#[cfg(feature = "board-v23")]
+use esp_hal::gpio::CreateErasedPin;
+use esp_hal::{clock::Clocks, delay::Delay, gpio::OutputPin, peripheral::Peripheral, peripherals};
 
-use crate::{ed047tc1, Error, Result};
-
-const CONTRAST_CYCLES_4BPP: &[u16; 15] = &[
-    30, 30, 20, 20, 30, 30, 30, 40, 40, 50, 50, 50, 100, 200, 300,
-];
-const CONTRAST_CYCLES_4BPP_WHITE: &[u16; 15] =
-    &[10, 10, 8, 8, 8, 8, 8, 10, 10, 15, 15, 20, 20, 100, 300];
-
-#[derive(Clone, Copy, Debug)]
-pub enum DrawMode {
-    BlackOnWhite,
-    WhiteOnWhite,
-    WhiteOnBlack,
-}
+pub use crate::draw_mode::DrawMode;
+use crate::{
+    board,
+    config::{self, DisplayConfig},
+    draw_mode::MixedRow,
+    ed047tc1,
+    image,
+    power::{PowerMode, Watchdog},
+    refresh::{Escalation, RefreshPolicy, RefreshState},
+    tone::ToneCurve,
+    waveform::{self, Waveform},
+    Error,
+    Result,
+};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Rectangle {
@@ -25,22 +27,6 @@ pub struct Rectangle {
     pub height: u16,
 }
 
-impl DrawMode {
-    fn lut_default(&self) -> u8 {
-        match self {
-            Self::BlackOnWhite => 0x55,
-            Self::WhiteOnBlack | Self::WhiteOnWhite => 0xAA,
-        }
-    }
-
-    fn contrast_cycles(&self) -> &[u16; 15] {
-        match self {
-            Self::WhiteOnBlack => CONTRAST_CYCLES_4BPP_WHITE,
-            Self::BlackOnWhite | Self::WhiteOnWhite => CONTRAST_CYCLES_4BPP,
-        }
-    }
-}
-
 const TAINTED_ROWS_SIZE: usize = Display::HEIGHT as usize / 8 + 1;
 const FRAMEBUFFER_SIZE: usize = (Display::WIDTH / 2) as usize * Display::HEIGHT as usize;
 const BYTES_PER_LINE: usize = Display::WIDTH as usize / 4;
@@ -48,9 +34,27 @@ const LINE_BYTES_4BPP: usize = Display::WIDTH as usize / 2;
 
 pub struct Display<'a> {
     epd: ed047tc1::ED047TC1<'a>,
+    config: DisplayConfig,
     skipping: u8,
+    contrast_scale: u8,
+    /// Calibrated output times of the grayscale frames, replacing the
+    /// contrast cycles of the config.
+    grayscale_cycles: Option<[u16; config::FRAME_COUNT]>,
+    tone_curve: ToneCurve,
+    inverted: bool,
     framebuffer: Box<[u8; FRAMEBUFFER_SIZE]>,
+    /// Content of the screen as far as the driver knows, same layout as the
+    /// framebuffer.
+    screen: Box<[u8; FRAMEBUFFER_SIZE]>,
     tainted_rows: [u8; TAINTED_ROWS_SIZE],
+    refresh: RefreshState,
+    power_mode: PowerMode,
+    powered: bool,
+    /// Time the display has been powered on in microseconds.
+    powered_since: u64,
+    max_on_time: Option<u32>,
+    watchdog: Option<Watchdog>,
+    delay: Delay,
 }
 
 impl<'a> Display<'a> {
@@ -65,31 +69,351 @@ impl<'a> Display<'a> {
         width: Self::WIDTH,
         height: Self::HEIGHT,
     };
-    pub fn new(
-        pins: ed047tc1::PinConfig,
+    /// Create the display driver.
+    ///
+    /// Returns [`Error::InvalidConfig`] if a value of the `config` is out of
+    /// range.
+    #[cfg(feature = "board-v23")]
+    pub fn new<CKV, DATA, CLK, STR>(
+        pins: board::PinConfig<CKV, DATA, CLK, STR>,
+        dma: impl Peripheral<P = peripherals::DMA> + 'a,
+        lcd_cam: impl Peripheral<P = peripherals::LCD_CAM> + 'a,
+        rmt: impl Peripheral<P = peripherals::RMT> + 'a,
+        clocks: &'a Clocks,
+        config: DisplayConfig,
+    ) -> Result<Self>
+    where
+        CKV: OutputPin + 'a,
+        DATA: OutputPin + CreateErasedPin + 'a,
+        CLK: OutputPin + CreateErasedPin + 'a,
+        STR: OutputPin + CreateErasedPin + 'a,
+    {
+        Self::from_parts(pins.split(clocks), dma, lcd_cam, rmt, clocks, config)
+    }
+
+    /// Create the display driver.
+    ///
+    /// Returns [`Error::InvalidConfig`] if a value of the `config` is out of
+    /// range.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn new<CKV: OutputPin + 'a>(
+        pins: board::PinConfig<CKV>,
         dma: impl Peripheral<P = peripherals::DMA> + 'a,
         lcd_cam: impl Peripheral<P = peripherals::LCD_CAM> + 'a,
         rmt: impl Peripheral<P = peripherals::RMT> + 'a,
         clocks: &'a Clocks,
-    ) -> Self {
-        Display {
-            epd: ed047tc1::ED047TC1::new(pins, dma, lcd_cam, rmt, clocks),
+        config: DisplayConfig,
+    ) -> Result<Self> {
+        Self::from_parts(pins.split(clocks), dma, lcd_cam, rmt, clocks, config)
+    }
+
+    fn from_parts<CKV: OutputPin + 'a>(
+        parts: board::Parts<'a, CKV>,
+        dma: impl Peripheral<P = peripherals::DMA> + 'a,
+        lcd_cam: impl Peripheral<P = peripherals::LCD_CAM> + 'a,
+        rmt: impl Peripheral<P = peripherals::RMT> + 'a,
+        clocks: &'a Clocks,
+        config: DisplayConfig,
+    ) -> Result<Self> {
+        config.validate()?;
+        Ok(Display {
+            epd: ed047tc1::ED047TC1::new(parts, dma, lcd_cam, rmt, clocks, &config)?,
+            config,
             skipping: 0,
+            contrast_scale: 100,
+            grayscale_cycles: None,
+            tone_curve: ToneCurve::LINEAR,
+            inverted: false,
             framebuffer: Box::new([0xFF; FRAMEBUFFER_SIZE]),
+            screen: Box::new([0xFF; FRAMEBUFFER_SIZE]),
             tainted_rows: [0; TAINTED_ROWS_SIZE],
-        }
+            refresh: RefreshState::new(RefreshPolicy::NEVER, now()),
+            power_mode: PowerMode::Manual,
+            powered: false,
+            powered_since: 0,
+            max_on_time: None,
+            watchdog: None,
+            delay: Delay::new(clocks),
+        })
     }
 
-    /// Turn the display on.
+    /// Turn the display on. Does nothing if the display is already on.
+    #[cfg(feature = "board-v23")]
     pub fn power_on(&mut self) {
-        self.epd.power_on()
+        // the shift register can't fail
+        let _ = self.try_power_on();
+    }
+
+    /// Turn the display on. Does nothing if the display is already on.
+    ///
+    /// Fails if the PMIC doesn't respond or the rails are not in regulation
+    /// in time.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn power_on(&mut self) -> Result<()> {
+        self.try_power_on()
+    }
+
+    /// Like [`Display::power_on`], but returns a [`Result`] on every board,
+    /// e.g. for code supporting several boards.
+    pub fn try_power_on(&mut self) -> Result<()> {
+        if !self.is_powered() {
+            self.epd.power_on()?;
+            self.powered = true;
+            self.powered_since = now();
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.start();
+            }
+        }
+        Ok(())
     }
 
     /// Turn the display off.
+    #[cfg(feature = "board-v23")]
     pub fn power_off(&mut self) {
+        // the shift register can't fail
+        let _ = self.try_power_off();
+    }
+
+    /// Turn the display off.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn power_off(&mut self) -> Result<()> {
+        self.try_power_off()
+    }
+
+    /// Like [`Display::power_off`], but returns a [`Result`] on every board.
+    pub fn try_power_off(&mut self) -> Result<()> {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.stop();
+        }
+        self.powered = false;
         self.epd.power_off()
     }
 
+    /// Set the timings of the power sequence of the panel rails, e.g. for
+    /// boards with different rail hardware.
+    pub fn set_power_sequence(&mut self, sequence: board::PowerSequence) {
+        self.epd.set_power_sequence(sequence)
+    }
+
+    /// The timings of the power sequence in use.
+    pub fn power_sequence(&self) -> board::PowerSequence {
+        self.epd.power_sequence()
+    }
+
+    /// The configuration passed to [`Display::new`].
+    pub fn config(&self) -> &DisplayConfig {
+        &self.config
+    }
+
+    /// Whether the display is powered on. `false` once the
+    /// [watchdog](Display::set_max_on_time) has powered it off.
+    pub fn is_powered(&self) -> bool {
+        self.powered && !self.watchdog.as_ref().is_some_and(|w| w.expired())
+    }
+
+    /// Set how the power of the display is managed, see [`PowerMode`].
+    pub fn set_power_mode(&mut self, mode: PowerMode) {
+        self.power_mode = mode;
+    }
+
+    /// The current power mode.
+    pub fn power_mode(&self) -> PowerMode {
+        self.power_mode
+    }
+
+    /// Limit the time (in seconds) the display may stay powered on, using a
+    /// hardware timer (e.g. a `SYSTIMER` alarm or a `TIMG` timer).
+    ///
+    /// Keeping the high voltage rails on for long periods can damage the
+    /// panel. The timer is armed whenever the display is powered on. Once it
+    /// expires, its interrupt powers the panel off like
+    /// [`emergency_power_off`](crate::power::emergency_power_off), without
+    /// the help of the application, even in the middle of an update. The
+    /// content of the screen is undefined afterwards. On the S3 Pro the
+    /// interrupt takes over the I2C pins, create the [`Display`] again to
+    /// use it afterwards.
+    ///
+    /// Returns [`Error::InvalidConfig`] if the timer can't count `seconds`.
+    /// A previously set timer is released.
+    pub fn set_max_on_time<T: esp_hal::timer::Timer>(
+        &mut self,
+        timer: T,
+        seconds: u32,
+    ) -> Result<Option<esp_hal::timer::ErasedTimer>> {
+        let previous = self.take_max_on_time();
+        self.watchdog = Some(Watchdog::new(timer, seconds)?);
+        if self.is_powered() {
+            if let Some(watchdog) = &self.watchdog {
+                watchdog.start();
+            }
+        }
+        Ok(previous)
+    }
+
+    /// The maximum time the display may stay powered on, enforced by the
+    /// timer set with [`Display::set_max_on_time`].
+    pub fn max_on_time(&self) -> Option<u32> {
+        self.watchdog.as_ref().map(Watchdog::seconds)
+    }
+
+    /// Remove the limit set with [`Display::set_max_on_time`] and release its
+    /// timer.
+    pub fn take_max_on_time(&mut self) -> Option<esp_hal::timer::ErasedTimer> {
+        self.watchdog.take().map(Watchdog::release)
+    }
+
+    /// Limit the time (in seconds) the display may stay powered on by
+    /// polling, `None` (default) for no limit. This is not a watchdog, prefer
+    /// [`Display::set_max_on_time`] if a timer is available.
+    ///
+    /// Nothing happens unless the driver is called: once the limit is
+    /// exceeded, the display is powered off by the next call updating the
+    /// screen or by [`Display::poll`]. If the application hangs or doesn't
+    /// call [`Display::poll`] periodically while the display is kept powered
+    /// on between updates, the rails stay on. Updates taking longer than the
+    /// limit (e.g. [`Display::repair`]) are not interrupted.
+    pub fn set_polled_max_on_time(&mut self, seconds: Option<u32>) {
+        self.max_on_time = seconds;
+    }
+
+    /// The maximum time the display may stay powered on, enforced by polling.
+    pub fn polled_max_on_time(&self) -> Option<u32> {
+        self.max_on_time
+    }
+
+    /// Enforce the [maximum on-time](Display::set_polled_max_on_time). Call
+    /// this periodically if the display is kept powered on between updates.
+    ///
+    /// Returns `true` if the display has been powered off.
+    pub fn poll(&mut self) -> Result<bool> {
+        let Some(max_on_time) = self.max_on_time else {
+            return Ok(false);
+        };
+        let on_time = now() - self.powered_since;
+        if !self.is_powered() || on_time < max_on_time as u64 * 1_000_000 {
+            return Ok(false);
+        }
+        self.try_power_off()?;
+        Ok(true)
+    }
+
+    /// Wait for the high voltage rails to settle after powering on.
+    pub(crate) fn settle(&self) {
+        self.delay.delay_millis(PowerMode::SETTLE_TIME_MS);
+    }
+
+    /// Run an operation updating the screen. Powers the display on and off
+    /// again in [`PowerMode::Auto`] if necessary. Operations nested in `f`
+    /// run with the display already powered on.
+    pub(crate) fn with_power<T>(&mut self, f: impl FnOnce(&mut Self) -> Result<T>) -> Result<T> {
+        self.poll()?;
+        if self.is_powered() {
+            return f(self);
+        }
+        match self.power_mode {
+            PowerMode::Manual => Err(Error::PoweredOff),
+            PowerMode::Auto => {
+                self.try_power_on()?;
+                self.settle();
+                let result = f(self);
+                let power_off = self.try_power_off();
+                let value = result?;
+                power_off.map(|_| value)
+            }
+        }
+    }
+
+    /// Read the panel temperature in degrees celsius. Returns `None` on
+    /// boards without a temperature sensor (V2.3).
+    pub fn temperature(&mut self) -> Result<Option<i8>> {
+        self.epd.temperature()
+    }
+
+    /// Access the PMIC, e.g. to read the fault interrupts.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn pmic(&mut self) -> &mut crate::tps65185::Tps65185<board::I2cBus<'a>> {
+        self.epd.pmic()
+    }
+
+    /// Set VCOM in millivolts. VCOM is negative, the value is the magnitude.
+    ///
+    /// The setting is kept by the driver and re-applied whenever the display is
+    /// powered on. Use [`Display::calibrate`] to find a suitable value.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn set_vcom(&mut self, millivolts: u16) -> Result<()> {
+        self.epd.set_vcom(millivolts)
+    }
+
+    /// VCOM in effect in millivolts, the value set via [`Display::set_vcom`]
+    /// or, if none has been set, the setting of the PMIC. Reading the PMIC
+    /// requires the display to be powered on.
+    #[cfg(feature = "board-s3-pro")]
+    pub fn vcom(&mut self) -> Result<u16> {
+        self.epd.vcom()
+    }
+
+    /// Scale the contrast cycle timings (in percent). Longer timings increase
+    /// the contrast, but also the time it takes to update the screen.
+    pub fn set_contrast_scale(&mut self, percent: u8) {
+        self.contrast_scale = percent;
+    }
+
+    /// Replace the output times of the frames in black on white and white on
+    /// white mode, e.g. by a table derived using
+    /// [`linearize_levels`](crate::calibration::linearize_levels). `None`
+    /// restores the contrast cycles of the [`DisplayConfig`].
+    ///
+    /// Returns [`Error::InvalidConfig`] if a time is not within 1 to
+    /// [`MAX_PULSE`](config::MAX_PULSE).
+    pub fn set_grayscale_cycles(
+        &mut self,
+        cycles: Option<[u16; config::FRAME_COUNT]>,
+    ) -> Result<()> {
+        if cycles.is_some_and(|cycles| cycles.iter().any(|&c| c == 0 || c > config::MAX_PULSE)) {
+            return Err(Error::InvalidConfig);
+        }
+        self.grayscale_cycles = cycles;
+        Ok(())
+    }
+
+    /// The output times of the frames in black on white and white on white
+    /// mode, before applying the contrast scale.
+    pub fn grayscale_cycles(&self) -> [u16; config::FRAME_COUNT] {
+        self.grayscale_cycles.unwrap_or(self.config.contrast_cycles)
+    }
+
+    /// Set the tone curve applied to the framebuffer on flush, the default is
+    /// [`ToneCurve::LINEAR`].
+    pub fn set_tone_curve(&mut self, curve: ToneCurve) {
+        self.tone_curve = curve;
+    }
+
+    /// The current tone curve.
+    pub fn tone_curve(&self) -> ToneCurve {
+        self.tone_curve
+    }
+
+    /// Invert the screen, i.e. render white on black (dark theme) using the
+    /// same drawing code.
+    ///
+    /// The framebuffer and the [`DrawMode`]s keep their meaning, `0xF` is
+    /// still the background, but it is drawn black and `0x0` is drawn white.
+    /// Clears (including the ones of the [`RefreshPolicy`]) turn the screen
+    /// black. The content of the screen is kept, call [`Display::clear`] to
+    /// switch the background.
+    pub fn set_inverted(&mut self, inverted: bool) {
+        if inverted != self.inverted {
+            // the screen didn't change, only the meaning of its levels
+            self.screen.iter_mut().for_each(|byte| *byte = !*byte);
+            self.inverted = inverted;
+        }
+    }
+
+    /// Whether the screen is inverted.
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
     /// Sets a single pixel in the framebuffer without updating the display.
     ///
     /// If the provided coordinates are outside the screen, this method returns
@@ -110,13 +434,29 @@ impl<'a> Display<'a> {
         } else {
             self.framebuffer[index] = (value & 0xF0) | (color & 0x0F);
         }
-        // taint row
-        let tainted_index = y as usize / TAINTED_ROWS_SIZE;
-        self.tainted_rows[tainted_index] |= 1 << (((y as u8) - (tainted_index as u8 * 8)) % 8);
+        self.taint_row(y);
         Ok(())
     }
 
-    /// Fill the whole framebuffer with the same color.
+    /// Reads a single pixel from the framebuffer.
+    ///
+    /// If the provided coordinates are outside the screen, this method returns
+    /// [Error::OutOfBounds].
+    pub fn pixel(&self, x: u16, y: u16) -> Result<u8> {
+        if x >= Self::WIDTH || y >= Self::HEIGHT {
+            return Err(Error::OutOfBounds);
+        }
+        let index: usize = x as usize / 2 + y as usize * (Self::WIDTH as usize / 2);
+        let value = self.framebuffer[index];
+        match x % 2 {
+            1 => Ok(value >> 4),
+            _ => Ok(value & 0x0F),
+        }
+    }
+
+    /// Fill the whole framebuffer with the same color. On an
+    /// [inverted](Display::set_inverted) screen, the color is inverted when
+    /// flushing.
     pub fn fill(&mut self, color: u8) -> Result<()> {
         if color > 0x0F {
             return Err(Error::InvalidColor);
@@ -129,14 +469,175 @@ impl<'a> Display<'a> {
     /// Flush updates the display with the contents of the framebuffer. The
     /// method clears the framebuffer. The provided mode should match the
     /// contents of your framebuffer.
+    ///
+    /// Depending on the [`RefreshPolicy`], the flush is escalated to a full
+    /// refresh of the screen or of the regions touched by the flush.
+    ///
+    /// Returns [`Error::PoweredOff`] if the display is powered off, unless
+    /// the [`PowerMode`] is [`PowerMode::Auto`].
     pub fn flush(&mut self, mode: DrawMode) -> Result<()> {
-        self.draw(mode)?;
+        self.with_power(|display| display.flush_powered(mode))
+    }
+
+    fn flush_powered(&mut self, mode: DrawMode) -> Result<()> {
+        self.apply_tone_curve();
+        self.update_screen(mode);
+        match self.refresh.flush(self.tainted_regions(), now()) {
+            Escalation::None => self.draw(mode)?,
+            Escalation::Full => self.redraw_regions(RefreshPolicy::ALL_REGIONS)?,
+            Escalation::Regions(regions) => {
+                for y in region_rows(regions) {
+                    self.untaint_row(y);
+                }
+                if self.tainted_rows.iter().any(|&rows| rows != 0) {
+                    self.draw(mode)?;
+                }
+                self.redraw_regions(regions)?;
+            }
+        }
+        self.tainted_rows.fill(0);
+        self.framebuffer.fill(0xFF);
+        Ok(())
+    }
+
+    /// Flush the framebuffer to the screen using a different mode for each
+    /// area in a single pass, e.g. [`DrawMode::BlackOnWhite`] for a photo and
+    /// [`DrawMode::WhiteOnWhite`] to erase a status bar next to it. Where
+    /// areas overlap, the later one wins. Content of the framebuffer outside
+    /// of all areas is discarded. Like [`Display::flush`], the method clears
+    /// the framebuffer and the [`RefreshPolicy`] applies.
+    ///
+    /// Each row is output for the same time across all of its pixels. Rows
+    /// shared by areas lightening the panel ([`DrawMode::WhiteOnBlack`], or
+    /// [`DrawMode::BlackOnWhite`] on an [inverted](Display::set_inverted)
+    /// screen) and other areas use the longer of their frame times, which
+    /// slightly increases the contrast of the other areas.
+    ///
+    /// Returns [`Error::PoweredOff`] if the display is powered off, unless
+    /// the [`PowerMode`] is [`PowerMode::Auto`].
+    pub fn flush_mixed(&mut self, areas: &[(Rectangle, DrawMode)]) -> Result<()> {
+        self.with_power(|display| display.flush_mixed_powered(areas))
+    }
+
+    fn flush_mixed_powered(&mut self, areas: &[(Rectangle, DrawMode)]) -> Result<()> {
+        for y in 0..Self::HEIGHT {
+            if !areas
+                .iter()
+                .any(|(area, _)| area.y <= y && y < area.y + area.height)
+            {
+                self.untaint_row(y);
+            }
+        }
+        self.apply_tone_curve();
+        self.update_screen_mixed(areas);
+        match self.refresh.flush(self.tainted_regions(), now()) {
+            Escalation::None => self.draw_mixed(areas)?,
+            Escalation::Full => self.redraw_regions(RefreshPolicy::ALL_REGIONS)?,
+            Escalation::Regions(regions) => {
+                for y in region_rows(regions) {
+                    self.untaint_row(y);
+                }
+                if self.tainted_rows.iter().any(|&rows| rows != 0) {
+                    self.draw_mixed(areas)?;
+                }
+                self.redraw_regions(regions)?;
+            }
+        }
         self.tainted_rows.fill(0);
         self.framebuffer.fill(0xFF);
         Ok(())
     }
 
-    /// Clears the screen.
+    /// Flush the framebuffer to the screen using a [`Waveform`], which drives
+    /// each pixel from its current level to the new one. Unlike
+    /// [`Display::flush`], the result doesn't depend on what was on the
+    /// screen before. The mode defines how the framebuffer is combined with
+    /// the content of the screen, the [`RefreshPolicy`] doesn't apply.
+    ///
+    /// The phases are picked by the temperature of the panel, or
+    /// [`DEFAULT_TEMPERATURE`](waveform::DEFAULT_TEMPERATURE) if the board
+    /// has no sensor.
+    ///
+    /// Returns [`Error::PoweredOff`] if the display is powered off, unless
+    /// the [`PowerMode`] is [`PowerMode::Auto`].
+    pub fn flush_waveform(&mut self, waveform: &Waveform, mode: DrawMode) -> Result<()> {
+        self.with_power(|display| display.flush_waveform_powered(waveform, mode))
+    }
+
+    fn flush_waveform_powered(&mut self, waveform: &Waveform, mode: DrawMode) -> Result<()> {
+        let temperature = self
+            .epd
+            .temperature()?
+            .unwrap_or(waveform::DEFAULT_TEMPERATURE);
+        let phases = waveform.phases(temperature);
+        self.apply_tone_curve();
+        // the framebuffer holds the target, the screen the current content
+        for y in 0..Self::HEIGHT {
+            if !self.is_tainted(y) {
+                continue;
+            }
+            let start = y as usize * LINE_BYTES_4BPP;
+            let screen = &self.screen[start..start + LINE_BYTES_4BPP];
+            let new = &mut self.framebuffer[start..start + LINE_BYTES_4BPP];
+            for (new, &screen) in new.iter_mut().zip(screen) {
+                *new = mode.merge(screen, *new);
+            }
+        }
+        for phase in 0..phases.len() {
+            let lut = phases.lut(phase);
+            let time = phases.time(phase).unwrap_or(waveform::DEFAULT_PHASE_TIME);
+            self.epd.frame_start()?;
+            for y in 0..Self::HEIGHT {
+                if !self.is_tainted(y) {
+                    self.epd.skip()?;
+                    continue;
+                }
+                let start = y as usize * LINE_BYTES_4BPP;
+                let end = start + LINE_BYTES_4BPP;
+                let row = waveform_row(
+                    &self.screen[start..end],
+                    &self.framebuffer[start..end],
+                    &lut,
+                    self.inverted,
+                );
+                self.epd.set_buffer(&row);
+                self.epd.output_row(time)?;
+            }
+            if self.skipping == 0 {
+                self.row_write(time)?;
+            }
+            self.epd.frame_end()?;
+        }
+        for y in 0..Self::HEIGHT {
+            if self.is_tainted(y) {
+                let start = y as usize * LINE_BYTES_4BPP;
+                let end = start + LINE_BYTES_4BPP;
+                self.screen[start..end].copy_from_slice(&self.framebuffer[start..end]);
+            }
+        }
+        self.tainted_rows.fill(0);
+        self.framebuffer.fill(0xFF);
+        Ok(())
+    }
+
+    /// Set the policy for automatic full refreshes, the default is
+    /// [`RefreshPolicy::NEVER`].
+    pub fn set_refresh_policy(&mut self, policy: RefreshPolicy) {
+        self.refresh.policy = policy;
+    }
+
+    /// The current refresh policy.
+    pub fn refresh_policy(&self) -> RefreshPolicy {
+        self.refresh.policy
+    }
+
+    /// Number of partial flushes since the last full refresh.
+    pub fn partial_flushes(&self) -> u16 {
+        self.refresh.partial_flushes()
+    }
+
+    /// Clears the screen, to black if the screen is
+    /// [inverted](Display::set_inverted).
     pub fn clear(&mut self) -> Result<()> {
         self.clear_area(Self::BOUNDING_BOX)
     }
@@ -144,6 +645,10 @@ impl<'a> Display<'a> {
     /// Performs the screen repair routine as described here
     /// https://github.com/Xinyuan-LilyGO/LilyGo-EPD47/blob/master/examples/screen_repair/screen_repair.ino
     pub fn repair(&mut self, delay: Delay) -> Result<()> {
+        self.with_power(|display| display.repair_powered(delay))
+    }
+
+    fn repair_powered(&mut self, delay: Delay) -> Result<()> {
         self.clear()?;
         for _ in 0..20 {
             self.push_pixels(Self::BOUNDING_BOX, 50, 0)?;
@@ -157,17 +662,146 @@ impl<'a> Display<'a> {
         self.clear()
     }
 
+    /// Clears an area of the screen, to black if the screen is
+    /// [inverted](Display::set_inverted).
+    ///
+    /// Returns [`Error::PoweredOff`] if the display is powered off, unless
+    /// the [`PowerMode`] is [`PowerMode::Auto`].
     pub fn clear_area(&mut self, area: Rectangle) -> Result<()> {
-        self.clear_cycles(area, 4, 50)
+        self.with_power(|display| display.clear_cycles(area))?;
+        let right = (area.x + area.width).min(Self::WIDTH);
+        let bottom = (area.y + area.height).min(Self::HEIGHT);
+        if area.x >= right {
+            return Ok(());
+        }
+        for y in area.y..bottom {
+            let start = y as usize * LINE_BYTES_4BPP;
+            let row = &mut self.screen[start..start + LINE_BYTES_4BPP];
+            image::fill_row(row, area.x as usize, (right - area.x) as usize, 0x0F);
+        }
+        // regions which have been cleared completely
+        if area.x == 0 && right == Self::WIDTH {
+            let regions = (0..RefreshPolicy::REGIONS)
+                .filter(|&region| {
+                    let top = region as u16 * RefreshPolicy::REGION_HEIGHT;
+                    area.y <= top && top + RefreshPolicy::REGION_HEIGHT <= bottom
+                })
+                .fold(0, |regions, region| regions | 1 << region);
+            match regions {
+                RefreshPolicy::ALL_REGIONS => self.refresh.reset_all(now()),
+                regions => self.refresh.reset_regions(regions),
+            }
+        }
+        Ok(())
     }
 
-    fn clear_cycles(&mut self, area: Rectangle, cycles: u16, cycle_time: u16) -> Result<()> {
-        for _ in 0..cycles {
+    /// Map the tainted rows of the framebuffer using the tone curve.
+    fn apply_tone_curve(&mut self) {
+        if self.tone_curve.is_linear() {
+            return;
+        }
+        let lut = self.tone_curve.byte_lut();
+        for y in 0..Self::HEIGHT {
+            if !self.is_tainted(y) {
+                continue;
+            }
+            let start = y as usize * LINE_BYTES_4BPP;
+            for byte in &mut self.framebuffer[start..start + LINE_BYTES_4BPP] {
+                *byte = lut[*byte as usize];
+            }
+        }
+    }
+
+    /// Merge the tainted rows of the framebuffer into the screen content, as
+    /// they will look after drawing them using the provided mode.
+    fn update_screen(&mut self, mode: DrawMode) {
+        for y in 0..Self::HEIGHT {
+            if !self.is_tainted(y) {
+                continue;
+            }
+            let start = y as usize * LINE_BYTES_4BPP;
+            let new = &self.framebuffer[start..start + LINE_BYTES_4BPP];
+            let screen = &mut self.screen[start..start + LINE_BYTES_4BPP];
+            for (screen, &new) in screen.iter_mut().zip(new) {
+                *screen = mode.merge(*screen, new);
+            }
+        }
+    }
+
+    /// Like [`Display::update_screen`], using the mode of the area covering
+    /// each pixel.
+    fn update_screen_mixed(&mut self, areas: &[(Rectangle, DrawMode)]) {
+        for y in 0..Self::HEIGHT {
+            if !self.is_tainted(y) {
+                continue;
+            }
+            let owners = row_owners(areas, y);
+            let start = y as usize * LINE_BYTES_4BPP;
+            let new = &self.framebuffer[start..start + LINE_BYTES_4BPP];
+            let screen = &mut self.screen[start..start + LINE_BYTES_4BPP];
+            for (i, (screen, &new)) in screen.iter_mut().zip(new).enumerate() {
+                let mut merged = *screen;
+                for pixel in 0..2 {
+                    let Some(mode) = owners[2 * i + pixel] else {
+                        continue;
+                    };
+                    let mask = 0x0F << (4 * pixel);
+                    merged = (merged & !mask) | (mode.merge(*screen, new) & mask);
+                }
+                *screen = merged;
+            }
+        }
+    }
+
+    /// Bit mask of the regions containing tainted rows.
+    fn tainted_regions(&self) -> u16 {
+        (0..Self::HEIGHT)
+            .filter(|&y| self.is_tainted(y))
+            .fold(0, |regions, y| {
+                regions | 1 << (y / RefreshPolicy::REGION_HEIGHT)
+            })
+    }
+
+    /// Clear the regions (bit mask) and redraw their content.
+    fn redraw_regions(&mut self, regions: u16) -> Result<()> {
+        if regions == RefreshPolicy::ALL_REGIONS {
+            self.clear_cycles(Self::BOUNDING_BOX)?;
+        } else {
+            for region in 0..RefreshPolicy::REGIONS as u16 {
+                if regions & 1 << region != 0 {
+                    let area = Rectangle {
+                        x: 0,
+                        y: region * RefreshPolicy::REGION_HEIGHT,
+                        width: Self::WIDTH,
+                        height: RefreshPolicy::REGION_HEIGHT,
+                    };
+                    self.clear_cycles(area)?;
+                }
+            }
+        }
+        self.tainted_rows.fill(0);
+        for y in region_rows(regions) {
+            let start = y as usize * LINE_BYTES_4BPP;
+            let end = start + LINE_BYTES_4BPP;
+            self.framebuffer[start..end].copy_from_slice(&self.screen[start..end]);
+            self.taint_row(y);
+        }
+        self.draw(DrawMode::BlackOnWhite)
+    }
+
+    fn clear_cycles(&mut self, area: Rectangle) -> Result<()> {
+        let cycle_time = self.config.clear_cycle_time;
+        // end on white (1), or black (0) if inverted
+        let (first, last) = match self.inverted {
+            false => (0, 1),
+            true => (1, 0),
+        };
+        for _ in 0..self.config.clear_cycles {
             for _ in 0..4 {
-                self.push_pixels(area, cycle_time, 0)?;
+                self.push_pixels(area, cycle_time, first)?;
             }
             for _ in 0..4 {
-                self.push_pixels(area, cycle_time, 1)?;
+                self.push_pixels(area, cycle_time, last)?;
             }
         }
         Ok(())
@@ -235,21 +869,68 @@ impl<'a> Display<'a> {
         Ok(())
     }
 
+    /// Content of the screen as far as the driver knows.
+    pub(crate) fn screen(&self) -> &[u8] {
+        self.screen.as_slice()
+    }
+
+    pub(crate) fn screen_mut(&mut self) -> &mut [u8] {
+        self.screen.as_mut_slice()
+    }
+
+    pub(crate) fn refresh_state(&self) -> &RefreshState {
+        &self.refresh
+    }
+
+    pub(crate) fn refresh_state_mut(&mut self) -> &mut RefreshState {
+        &mut self.refresh
+    }
+
+    fn taint_row(&mut self, row: u16) {
+        self.tainted_rows[row as usize / 8] |= 1 << (row % 8);
+    }
+
+    fn untaint_row(&mut self, row: u16) {
+        self.tainted_rows[row as usize / 8] &= !(1 << (row % 8));
+    }
+
+    /// Mutable access to a single row of the framebuffer (two pixels per
+    /// byte, the low nibble holds the left pixel). The row is marked as
+    /// tainted.
+    pub(crate) fn row_mut(&mut self, row: u16) -> &mut [u8] {
+        self.taint_row(row);
+        let start = row as usize * LINE_BYTES_4BPP;
+        &mut self.framebuffer[start..start + LINE_BYTES_4BPP]
+    }
+
     fn is_tainted(&self, row: u16) -> bool {
-        let index = row as usize / TAINTED_ROWS_SIZE;
-        self.tainted_rows[index] & (1 << (((row as u8) - (index as u8 * 8)) % 8)) != 0
+        self.tainted_rows[row as usize / 8] & (1 << (row % 8)) != 0
+    }
+
+    /// Output time of a frame driving the panel in `mode`, i.e. the mode
+    /// returned by [`DrawMode::drive`].
+    fn contrast_cycles(&self, mode: DrawMode, frame: usize) -> u16 {
+        let cycles = match (mode, &self.grayscale_cycles) {
+            (DrawMode::BlackOnWhite | DrawMode::WhiteOnWhite, Some(cycles)) => cycles[frame],
+            _ => mode.contrast_cycles(&self.config)[frame],
+        } as u32;
+        (cycles * self.contrast_scale as u32 / 100).min(config::MAX_PULSE as u32) as u16
     }
 
-    const DRAW_IMAGE_FRAME_COUNT: usize = 15;
+    const DRAW_IMAGE_FRAME_COUNT: usize = config::FRAME_COUNT;
     fn draw(&mut self, mode: DrawMode) -> Result<()> {
         // let start = esp_hal::time::current_time();
 
+        // the timings follow the direction the panel is driven in, e.g. black
+        // on white content of an inverted screen is drawn by lightening
+        let (drive_mode, invert) = mode.drive(self.inverted);
+
         // init lut
-        let mut lut = vec![mode.lut_default(); 1 << 16];
+        let mut lut = vec![drive_mode.lut_default(); 1 << 16];
 
         for k in 0..Self::DRAW_IMAGE_FRAME_COUNT {
             // update lut
-            update_lut(&mut lut, k, mode);
+            update_lut(&mut lut, k, drive_mode);
             // start draw
             self.epd.frame_start()?;
             // build line
@@ -261,12 +942,12 @@ impl<'a> Display<'a> {
                 let start = y as usize * LINE_BYTES_4BPP;
                 let end = start + LINE_BYTES_4BPP;
                 // draw
-                let buf = prepare_dma_buffer(&self.framebuffer[start..end], &lut);
+                let buf = prepare_dma_buffer(&self.framebuffer[start..end], &lut, invert);
                 self.epd.set_buffer(buf.as_slice());
-                self.epd.output_row(mode.contrast_cycles()[k])?;
+                self.epd.output_row(self.contrast_cycles(drive_mode, k))?;
             }
             if self.skipping == 0 {
-                self.row_write(mode.contrast_cycles()[k])?;
+                self.row_write(self.contrast_cycles(drive_mode, k))?;
             }
             self.epd.frame_end()?;
         }
@@ -276,6 +957,94 @@ impl<'a> Display<'a> {
         // );
         Ok(())
     }
+
+    /// Like [`Display::draw`], selecting the lookup table of each pixel by
+    /// the mode of the area covering it.
+    fn draw_mixed(&mut self, areas: &[(Rectangle, DrawMode)]) -> Result<()> {
+        // one lut per mode in use
+        let mut luts: [Option<Vec<u8>>; 3] = Default::default();
+        for (_, mode) in areas {
+            let (drive_mode, _) = mode.drive(self.inverted);
+            luts[mode.index()].get_or_insert_with(|| vec![drive_mode.lut_default(); 1 << 16]);
+        }
+
+        for k in 0..Self::DRAW_IMAGE_FRAME_COUNT {
+            for (index, lut) in luts.iter_mut().enumerate() {
+                if let Some(lut) = lut {
+                    update_lut(lut, k, DrawMode::ALL[index].drive(self.inverted).0);
+                }
+            }
+            self.epd.frame_start()?;
+            let mut frame_time = 0;
+            for y in 0..Self::HEIGHT {
+                if !self.is_tainted(y) {
+                    self.epd.skip()?;
+                    continue;
+                }
+                let start = y as usize * LINE_BYTES_4BPP;
+                let end = start + LINE_BYTES_4BPP;
+                let mixed = MixedRow::new(areas, y);
+                let time = mixed.time(|mode| self.contrast_cycles(mode.drive(self.inverted).0, k));
+                if time == 0 {
+                    // the areas don't cover any pixel of the row
+                    self.epd.skip()?;
+                    continue;
+                }
+                let mut row = [0u8; BYTES_PER_LINE];
+                for mode in mixed.modes() {
+                    let Some(lut) = &luts[mode.index()] else {
+                        continue;
+                    };
+                    let (_, invert) = mode.drive(self.inverted);
+                    let buf = prepare_dma_buffer(&self.framebuffer[start..end], lut, invert);
+                    for (x, owner) in mixed.owners.iter().enumerate() {
+                        if *owner == Some(mode) {
+                            row[x / 4] |= buf[x / 4] & (0b11 << (2 * (x % 4)));
+                        }
+                    }
+                }
+                self.epd.set_buffer(&row);
+                self.epd.output_row(time)?;
+                frame_time = frame_time.max(time);
+            }
+            if self.skipping == 0 && frame_time > 0 {
+                self.row_write(frame_time)?;
+            }
+            self.epd.frame_end()?;
+        }
+        Ok(())
+    }
+}
+
+/// System time in microseconds.
+pub(crate) fn now() -> u64 {
+    esp_hal::time::current_time().ticks()
+}
+
+/// Rows of the regions (bit mask).
+fn region_rows(regions: u16) -> impl Iterator<Item = u16> {
+    (0..Display::HEIGHT).filter(move |y| regions & 1 << (y / RefreshPolicy::REGION_HEIGHT) != 0)
+}
+
+/// Output of a row during a waveform phase, from the current (`from`) and
+/// the target (`to`) content of the row. If `invert` is set, the levels are
+/// inverted.
+fn waveform_row(from: &[u8], to: &[u8], lut: &[u8; 256], invert: bool) -> [u8; BYTES_PER_LINE] {
+    let flip = match invert {
+        true => 0x0F,
+        false => 0x00,
+    };
+    let mut row = [0u8; BYTES_PER_LINE];
+    for (i, out) in row.iter_mut().enumerate() {
+        for pixel in 0..4 {
+            let byte = 2 * i + pixel / 2;
+            let shift = 4 * (pixel % 2);
+            let from = ((from[byte] >> shift) & 0x0F) ^ flip;
+            let to = ((to[byte] >> shift) & 0x0F) ^ flip;
+            *out |= lut[(to << 4 | from) as usize] << (2 * pixel);
+        }
+    }
+    row
 }
 
 fn line_buffer_reorder(data: &mut [u8]) {
@@ -289,13 +1058,17 @@ fn line_buffer_reorder(data: &mut [u8]) {
     }
 }
 
-fn prepare_dma_buffer(line_data: &[u8], conversion_lut: &[u8]) -> Vec<u8> {
+fn prepare_dma_buffer(line_data: &[u8], conversion_lut: &[u8], invert: bool) -> Vec<u8> {
+    let flip = match invert {
+        true => 0xFFFF,
+        false => 0x0000,
+    };
     let mut epd_input = vec![0u8; BYTES_PER_LINE];
     let mut wide_epd_input: Vec<u32> = vec![0u32; Display::WIDTH as usize / 16];
 
     let line_data_16: Vec<u16> = line_data
         .chunks(2)
-        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+        .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]) ^ flip)
         .collect();
 
     for (j, chunk) in line_data_16.chunks(4).enumerate() {
@@ -0,0 +1,117 @@
+//! Draw modes, i.e. how new content is combined with the screen, and how the
+//! panel is driven for them.
+
+use crate::{
+    config::{self, DisplayConfig},
+    display::{Display, Rectangle},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DrawMode {
+    BlackOnWhite,
+    WhiteOnWhite,
+    WhiteOnBlack,
+}
+
+impl DrawMode {
+    /// All modes, in the order of [`DrawMode::index`].
+    pub(crate) const ALL: [DrawMode; 3] =
+        [Self::BlackOnWhite, Self::WhiteOnWhite, Self::WhiteOnBlack];
+
+    pub(crate) fn index(&self) -> usize {
+        match self {
+            Self::BlackOnWhite => 0,
+            Self::WhiteOnWhite => 1,
+            Self::WhiteOnBlack => 2,
+        }
+    }
+
+    pub(crate) fn lut_default(&self) -> u8 {
+        match self {
+            Self::BlackOnWhite => 0x55,
+            Self::WhiteOnBlack | Self::WhiteOnWhite => 0xAA,
+        }
+    }
+
+    /// Content of two pixels (one byte) on the screen after drawing `new`
+    /// onto `screen`.
+    pub(crate) fn merge(&self, screen: u8, new: u8) -> u8 {
+        let merge = |screen: u8, new: u8| match self {
+            Self::BlackOnWhite => screen.min(new),
+            Self::WhiteOnBlack => screen.max(new),
+            // pixels are lightened by the inverse of the value
+            Self::WhiteOnWhite if new == 0x0F => screen,
+            Self::WhiteOnWhite => (screen + 0x0F - new).min(0x0F),
+        };
+        merge(screen >> 4, new >> 4) << 4 | merge(screen & 0x0F, new & 0x0F)
+    }
+
+    /// Mode and whether the levels are inverted to drive the panel, for
+    /// content drawn using this mode on an
+    /// [inverted](crate::Display::set_inverted) screen or not.
+    pub(crate) fn drive(&self, inverted: bool) -> (DrawMode, bool) {
+        match (inverted, self) {
+            (false, _) => (*self, false),
+            // darkening becomes lightening and vice versa
+            (true, Self::BlackOnWhite) => (Self::WhiteOnBlack, true),
+            (true, Self::WhiteOnBlack) => (Self::BlackOnWhite, true),
+            // lightening by 15 - v becomes darkening by 15 - v
+            (true, Self::WhiteOnWhite) => (Self::BlackOnWhite, false),
+        }
+    }
+
+    /// The contrast cycles of the config for driving the panel in this mode.
+    /// Pass the mode returned by [`DrawMode::drive`], the cycles depend on
+    /// whether the panel is driven from black to white or the other way
+    /// around.
+    pub(crate) fn contrast_cycles<'c>(
+        &self,
+        config: &'c DisplayConfig,
+    ) -> &'c [u16; config::FRAME_COUNT] {
+        match self {
+            Self::WhiteOnBlack => &config.contrast_cycles_white,
+            Self::BlackOnWhite | Self::WhiteOnWhite => &config.contrast_cycles,
+        }
+    }
+}
+
+/// A row of areas drawn in different modes.
+pub(crate) struct MixedRow {
+    /// Mode of the area covering each pixel of the row, later areas take
+    /// precedence.
+    pub(crate) owners: [Option<DrawMode>; Display::WIDTH as usize],
+    /// Whether pixels of the row are drawn in a mode, by [`DrawMode::index`].
+    used: [bool; 3],
+}
+
+impl MixedRow {
+    /// Row `y` of the `areas`.
+    pub(crate) fn new(areas: &[(Rectangle, DrawMode)], y: u16) -> Self {
+        let mut owners = [None; Display::WIDTH as usize];
+        for (area, mode) in areas {
+            if area.y <= y && y < area.y + area.height {
+                let left = area.x.min(Display::WIDTH) as usize;
+                let right = (area.x + area.width).min(Display::WIDTH) as usize;
+                owners[left..right].fill(Some(*mode));
+            }
+        }
+        let mut used = [false; 3];
+        for mode in owners.iter().flatten() {
+            used[mode.index()] = true;
+        }
+        MixedRow { owners, used }
+    }
+
+    /// The modes of the pixels of the row.
+    pub(crate) fn modes(&self) -> impl Iterator<Item = DrawMode> + '_ {
+        DrawMode::ALL
+            .into_iter()
+            .filter(|mode| self.used[mode.index()])
+    }
+
+    /// Output time of the row, the longest of the output times `time` of
+    /// its modes. 0 if the areas don't cover any pixel of the row.
+    pub(crate) fn time(&self, time: impl Fn(DrawMode) -> u16) -> u16 {
+        self.modes().map(time).max().unwrap_or(0)
+    }
+}
@@ -2,9 +2,8 @@ use core::ptr::addr_of_mut;
 
 use esp_hal::{
     clock::Clocks,
-    dma::{self},
-    dma_buffers,
-    gpio::{GpioPin, Level, Output, OutputPin},
+    dma::{self, DmaDescriptor},
+    gpio::OutputPin,
     lcd_cam::{lcd::i8080, LcdCam},
     peripheral::Peripheral,
     peripherals,
@@ -12,148 +11,43 @@ use esp_hal::{
     Blocking,
 };
 
-use crate::rmt;
+use crate::{board, config::DisplayConfig, rmt};
 
+/// A row of 2 bits per pixel, padded, sent in a single transfer. The size is
+/// fixed, see the [config](crate::config) module.
 const DMA_BUFFER_SIZE: usize = 248;
+/// A descriptor transfers at most 4092 bytes, i.e. one covers a row.
+const DMA_DESCRIPTOR_COUNT: usize = DMA_BUFFER_SIZE.div_ceil(4092);
 
 fn dma_buffer() -> &'static mut [u8; DMA_BUFFER_SIZE] {
     static mut BUFFER: [u8; DMA_BUFFER_SIZE] = [0u8; DMA_BUFFER_SIZE];
     unsafe { &mut *addr_of_mut!(BUFFER) }
 }
 
-struct ConfigRegister {
-    latch_enable: bool,
-    power_disable: bool,
-    pos_power_enable: bool,
-    neg_power_enable: bool,
-    stv: bool,
-    power_enable: bool, /* scan_direction, see https://github.com/vroland/epdiy/blob/main/src/board/epd_board_lilygo_t5_47.c#L199 */
-    mode: bool,
-    output_enable: bool,
-}
-
-impl Default for ConfigRegister {
-    fn default() -> Self {
-        ConfigRegister {
-            latch_enable: false,
-            power_disable: true,
-            pos_power_enable: false,
-            neg_power_enable: false,
-            stv: true,
-            power_enable: false,
-            mode: false,
-            output_enable: false,
-        }
-    }
-}
-
-struct ConfigWriter<'a, DATA, CLK, STR>
-where
-    DATA: OutputPin,
-    CLK: OutputPin,
-    STR: OutputPin,
-{
-    pin_data: Output<'a, DATA>,
-    pin_clk: Output<'a, CLK>,
-    pin_str: Output<'a, STR>,
-    config: ConfigRegister,
-}
-
-impl<'a, DATA, CLK, STR> ConfigWriter<'a, DATA, CLK, STR>
-where
-    DATA: OutputPin,
-    CLK: OutputPin,
-    STR: OutputPin,
-{
-    fn new(
-        data: impl Peripheral<P = DATA> + 'a,
-        clk: impl Peripheral<P = CLK> + 'a,
-        str: impl Peripheral<P = STR> + 'a,
-    ) -> Self {
-        ConfigWriter {
-            pin_data: Output::new(data, Level::High),
-            pin_clk: Output::new(clk, Level::High),
-            pin_str: Output::new(str, Level::Low),
-            config: ConfigRegister::default(),
-        }
-    }
-
-    fn write(&mut self) {
-        self.pin_str.set_low();
-        self.write_bool(self.config.output_enable);
-        self.write_bool(self.config.mode);
-        self.write_bool(self.config.power_enable);
-        self.write_bool(self.config.stv);
-        self.write_bool(self.config.neg_power_enable);
-        self.write_bool(self.config.pos_power_enable);
-        self.write_bool(self.config.power_disable);
-        self.write_bool(self.config.latch_enable);
-        self.pin_str.set_high();
-    }
-
-    #[inline(always)]
-    fn write_bool(&mut self, v: bool) {
-        self.pin_clk.set_low();
-        self.pin_data.set_level(match v {
-            true => Level::High,
-            false => Level::Low,
-        });
-        self.pin_clk.set_high();
-    }
-}
-
-pub struct PinConfig {
-    pub data0: GpioPin<6>,
-    pub data1: GpioPin<7>,
-    pub data2: GpioPin<4>,
-    pub data3: GpioPin<5>,
-    pub data4: GpioPin<2>,
-    pub data5: GpioPin<3>,
-    pub data6: GpioPin<8>,
-    pub data7: GpioPin<1>,
-    pub cfg_data: GpioPin<13>,
-    pub cfg_clk: GpioPin<12>,
-    pub cfg_str: GpioPin<0>,
-    pub lcd_dc: GpioPin<40>,
-    pub lcd_wrx: GpioPin<41>,
-    pub rmt: GpioPin<38>,
+/// Descriptors of the row transfers. Like the buffer they are kept in internal
+/// RAM, the DMA can't fetch descriptors from PSRAM.
+fn dma_descriptors() -> &'static mut [DmaDescriptor; DMA_DESCRIPTOR_COUNT] {
+    static mut DESCRIPTORS: [DmaDescriptor; DMA_DESCRIPTOR_COUNT] =
+        [DmaDescriptor::EMPTY; DMA_DESCRIPTOR_COUNT];
+    unsafe { &mut *addr_of_mut!(DESCRIPTORS) }
 }
 
 pub(crate) struct ED047TC1<'a> {
-    i8080: i8080::I8080<
-        'a,
-        dma::DmaChannel0,
-        i8080::TxEightBits<
-            'a,
-            GpioPin<6>,
-            GpioPin<7>,
-            GpioPin<4>,
-            GpioPin<5>,
-            GpioPin<2>,
-            GpioPin<3>,
-            GpioPin<8>,
-            GpioPin<1>,
-        >,
-        Blocking,
-    >,
-    cfg_writer: ConfigWriter<'a, GpioPin<13>, GpioPin<12>, GpioPin<0>>,
-    rmt: rmt::Rmt<'a>,
+    i8080: i8080::I8080<'a, dma::DmaChannel0, board::TxPins<'a>, Blocking>,
+    control: board::Control<'a>,
+    rmt: rmt::Rmt,
+    skip_pulse: (u16, u16),
 }
 
 impl<'a> ED047TC1<'a> {
-    pub(crate) fn new(
-        pins: PinConfig,
+    pub(crate) fn new<CKV: OutputPin + 'a>(
+        parts: board::Parts<'a, CKV>,
         dma: impl Peripheral<P = peripherals::DMA> + 'a,
         lcd_cam: impl Peripheral<P = peripherals::LCD_CAM> + 'a,
         rmt: impl Peripheral<P = peripherals::RMT> + 'a,
         clocks: &'a Clocks,
-    ) -> Self {
-        // configure data pins
-        let tx_pins = i8080::TxEightBits::new(
-            pins.data0, pins.data1, pins.data2, pins.data3, pins.data4, pins.data5, pins.data6,
-            pins.data7,
-        );
-
+        config: &DisplayConfig,
+    ) -> crate::Result<Self> {
         // configure dma
         let dma = dma::Dma::new(dma);
         let channel = dma.channel0.configure(false, dma::DmaPriority::Priority0);
@@ -161,19 +55,13 @@ impl<'a> ED047TC1<'a> {
         // init lcd
         let lcd_cam = LcdCam::new(lcd_cam);
 
-        // init panel config writer (?)
-        let mut cfg_writer = ConfigWriter::new(pins.cfg_data, pins.cfg_clk, pins.cfg_str);
-        cfg_writer.write();
-
-        let (_tx_buffer, tx_descriptors, _, _rx_descriptors) = dma_buffers!(32000, 0);
-
-        let ctrl = ED047TC1 {
+        let mut ctrl = ED047TC1 {
             i8080: i8080::I8080::new(
                 lcd_cam.lcd,
                 channel.tx,
-                tx_descriptors,
-                tx_pins,
-                10.MHz(),
+                dma_descriptors(),
+                parts.tx_pins,
+                config.bus_clock.Hz(),
                 i8080::Config {
                     cd_idle_edge: false,  // dc_idle_level
                     cd_cmd_edge: true,    // dc_cmd_level
@@ -183,78 +71,77 @@ impl<'a> ED047TC1<'a> {
                 },
                 clocks,
             )
-            .with_ctrl_pins(pins.lcd_dc, pins.lcd_wrx),
-            cfg_writer,
-            rmt: rmt::Rmt::new(rmt, clocks),
+            .with_ctrl_pins(parts.lcd_dc, parts.lcd_wrx),
+            control: parts.control,
+            rmt: rmt::Rmt::new(rmt, parts.ckv, clocks)?,
+            skip_pulse: config.skip_pulse,
         };
-        ctrl
+        ctrl.control.set_power_sequence(config.power_sequence);
+        Ok(ctrl)
     }
 
-    pub(crate) fn power_on(&mut self) {
-        self.cfg_writer.config.power_enable = true;
-        self.cfg_writer.config.power_disable = false;
-        self.cfg_writer.write();
-        busy_delay(100 * 240);
-        self.cfg_writer.config.neg_power_enable = true;
-        self.cfg_writer.write();
-        busy_delay(500 * 240);
-        self.cfg_writer.config.pos_power_enable = true;
-        self.cfg_writer.write();
-        busy_delay(100 * 240);
-        self.cfg_writer.config.stv = true;
-        self.cfg_writer.write();
+    pub(crate) fn power_on(&mut self) -> crate::Result<()> {
+        self.control.power_on()
     }
 
-    pub(crate) fn power_off(&mut self) {
-        self.cfg_writer.config.power_enable = false;
-        self.cfg_writer.config.pos_power_enable = false;
-        self.cfg_writer.write();
-        busy_delay(10 * 240);
-        self.cfg_writer.config.neg_power_enable = false;
-        self.cfg_writer.write();
-        busy_delay(100 * 240);
-        self.cfg_writer.config.power_disable = true;
-        self.cfg_writer.config.mode = false;
-        // self.cfg_writer.write();
-        self.cfg_writer.config.stv = false;
-        self.cfg_writer.write();
+    pub(crate) fn power_off(&mut self) -> crate::Result<()> {
+        self.control.power_off()
+    }
+
+    pub(crate) fn set_power_sequence(&mut self, sequence: board::PowerSequence) {
+        self.control.set_power_sequence(sequence)
+    }
+
+    pub(crate) fn power_sequence(&self) -> board::PowerSequence {
+        self.control.power_sequence()
+    }
+
+    pub(crate) fn temperature(&mut self) -> crate::Result<Option<i8>> {
+        self.control.temperature()
+    }
+
+    #[cfg(feature = "board-s3-pro")]
+    pub(crate) fn pmic(&mut self) -> &mut crate::tps65185::Tps65185<board::I2cBus<'a>> {
+        self.control.pmic()
+    }
+
+    #[cfg(feature = "board-s3-pro")]
+    pub(crate) fn set_vcom(&mut self, millivolts: u16) -> crate::Result<()> {
+        self.control.set_vcom(millivolts)
+    }
+
+    #[cfg(feature = "board-s3-pro")]
+    pub(crate) fn vcom(&mut self) -> crate::Result<u16> {
+        self.control.vcom()
     }
 
     pub(crate) fn frame_start(&mut self) -> crate::Result<()> {
-        self.cfg_writer.config.mode = true;
-        self.cfg_writer.write();
+        self.control.set_mode(true)?;
 
         self.rmt.pulse(10, 10, true)?;
 
-        self.cfg_writer.config.stv = false;
-        self.cfg_writer.write();
-        // busy_delay(240);
+        self.control.set_stv(false)?;
         self.rmt.pulse(10000, 1000, false)?;
-        self.cfg_writer.config.stv = true;
-        self.cfg_writer.write();
-        // self.rmt.pulse(0, 100, true)?;
-        self.rmt.pulse(10, 10, true)?;
-        self.rmt.pulse(10, 10, true)?;
-        self.rmt.pulse(10, 10, true)?;
+        self.control.set_stv(true)?;
+        // 4 gate clocks until the first row
+        for _ in 0..3 {
+            self.rmt.queue(10, 10)?;
+        }
         self.rmt.pulse(10, 10, true)?;
 
-        self.cfg_writer.config.output_enable = true;
-        self.cfg_writer.write();
+        self.control.set_output_enable(true)?;
         self.rmt.pulse(10, 10, true)?;
 
         Ok(())
     }
 
     pub(crate) fn latch_row(&mut self) {
-        self.cfg_writer.config.latch_enable = true;
-        self.cfg_writer.write();
-
-        self.cfg_writer.config.latch_enable = false;
-        self.cfg_writer.write();
+        self.control.latch_row();
     }
 
     pub(crate) fn skip(&mut self) -> crate::Result<()> {
-        self.rmt.pulse(45, 5, false)?;
+        self.rmt
+            .pulse(self.skip_pulse.0, self.skip_pulse.1, false)?;
         Ok(())
     }
 
@@ -269,10 +156,8 @@ impl<'a> ED047TC1<'a> {
     }
 
     pub(crate) fn frame_end(&mut self) -> crate::Result<()> {
-        self.cfg_writer.config.output_enable = false;
-        self.cfg_writer.write();
-        self.cfg_writer.config.mode = true;
-        self.cfg_writer.write();
+        self.control.set_output_enable(false)?;
+        self.control.set_mode(true)?;
         self.rmt.pulse(10, 10, true)?;
         self.rmt.pulse(10, 10, true)?;
 
@@ -285,13 +170,9 @@ impl<'a> ED047TC1<'a> {
     }
 }
 
-#[inline(always)]
-fn busy_delay(wait_cycles: u32) {
-    let target = cycles() + wait_cycles as u64;
-    while cycles() < target {}
-}
-
-#[inline(always)]
-fn cycles() -> u64 {
-    esp_hal::xtensa_lx::timer::get_cycle_count() as u64
+impl<'a> Drop for ED047TC1<'a> {
+    fn drop(&mut self) {
+        // never leave the high voltage rails on
+        let _ = self.control.power_off();
+    }
 }
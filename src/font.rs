@@ -0,0 +1,320 @@
+//! Anti-aliased 4bpp bitmap fonts.
+//!
+//! The format is inspired by the one used by [epdiy]: pre-rasterized glyphs
+//! with 16 levels of coverage, looked up through sorted unicode code point
+//! intervals. Additionally, the format supports kerning pairs.
+//!
+//! A font is a single little endian blob:
+//!
+//! | Offset | Size               | Content                                                |
+//! |--------|--------------------|--------------------------------------------------------|
+//! | 0      | 4                  | Magic `EPF4`                                           |
+//! | 4      | 1                  | Version (1)                                            |
+//! | 5      | 1                  | Reserved                                               |
+//! | 6      | 2                  | Line height (`u16`)                                    |
+//! | 8      | 2                  | Ascender (`i16`)                                       |
+//! | 10     | 2                  | Descender (`i16`, usually negative)                    |
+//! | 12     | 2                  | Number of glyphs                                       |
+//! | 14     | 2                  | Number of intervals                                    |
+//! | 16     | 2                  | Number of kerning pairs                                |
+//! | 18     | 2                  | Reserved                                               |
+//! | 20     | 12 * intervals     | Intervals: first, last (`u32`), glyph index (`u32`)    |
+//! | ...    | 16 * glyphs        | Glyphs, see [`Glyph`]                                  |
+//! | ...    | 12 * kerning pairs | Kerning: left, right (`u32`), offset (`i16`), reserved |
+//! | ...    | ...                | Glyph bitmaps                                          |
+//!
+//! Intervals must be sorted by code point and must not overlap, kerning pairs
+//! must be sorted by (left, right). Each glyph bitmap row is `(width + 1) / 2`
+//! bytes long, the low nibble holds the left pixel. A value of `0xF` means
+//! full coverage.
+//!
+//! Fonts are created from TTF/OTF files using the `fontconvert` tool in
+//! `tools/fontconvert`.
+//!
+//! [epdiy]: https://github.com/vroland/epdiy
+
+use crate::{
+    display::{Display, Rectangle},
+    Error,
+    Result,
+};
+
+const MAGIC: &[u8; 4] = b"EPF4";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 20;
+const INTERVAL_SIZE: usize = 12;
+const GLYPH_SIZE: usize = 16;
+const KERNING_SIZE: usize = 12;
+
+fn u16_at(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn i16_at(data: &[u8], offset: usize) -> i16 {
+    u16_at(data, offset) as i16
+}
+
+fn u32_at(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+/// Metrics and bitmap location of a single glyph.
+///
+/// Each glyph record consists of the width, height, advance (`u16`), left,
+/// top (`i16`), two reserved bytes and the data offset (`u32`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyph {
+    /// Bitmap width in pixels.
+    pub width: u16,
+    /// Bitmap height in pixels.
+    pub height: u16,
+    /// Distance to advance the cursor (x axis).
+    pub advance_x: u16,
+    /// Distance from the cursor to the left edge of the bitmap.
+    pub left: i16,
+    /// Distance from the baseline to the top edge of the bitmap.
+    pub top: i16,
+    /// Offset of the bitmap in the bitmap section.
+    pub data_offset: u32,
+}
+
+impl Glyph {
+    fn parse(data: &[u8]) -> Self {
+        Glyph {
+            width: u16_at(data, 0),
+            height: u16_at(data, 2),
+            advance_x: u16_at(data, 4),
+            left: i16_at(data, 6),
+            top: i16_at(data, 8),
+            data_offset: u32_at(data, 12),
+        }
+    }
+
+    fn bitmap_size(&self) -> usize {
+        (self.width as usize).div_ceil(2) * self.height as usize
+    }
+}
+
+/// An anti-aliased 4bpp font.
+#[derive(Clone, Copy, Debug)]
+pub struct Font<'a> {
+    intervals: &'a [u8],
+    glyphs: &'a [u8],
+    kerning: &'a [u8],
+    bitmap: &'a [u8],
+    /// Distance between two lines.
+    pub advance_y: u16,
+    /// Distance from the baseline to the top of the highest glyph.
+    pub ascender: i16,
+    /// Distance from the baseline to the bottom of the lowest glyph, usually
+    /// negative.
+    pub descender: i16,
+}
+
+impl<'a> Font<'a> {
+    /// Load a font from its binary representation.
+    ///
+    /// Returns [`Error::InvalidFormat`] if the data is malformed, e.g. the
+    /// tables are truncated or a glyph bitmap is out of bounds.
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE || &data[0..4] != MAGIC || data[4] != VERSION {
+            return Err(Error::InvalidFormat);
+        }
+        let glyph_count = u16_at(data, 12) as usize;
+        let interval_count = u16_at(data, 14) as usize;
+        let kerning_count = u16_at(data, 16) as usize;
+
+        // offset of the table following a table of `count` entries at `start`
+        let table_end = |start: usize, count: usize, size: usize| {
+            count
+                .checked_mul(size)
+                .and_then(|len| start.checked_add(len))
+                .ok_or(Error::InvalidFormat)
+        };
+        let glyphs_start = table_end(HEADER_SIZE, interval_count, INTERVAL_SIZE)?;
+        let kerning_start = table_end(glyphs_start, glyph_count, GLYPH_SIZE)?;
+        let bitmap_start = table_end(kerning_start, kerning_count, KERNING_SIZE)?;
+        if data.len() < bitmap_start {
+            return Err(Error::InvalidFormat);
+        }
+        let font = Font {
+            intervals: &data[HEADER_SIZE..glyphs_start],
+            glyphs: &data[glyphs_start..kerning_start],
+            kerning: &data[kerning_start..bitmap_start],
+            bitmap: &data[bitmap_start..],
+            advance_y: u16_at(data, 6),
+            ascender: i16_at(data, 8),
+            descender: i16_at(data, 10),
+        };
+        // validate the tables once, so lookups can't go out of bounds
+        for chunk in font.intervals.chunks_exact(INTERVAL_SIZE) {
+            let (first, last, index) = (u32_at(chunk, 0), u32_at(chunk, 4), u32_at(chunk, 8));
+            let last_index = last
+                .checked_sub(first)
+                .and_then(|span| index.checked_add(span));
+            if !last_index.is_some_and(|last_index| (last_index as usize) < glyph_count) {
+                return Err(Error::InvalidFormat);
+            }
+        }
+        for index in 0..glyph_count {
+            let glyph = font.glyph_at(index);
+            let end = (glyph.data_offset as usize).checked_add(glyph.bitmap_size());
+            if !end.is_some_and(|end| end <= font.bitmap.len()) {
+                return Err(Error::InvalidFormat);
+            }
+        }
+        Ok(font)
+    }
+
+    fn glyph_at(&self, index: usize) -> Glyph {
+        Glyph::parse(&self.glyphs[index * GLYPH_SIZE..(index + 1) * GLYPH_SIZE])
+    }
+
+    /// Look up the glyph of a character.
+    pub fn glyph(&self, c: char) -> Option<Glyph> {
+        let code_point = c as u32;
+        let intervals = self.intervals.len() / INTERVAL_SIZE;
+        let (mut low, mut high) = (0, intervals);
+        while low < high {
+            let mid = (low + high) / 2;
+            let interval = &self.intervals[mid * INTERVAL_SIZE..];
+            let (first, last) = (u32_at(interval, 0), u32_at(interval, 4));
+            if code_point < first {
+                high = mid;
+            } else if code_point > last {
+                low = mid + 1;
+            } else {
+                let index = u32_at(interval, 8) + (code_point - first);
+                return Some(self.glyph_at(index as usize));
+            }
+        }
+        None
+    }
+
+    /// Look up the glyph of a character, falls back to `?` for unknown
+    /// characters.
+    pub fn glyph_or_fallback(&self, c: char) -> Option<Glyph> {
+        self.glyph(c).or_else(|| self.glyph('?'))
+    }
+
+    /// Kerning offset between two consecutive characters.
+    pub fn kerning(&self, left: char, right: char) -> i16 {
+        let key = (left as u32, right as u32);
+        let pairs = self.kerning.len() / KERNING_SIZE;
+        let (mut low, mut high) = (0, pairs);
+        while low < high {
+            let mid = (low + high) / 2;
+            let pair = &self.kerning[mid * KERNING_SIZE..];
+            match (u32_at(pair, 0), u32_at(pair, 4)).cmp(&key) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => return i16_at(pair, 8),
+            }
+        }
+        0
+    }
+
+    /// Coverage (0x0 - 0xF) of a single pixel of the glyph.
+    pub fn coverage(&self, glyph: &Glyph, x: u16, y: u16) -> u8 {
+        let stride = (glyph.width as usize).div_ceil(2);
+        let byte = self.bitmap[glyph.data_offset as usize + y as usize * stride + x as usize / 2];
+        match x % 2 {
+            0 => byte & 0x0F,
+            _ => byte >> 4,
+        }
+    }
+
+    /// Width of a single line of text in pixels, including kerning.
+    pub fn text_width(&self, text: &str) -> i32 {
+        self.layout(text, 0).last().map_or(0, |(_, x, _)| x)
+    }
+
+    /// Iterate over the glyphs of a single line of text. Yields the glyph,
+    /// the x position of the cursor before the glyph and after the glyph.
+    pub(crate) fn layout<'t>(
+        &'t self,
+        text: &'t str,
+        x: i32,
+    ) -> impl Iterator<Item = (Glyph, i32, i32)> + 't {
+        let mut cursor = x;
+        let mut previous: Option<char> = None;
+        text.chars().filter_map(move |c| {
+            let glyph = self.glyph_or_fallback(c)?;
+            if let Some(previous) = previous {
+                cursor += self.kerning(previous, c) as i32;
+            }
+            previous = Some(c);
+            let start = cursor;
+            cursor += glyph.advance_x as i32;
+            Some((glyph, start, cursor))
+        })
+    }
+}
+
+/// Blend between the background and the foreground color according to the
+/// coverage (0x0 - 0xF).
+pub(crate) fn blend(background: u8, foreground: u8, coverage: u8) -> u8 {
+    let (background, foreground) = (background as i16, foreground as i16);
+    (background + (foreground - background) * coverage as i16 / 0xF) as u8
+}
+
+impl<'a> Display<'a> {
+    /// Draw text into the framebuffer. The glyphs are blended with the current
+    /// content of the framebuffer.
+    ///
+    /// The position is the start of the baseline of the first line, `\n`
+    /// starts a new line. Returns the bounding box of the drawn text.
+    pub fn draw_text(
+        &mut self,
+        font: &Font,
+        text: &str,
+        x: i32,
+        y: i32,
+        color: u8,
+    ) -> Result<Rectangle> {
+        if color > 0x0F {
+            return Err(Error::InvalidColor);
+        }
+        let (mut min_x, mut max_x) = (x, x);
+        let mut baseline = y;
+        for line in text.split('\n') {
+            for (glyph, start, end) in font.layout(line, x) {
+                let left = start + glyph.left as i32;
+                let top = baseline - glyph.top as i32;
+                for gy in 0..glyph.height {
+                    for gx in 0..glyph.width {
+                        let coverage = font.coverage(&glyph, gx, gy);
+                        let (px, py) = (left + gx as i32, top + gy as i32);
+                        if coverage == 0
+                            || !(0..Self::WIDTH as i32).contains(&px)
+                            || !(0..Self::HEIGHT as i32).contains(&py)
+                        {
+                            continue;
+                        }
+                        let (px, py) = (px as u16, py as u16);
+                        let background = self.pixel(px, py)?;
+                        self.set_pixel(px, py, blend(background, color, coverage))?;
+                    }
+                }
+                min_x = min_x.min(left);
+                max_x = max_x.max(end);
+            }
+            baseline += font.advance_y as i32;
+        }
+        let top = (y - font.ascender as i32).max(0);
+        let bottom =
+            (baseline - font.advance_y as i32 - font.descender as i32).min(Self::HEIGHT as i32);
+        let (min_x, max_x) = (min_x.max(0), max_x.min(Self::WIDTH as i32));
+        Ok(Rectangle {
+            x: min_x as u16,
+            y: top as u16,
+            width: (max_x - min_x).max(0) as u16,
+            height: (bottom - top).max(0) as u16,
+        })
+    }
+}
@@ -1,6 +1,16 @@
-use embedded_graphics_core::{pixelcolor::Gray4, prelude::*};
+use embedded_graphics::text::{
+    renderer::{CharacterStyle, TextMetrics, TextRenderer},
+    Baseline,
+};
+use embedded_graphics_core::{image::ImageDrawable, pixelcolor::Gray4, prelude::*, primitives};
 
-use crate::{display::Display, Error};
+use crate::{
+    display::Display,
+    font::{self, Font},
+    image::Image,
+    layer::Layer,
+    Error,
+};
 
 impl<'a> DrawTarget for Display<'a> {
     type Color = Gray4;
@@ -32,6 +42,40 @@ impl<'a> OriginDimensions for Display<'a> {
     }
 }
 
+/// Draws to the layer, drawn pixels become opaque.
+impl DrawTarget for Layer {
+    type Color = Gray4;
+
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels.into_iter() {
+            let (Ok(x), Ok(y)) = (u16::try_from(coord.x), u16::try_from(coord.y)) else {
+                continue;
+            };
+            let result = self.set_pixel(x, y, color.luma());
+            if matches!(result, Err(Error::OutOfBounds)) {
+                continue;
+            }
+            result?;
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill(color.luma())
+    }
+}
+
+impl OriginDimensions for Layer {
+    fn size(&self) -> Size {
+        Size::new(self.width() as u32, self.height() as u32)
+    }
+}
+
 impl Into<crate::display::Rectangle> for embedded_graphics_core::primitives::Rectangle {
     fn into(self) -> crate::display::Rectangle {
         crate::display::Rectangle {
@@ -42,3 +86,170 @@ impl Into<crate::display::Rectangle> for embedded_graphics_core::primitives::Rec
         }
     }
 }
+
+impl<'a> OriginDimensions for Image<'a> {
+    fn size(&self) -> Size {
+        Size::new(self.width() as u32, self.height() as u32)
+    }
+}
+
+/// Allows drawing [`Image`]s using [`embedded_graphics::image::Image`]. Use
+/// [`Display::draw_image`] to copy the image into the framebuffer directly.
+impl<'a> ImageDrawable for Image<'a> {
+    type Color = Gray4;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_sub_image(target, &self.bounding_box())
+    }
+
+    fn draw_sub_image<D>(
+        &self,
+        target: &mut D,
+        area: &primitives::Rectangle,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let area = area.intersection(&self.bounding_box());
+        let Some(bottom_right) = area.bottom_right() else {
+            return Ok(());
+        };
+        let (left, top) = (area.top_left.x as u16, area.top_left.y as u16);
+        let (right, bottom) = (bottom_right.x as u16, bottom_right.y as u16);
+        let colors =
+            (top..=bottom).flat_map(|y| (left..=right).map(move |x| Gray4::new(self.pixel(x, y))));
+        target.fill_contiguous(
+            &primitives::Rectangle::new(Point::zero(), area.size),
+            colors,
+        )
+    }
+}
+
+/// Text style for the anti-aliased 4bpp [`Font`] to be used with
+/// [`embedded_graphics::text::Text`] (or `embedded-text`).
+///
+/// Without a background color, the glyphs are blended with white since the
+/// generic [`DrawTarget`] can't be read. Use [`Display::draw_text`] to blend
+/// with the actual content of the framebuffer.
+#[derive(Clone, Copy, Debug)]
+pub struct FontStyle<'a> {
+    /// The font.
+    pub font: &'a Font<'a>,
+    /// Text color, `None` to draw nothing (e.g. to measure text).
+    pub text_color: Option<Gray4>,
+    /// Background color, `None` for a transparent background.
+    pub background_color: Option<Gray4>,
+}
+
+impl<'a> FontStyle<'a> {
+    /// Create a new style using the provided font and color.
+    pub fn new(font: &'a Font<'a>, text_color: Gray4) -> Self {
+        FontStyle {
+            font,
+            text_color: Some(text_color),
+            background_color: None,
+        }
+    }
+
+    fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        let (ascender, descender) = (self.font.ascender as i32, self.font.descender as i32);
+        match baseline {
+            Baseline::Top => ascender,
+            Baseline::Bottom => descender,
+            Baseline::Middle => (ascender + descender) / 2,
+            Baseline::Alphabetic => 0,
+        }
+    }
+
+    fn line_box(&self, x: i32, baseline: i32, width: u32) -> primitives::Rectangle {
+        let top = baseline - self.font.ascender as i32;
+        let height = (self.font.ascender - self.font.descender).max(0) as u32;
+        primitives::Rectangle::new(Point::new(x, top), Size::new(width, height))
+    }
+}
+
+impl<'a> CharacterStyle for FontStyle<'a> {
+    type Color = Gray4;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+}
+
+impl<'a> TextRenderer for FontStyle<'a> {
+    type Color = Gray4;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let y = position.y + self.baseline_offset(baseline);
+        let width = self.font.text_width(text).max(0) as u32;
+        if let Some(background) = self.background_color {
+            target.fill_solid(&self.line_box(position.x, y, width), background)?;
+        }
+        if let Some(color) = self.text_color {
+            let background = self.background_color.unwrap_or(Gray4::WHITE).luma();
+            for (glyph, start, _) in self.font.layout(text, position.x) {
+                let left = start + glyph.left as i32;
+                let top = y - glyph.top as i32;
+                let pixels = (0..glyph.height)
+                    .flat_map(|gy| (0..glyph.width).map(move |gx| (gx, gy)))
+                    .filter_map(|(gx, gy)| {
+                        let coverage = self.font.coverage(&glyph, gx, gy);
+                        (coverage > 0).then(|| {
+                            Pixel(
+                                Point::new(left + gx as i32, top + gy as i32),
+                                Gray4::new(font::blend(background, color.luma(), coverage)),
+                            )
+                        })
+                    });
+                target.draw_iter(pixels)?;
+            }
+        }
+        Ok(Point::new(position.x + width as i32, position.y))
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        if let Some(background) = self.background_color {
+            let y = position.y + self.baseline_offset(baseline);
+            target.fill_solid(&self.line_box(position.x, y, width), background)?;
+        }
+        Ok(Point::new(position.x + width as i32, position.y))
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let y = position.y + self.baseline_offset(baseline);
+        let width = self.font.text_width(text).max(0) as u32;
+        TextMetrics {
+            bounding_box: self.line_box(position.x, y, width),
+            next_position: Point::new(position.x + width as i32, position.y),
+        }
+    }
+
+    fn line_height(&self) -> u32 {
+        self.font.advance_y as u32
+    }
+}
@@ -0,0 +1,272 @@
+//! Packed 4bpp images.
+//!
+//! Images use the same layout as the framebuffer: two pixels per byte, the
+//! low nibble holds the left pixel and `0xF` is white. Each row starts on a
+//! new byte, i.e. a row is `(width + 1) / 2` bytes long. Use the `imgconvert`
+//! tool in `tools/imgconvert` to convert PNG, JPEG or BMP files.
+//!
+//! A full screen image takes 259 KB, [`CompressedImage`]s are run-length
+//! encoded and decoded row by row while drawing.
+
+use alloc::vec::Vec;
+
+use crate::{
+    display::{Display, Rectangle},
+    rle,
+    Error,
+    Result,
+};
+
+/// A packed 4bpp image, e.g. included using `include_bytes!`.
+#[derive(Clone, Copy, Debug)]
+pub struct Image<'a> {
+    data: &'a [u8],
+    width: u16,
+    height: u16,
+}
+
+impl<'a> Image<'a> {
+    /// Create an image from packed 4bpp data.
+    ///
+    /// Returns [`Error::InvalidFormat`] if the length of the data does not
+    /// match the dimensions.
+    pub fn new(data: &'a [u8], width: u16, height: u16) -> Result<Self> {
+        if data.len() != Self::stride(width) * height as usize {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Image {
+            data,
+            width,
+            height,
+        })
+    }
+
+    fn stride(width: u16) -> usize {
+        (width as usize).div_ceil(2)
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Color of a single pixel, the coordinates must be within the image.
+    pub fn pixel(&self, x: u16, y: u16) -> u8 {
+        let byte = self.data[y as usize * Self::stride(self.width) + x as usize / 2];
+        match x % 2 {
+            1 => byte >> 4,
+            _ => byte & 0x0F,
+        }
+    }
+
+    /// A single row of packed pixels.
+    pub fn row(&self, y: u16) -> &'a [u8] {
+        let stride = Self::stride(self.width);
+        &self.data[y as usize * stride..(y as usize + 1) * stride]
+    }
+
+    /// Compress the image, see [`CompressedImage`].
+    pub fn compress(&self) -> Vec<u8> {
+        rle::encode(self.data, self.width, self.height)
+    }
+}
+
+/// A run-length encoded 4bpp image, created by `imgconvert --compress` or
+/// [`Image::compress`].
+///
+/// White backgrounds and solid areas compress very well, a blank row takes
+/// two bytes. Dithered photos barely compress at all.
+#[derive(Clone, Copy, Debug)]
+pub struct CompressedImage<'a> {
+    rows: &'a [u8],
+    width: u16,
+    height: u16,
+}
+
+impl<'a> CompressedImage<'a> {
+    /// Load a compressed image.
+    ///
+    /// The data is validated once, returns [`Error::InvalidFormat`] if the
+    /// header or any of the rows is malformed.
+    pub fn new(data: &'a [u8]) -> Result<Self> {
+        let (width, height, rows) = rle::parse_header(data).ok_or(Error::InvalidFormat)?;
+        if !rle::validate(rows, width, height) {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(CompressedImage {
+            rows,
+            width,
+            height,
+        })
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Decode the image row by row. `row` is called with the index and the
+    /// packed pixels of each row, the buffer must hold at least
+    /// `(width + 1) / 2` bytes.
+    pub fn decode(&self, buffer: &mut [u8], mut row: impl FnMut(u16, &[u8])) -> Result<()> {
+        let stride = (self.width as usize).div_ceil(2);
+        let buffer = buffer.get_mut(..stride).ok_or(Error::OutOfBounds)?;
+        let mut rows = self.rows;
+        for y in 0..self.height {
+            rle::decode_row(&mut rows, self.width as usize, |x, span| {
+                write_span(buffer, x, 0, self.width as usize, span)
+            })
+            .ok_or(Error::InvalidFormat)?;
+            row(y, buffer);
+        }
+        Ok(())
+    }
+}
+
+/// Write a decoded span starting at pixel `x` of the packed `dst` row,
+/// shifted by `offset` pixels (`x + offset`) and clipped to `limit` pixels.
+fn write_span(dst: &mut [u8], x: usize, offset: isize, limit: usize, span: rle::Span) {
+    let start = x as isize + offset;
+    let end = (start + span.length() as isize).min(limit as isize);
+    let skip = (-start).max(0) as usize;
+    let start = start.max(0) as usize;
+    if end <= start as isize {
+        return;
+    }
+    let length = end as usize - start;
+    match span {
+        rle::Span::Run { color, .. } => fill_row(dst, start, length, color),
+        rle::Span::Literal { data, .. } => blit_row(dst, start, data, skip, length),
+    }
+}
+
+/// Fill `width` pixels starting at pixel `x` of the packed row.
+pub(crate) fn fill_row(dst: &mut [u8], x: usize, width: usize, color: u8) {
+    let (mut x, end) = (x, x + width);
+    if x % 2 == 1 && x < end {
+        dst[x / 2] = (dst[x / 2] & 0x0F) | color << 4;
+        x += 1;
+    }
+    let bytes = (end - x) / 2;
+    dst[x / 2..x / 2 + bytes].fill(color << 4 | color);
+    x += bytes * 2;
+    if x < end {
+        dst[x / 2] = (dst[x / 2] & 0xF0) | color;
+    }
+}
+
+/// Copy `width` pixels starting at pixel `src_x` of the packed `src` row to
+/// pixel `dst_x` of the packed `dst` row.
+pub(crate) fn blit_row(dst: &mut [u8], dst_x: usize, src: &[u8], src_x: usize, width: usize) {
+    let mut i = 0;
+    // fast path: both rows are aligned to full bytes
+    if dst_x % 2 == 0 && src_x % 2 == 0 {
+        let bytes = width / 2;
+        dst[dst_x / 2..dst_x / 2 + bytes].copy_from_slice(&src[src_x / 2..src_x / 2 + bytes]);
+        i = bytes * 2;
+    }
+    for i in i..width {
+        let (sx, dx) = (src_x + i, dst_x + i);
+        let color = match sx % 2 {
+            1 => src[sx / 2] >> 4,
+            _ => src[sx / 2] & 0x0F,
+        };
+        let byte = &mut dst[dx / 2];
+        *byte = match dx % 2 {
+            1 => (*byte & 0x0F) | color << 4,
+            _ => (*byte & 0xF0) | color,
+        };
+    }
+}
+
+impl<'a> Display<'a> {
+    /// Draw an image into the framebuffer with its top left corner at the
+    /// provided position. Parts outside the screen are clipped.
+    ///
+    /// Returns the area of the screen that has been drawn to.
+    pub fn draw_image(&mut self, image: &Image, x: i32, y: i32) -> Result<Rectangle> {
+        let left = x.max(0);
+        let top = y.max(0);
+        let right = (x + image.width() as i32).min(Self::WIDTH as i32);
+        let bottom = (y + image.height() as i32).min(Self::HEIGHT as i32);
+        if left >= right || top >= bottom {
+            return Ok(Rectangle {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            });
+        }
+        for row in top..bottom {
+            let src = image.row((row - y) as u16);
+            blit_row(
+                self.row_mut(row as u16),
+                left as usize,
+                src,
+                (left - x) as usize,
+                (right - left) as usize,
+            );
+        }
+        Ok(Rectangle {
+            x: left as u16,
+            y: top as u16,
+            width: (right - left) as u16,
+            height: (bottom - top) as u16,
+        })
+    }
+
+    /// Draw a compressed image into the framebuffer with its top left corner
+    /// at the provided position. The rows are decoded directly into the
+    /// framebuffer, parts outside the screen are clipped.
+    ///
+    /// Returns the area of the screen that has been drawn to.
+    pub fn draw_compressed_image(
+        &mut self,
+        image: &CompressedImage,
+        x: i32,
+        y: i32,
+    ) -> Result<Rectangle> {
+        let left = x.max(0);
+        let top = y.max(0);
+        let right = (x + image.width() as i32).min(Self::WIDTH as i32);
+        let bottom = (y + image.height() as i32).min(Self::HEIGHT as i32);
+        if left >= right || top >= bottom {
+            return Ok(Rectangle {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            });
+        }
+        let mut rows = image.rows;
+        for row in y..bottom {
+            // rows above the screen have to be decoded to find the next row
+            let result = match row < 0 {
+                true => rle::decode_row(&mut rows, image.width() as usize, |_, _| {}),
+                false => {
+                    let dst = self.row_mut(row as u16);
+                    rle::decode_row(&mut rows, image.width() as usize, |sx, span| {
+                        write_span(dst, sx, x as isize, Self::WIDTH as usize, span)
+                    })
+                }
+            };
+            result.ok_or(Error::InvalidFormat)?;
+        }
+        Ok(Rectangle {
+            x: left as u16,
+            y: top as u16,
+            width: (right - left) as u16,
+            height: (bottom - top) as u16,
+        })
+    }
+}
@@ -0,0 +1,522 @@
+//! Off-screen layers composited into the framebuffer.
+//!
+//! A [`Layer`] is a 4bpp surface with its own position on the screen. Its
+//! pixels are transparent until they are drawn to, so a popup layer only
+//! covers what has been drawn on it. The [`Compositor`] stacks the layers by
+//! their z-order and keeps track of the areas that changed, e.g. by drawing
+//! to a layer, moving it or hiding it. [`Compositor::flush`] composites only
+//! these areas into the framebuffer and updates the screen, so dismissing a
+//! popup restores the content underneath without redrawing it.
+//!
+//! ```rust no_run
+//! # fn example(display: &mut lilygo_epd47::Display) -> Result<(), lilygo_epd47::Error> {
+//! use lilygo_epd47::layer::{Compositor, Layer};
+//!
+//! let mut compositor = Compositor::new();
+//! let content = compositor.add(Layer::new(0, 0, 960, 540), 0);
+//! let popup = compositor.add(Layer::new(280, 170, 400, 200), 10);
+//! // draw to the layers, e.g. using embedded-graphics
+//! compositor.layer_mut(content).unwrap().fill(0x0F)?;
+//! compositor.layer_mut(popup).unwrap().fill(0x08)?;
+//! compositor.flush(display)?;
+//! // dismiss the popup, the content underneath is restored
+//! compositor.layer_mut(popup).unwrap().set_visible(false);
+//! compositor.flush(display)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    display::{Display, DrawMode, Rectangle},
+    image,
+    Error,
+    Result,
+};
+
+/// An area in screen coordinates, `right` and `bottom` are exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Bounds {
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+}
+
+impl Bounds {
+    fn new(x: i32, y: i32, width: u16, height: u16) -> Self {
+        Bounds {
+            left: x,
+            top: y,
+            right: x + width as i32,
+            bottom: y + height as i32,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.left >= self.right || self.top >= self.bottom
+    }
+
+    fn area(&self) -> i64 {
+        (self.right - self.left) as i64 * (self.bottom - self.top) as i64
+    }
+
+    fn intersects(&self, other: &Bounds) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.top < other.bottom
+            && other.top < self.bottom
+    }
+
+    fn union(self, other: Bounds) -> Bounds {
+        Bounds {
+            left: self.left.min(other.left),
+            top: self.top.min(other.top),
+            right: self.right.max(other.right),
+            bottom: self.bottom.max(other.bottom),
+        }
+    }
+
+    /// The part of the bounds on the screen, `None` if there is none.
+    fn clip(&self) -> Option<Rectangle> {
+        let left = self.left.max(0);
+        let top = self.top.max(0);
+        let right = self.right.min(Display::WIDTH as i32);
+        let bottom = self.bottom.min(Display::HEIGHT as i32);
+        (left < right && top < bottom).then(|| Rectangle {
+            x: left as u16,
+            y: top as u16,
+            width: (right - left) as u16,
+            height: (bottom - top) as u16,
+        })
+    }
+}
+
+/// Areas of the screen which changed, a few disjoint rectangles.
+#[derive(Clone, Debug, Default)]
+struct Damage(Vec<Bounds>);
+
+impl Damage {
+    /// Maximum number of rectangles, further areas are merged into the
+    /// rectangle which grows the least.
+    const MAX_AREAS: usize = 8;
+
+    fn add(&mut self, mut bounds: Bounds) {
+        if bounds.is_empty() {
+            return;
+        }
+        // the union may overlap further rectangles
+        while let Some(index) = self.0.iter().position(|other| other.intersects(&bounds)) {
+            bounds = bounds.union(self.0.swap_remove(index));
+        }
+        if self.0.len() < Self::MAX_AREAS {
+            self.0.push(bounds);
+            return;
+        }
+        let growth = |other: &Bounds| bounds.union(*other).area() - other.area();
+        let index = (0..self.0.len())
+            .min_by_key(|&index| growth(&self.0[index]))
+            .unwrap_or_default();
+        let merged = bounds.union(self.0.swap_remove(index));
+        self.add(merged);
+    }
+
+    fn extend(&mut self, other: &Damage) {
+        for &bounds in &other.0 {
+            self.add(bounds);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// An off-screen 4bpp surface, see the [module documentation](self).
+///
+/// Coordinates used for drawing are relative to the top left corner of the
+/// layer. With the `embedded-graphics` feature, layers implement
+/// `DrawTarget<Color = Gray4>`.
+#[derive(Clone, Debug)]
+pub struct Layer {
+    x: i32,
+    y: i32,
+    width: u16,
+    height: u16,
+    /// Packed pixels, same layout as an [`Image`](crate::image::Image).
+    pixels: Vec<u8>,
+    /// One bit per pixel, set if the pixel is opaque.
+    opaque: Vec<u8>,
+    visible: bool,
+    /// Areas of the screen which changed since the last composite.
+    damage: Damage,
+}
+
+impl Layer {
+    /// Create a visible, fully transparent layer with its top left corner at
+    /// the provided position of the screen.
+    pub fn new(x: i32, y: i32, width: u16, height: u16) -> Self {
+        Layer {
+            x,
+            y,
+            width,
+            height,
+            pixels: vec![0xFF; Self::stride(width) * height as usize],
+            opaque: vec![0; (width as usize).div_ceil(8) * height as usize],
+            visible: true,
+            damage: Damage::default(),
+        }
+    }
+
+    fn stride(width: u16) -> usize {
+        (width as usize).div_ceil(2)
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds::new(self.x, self.y, self.width, self.height)
+    }
+
+    /// Mark an area of the layer (layer coordinates) as changed.
+    fn damage(&mut self, x: u16, y: u16, width: u16, height: u16) {
+        if self.visible {
+            let bounds = Bounds::new(self.x + x as i32, self.y + y as i32, width, height);
+            self.damage.add(bounds);
+        }
+    }
+
+    /// Width in pixels.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Height in pixels.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Position of the top left corner on the screen.
+    pub fn position(&self) -> (i32, i32) {
+        (self.x, self.y)
+    }
+
+    /// Move the layer, the area it covered before and the area it covers now
+    /// are updated by the next composite.
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        if (x, y) == (self.x, self.y) {
+            return;
+        }
+        self.damage(0, 0, self.width, self.height);
+        (self.x, self.y) = (x, y);
+        self.damage(0, 0, self.width, self.height);
+    }
+
+    /// Whether the layer is visible.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Show or hide the layer, e.g. to dismiss a popup. Hidden layers keep
+    /// their content.
+    pub fn set_visible(&mut self, visible: bool) {
+        if visible != self.visible {
+            self.visible = true;
+            self.damage(0, 0, self.width, self.height);
+            self.visible = visible;
+        }
+    }
+
+    /// Sets a single pixel, making it opaque.
+    ///
+    /// If the provided coordinates are outside the layer, this method returns
+    /// [Error::OutOfBounds]. If the provided color is greater than 0x0F,
+    /// this method returns [Error::InvalidColor].
+    pub fn set_pixel(&mut self, x: u16, y: u16, color: u8) -> Result<()> {
+        if x >= self.width || y >= self.height {
+            return Err(Error::OutOfBounds);
+        }
+        if color > 0x0F {
+            return Err(Error::InvalidColor);
+        }
+        let index = y as usize * Self::stride(self.width) + x as usize / 2;
+        let value = self.pixels[index];
+        self.pixels[index] = match x % 2 {
+            1 => (value & 0x0F) | color << 4,
+            _ => (value & 0xF0) | color,
+        };
+        let mask = y as usize * (self.width as usize).div_ceil(8) + x as usize / 8;
+        self.opaque[mask] |= 1 << (x % 8);
+        self.damage(x, y, 1, 1);
+        Ok(())
+    }
+
+    /// Color of a single pixel, `None` if it is transparent or outside the
+    /// layer.
+    pub fn pixel(&self, x: u16, y: u16) -> Option<u8> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let mask = y as usize * (self.width as usize).div_ceil(8) + x as usize / 8;
+        if self.opaque[mask] & (1 << (x % 8)) == 0 {
+            return None;
+        }
+        let value = self.pixels[y as usize * Self::stride(self.width) + x as usize / 2];
+        match x % 2 {
+            1 => Some(value >> 4),
+            _ => Some(value & 0x0F),
+        }
+    }
+
+    /// Fill the whole layer with the same color, making it opaque.
+    pub fn fill(&mut self, color: u8) -> Result<()> {
+        if color > 0x0F {
+            return Err(Error::InvalidColor);
+        }
+        self.pixels.fill(color << 4 | color);
+        self.opaque.fill(0xFF);
+        self.damage(0, 0, self.width, self.height);
+        Ok(())
+    }
+
+    /// Make the whole layer transparent.
+    pub fn clear(&mut self) {
+        self.erase(Rectangle {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        });
+    }
+
+    /// Make an area of the layer (layer coordinates) transparent.
+    pub fn erase(&mut self, area: Rectangle) {
+        let right = area.x.saturating_add(area.width).min(self.width);
+        let bottom = area.y.saturating_add(area.height).min(self.height);
+        if area.x >= right || area.y >= bottom {
+            return;
+        }
+        let stride = (self.width as usize).div_ceil(8);
+        for y in area.y..bottom {
+            for x in area.x..right {
+                self.opaque[y as usize * stride + x as usize / 8] &= !(1 << (x % 8));
+            }
+        }
+        self.damage(area.x, area.y, right - area.x, bottom - area.y);
+    }
+}
+
+/// Handle of a layer added to a [`Compositor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayerId(u16);
+
+struct Entry {
+    id: LayerId,
+    z: i16,
+    layer: Layer,
+}
+
+/// Stack of z-ordered layers, see the [module documentation](self).
+///
+/// Pixels not covered by any opaque layer pixel are white.
+#[derive(Default)]
+pub struct Compositor {
+    /// Layers sorted by their z-order, the topmost last.
+    layers: Vec<Entry>,
+    next_id: u16,
+    /// Areas of the screen which changed by adding, removing or reordering
+    /// layers.
+    damage: Damage,
+}
+
+impl Compositor {
+    /// Create an empty compositor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a layer with the provided z-order, higher layers cover lower ones.
+    /// Layers with the same z-order are stacked in the order they have been
+    /// added.
+    pub fn add(&mut self, layer: Layer, z: i16) -> LayerId {
+        let id = LayerId(self.next_id);
+        self.next_id = self.next_id.wrapping_add(1);
+        if layer.visible {
+            self.damage.add(layer.bounds());
+        }
+        let index = self.layers.partition_point(|entry| entry.z <= z);
+        self.layers.insert(index, Entry { id, z, layer });
+        id
+    }
+
+    /// Remove a layer, the area it covered is updated by the next
+    /// composite.
+    pub fn remove(&mut self, id: LayerId) -> Option<Layer> {
+        let index = self.layers.iter().position(|entry| entry.id == id)?;
+        let layer = self.layers.remove(index).layer;
+        if layer.visible {
+            self.damage.add(layer.bounds());
+        }
+        // e.g. the area of a layer hidden before removing it
+        self.damage.extend(&layer.damage);
+        Some(layer)
+    }
+
+    /// Access a layer.
+    pub fn layer(&self, id: LayerId) -> Option<&Layer> {
+        self.layers
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| &entry.layer)
+    }
+
+    /// Mutable access to a layer, e.g. to draw to it.
+    pub fn layer_mut(&mut self, id: LayerId) -> Option<&mut Layer> {
+        self.layers
+            .iter_mut()
+            .find(|entry| entry.id == id)
+            .map(|entry| &mut entry.layer)
+    }
+
+    /// The z-order of a layer.
+    pub fn z(&self, id: LayerId) -> Option<i16> {
+        self.layers
+            .iter()
+            .find(|entry| entry.id == id)
+            .map(|entry| entry.z)
+    }
+
+    /// Change the z-order of a layer, e.g. to raise it above the others.
+    ///
+    /// Returns [`Error::OutOfBounds`] if there is no such layer.
+    pub fn set_z(&mut self, id: LayerId, z: i16) -> Result<()> {
+        let index = self
+            .layers
+            .iter()
+            .position(|entry| entry.id == id)
+            .ok_or(Error::OutOfBounds)?;
+        let entry = self.layers.remove(index);
+        if entry.layer.visible {
+            self.damage.add(entry.layer.bounds());
+        }
+        let index = self.layers.partition_point(|other| other.z <= z);
+        self.layers.insert(index, Entry { z, ..entry });
+        Ok(())
+    }
+
+    /// The disjoint areas of the screen which changed since the last
+    /// composite, empty if nothing changed.
+    pub fn damage(&self) -> Vec<Rectangle> {
+        let mut damage = self.damage.clone();
+        for entry in &self.layers {
+            damage.extend(&entry.layer.damage);
+        }
+        damage.0.iter().filter_map(Bounds::clip).collect()
+    }
+
+    /// Color of a single pixel of the screen (screen coordinates).
+    fn composite_pixel(&self, x: i32, y: i32) -> u8 {
+        self.layers
+            .iter()
+            .rev()
+            .filter(|entry| entry.layer.visible)
+            .find_map(|entry| {
+                let (lx, ly) = (x - entry.layer.x, y - entry.layer.y);
+                match (u16::try_from(lx), u16::try_from(ly)) {
+                    (Ok(lx), Ok(ly)) => entry.layer.pixel(lx, ly),
+                    _ => None,
+                }
+            })
+            .unwrap_or(0x0F)
+    }
+
+    /// Composite the changed areas into the framebuffer of the display,
+    /// without updating the screen. Only the rows of these areas are
+    /// tainted.
+    ///
+    /// Returns the areas, empty if nothing changed.
+    pub fn composite(&mut self, display: &mut Display) -> Result<Vec<Rectangle>> {
+        Ok(self
+            .composite_areas(display)
+            .into_iter()
+            .map(|(area, _)| area)
+            .collect())
+    }
+
+    /// Like [`Compositor::composite`], additionally returns for each area
+    /// whether any pixel gets lighter than it is on the screen.
+    fn composite_areas(&mut self, display: &mut Display) -> Vec<(Rectangle, bool)> {
+        let areas = self.damage();
+        self.reset_damage();
+        // the screen holds the levels after the tone curve
+        let tone_curve = display.tone_curve();
+        let mut composited = Vec::with_capacity(areas.len());
+        for area in areas {
+            let mut row = vec![0u8; (area.width as usize).div_ceil(2)];
+            let mut lightens = false;
+            for y in area.y..area.y + area.height {
+                for i in 0..area.width {
+                    let x = area.x + i;
+                    let color = self.composite_pixel(x as i32, y as i32);
+                    lightens |= tone_curve.map(color) > screen_pixel(display, x, y);
+                    image::fill_row(&mut row, i as usize, 1, color);
+                }
+                let dst = display.row_mut(y);
+                image::blit_row(dst, area.x as usize, &row, 0, area.width as usize);
+            }
+            composited.push((area, lightens));
+        }
+        composited
+    }
+
+    fn reset_damage(&mut self) {
+        self.damage.clear();
+        for entry in &mut self.layers {
+            entry.layer.damage.clear();
+        }
+    }
+
+    /// Composite the changed areas and update the screen. The areas in which
+    /// a pixel gets lighter, e.g. because a popup has been dismissed, are
+    /// cleared first. The display is powered on once for clearing and
+    /// drawing.
+    ///
+    /// Returns the updated areas, empty if nothing changed.
+    ///
+    /// Returns [`Error::PoweredOff`] if the display is powered off, unless
+    /// the [`PowerMode`](crate::PowerMode) is
+    /// [`PowerMode::Auto`](crate::PowerMode::Auto).
+    pub fn flush(&mut self, display: &mut Display) -> Result<Vec<Rectangle>> {
+        let areas = self.composite_areas(display);
+        if areas.is_empty() {
+            return Ok(Vec::new());
+        }
+        // drawing pixels again would darken them further
+        let tone_curve = display.tone_curve();
+        for &(area, _) in areas.iter().filter(|(_, lightens)| !lightens) {
+            for y in area.y..area.y + area.height {
+                for x in area.x..area.x + area.width {
+                    if tone_curve.map(display.pixel(x, y)?) == screen_pixel(display, x, y) {
+                        display.set_pixel(x, y, 0x0F)?;
+                    }
+                }
+            }
+        }
+        display.with_power(|display| {
+            for &(area, _) in areas.iter().filter(|(_, lightens)| *lightens) {
+                display.clear_area(area)?;
+            }
+            display.flush(DrawMode::BlackOnWhite)
+        })?;
+        Ok(areas.into_iter().map(|(area, _)| area).collect())
+    }
+}
+
+/// Color of a single pixel on the screen as far as the driver knows, after
+/// the [tone curve](Display::set_tone_curve) has been applied.
+fn screen_pixel(display: &Display, x: u16, y: u16) -> u8 {
+    let byte = display.screen()[y as usize * Display::WIDTH as usize / 2 + x as usize / 2];
+    match x % 2 {
+        1 => byte >> 4,
+        _ => byte & 0x0F,
+    }
+}
@@ -1,5 +1,6 @@
 //! Simple driver for the LilyGo T5 4.7 inch E-Ink Display.
-//! The driver only supports the V2.3 hardware variant (ESP32-S3)
+//! The driver supports the V2.3 (default, feature `board-v23`) and the S3 Pro
+//! (feature `board-s3-pro`) hardware variants (ESP32-S3).
 //!
 //! This library depends on alloc and requires you to set up an global allocator
 //! for the PSRAM.
@@ -31,7 +32,7 @@
 //! use esp_hal::peripherals::Peripherals;
 //! use esp_hal::prelude::*;
 //! use esp_hal::system::SystemControl;
-//! use lilygo_epd47::{Display, DrawMode, PinConfig};
+//! use lilygo_epd47::{Display, DisplayConfig, DrawMode, PinConfig};
 //!
 //! #[entry]
 //! fn main() -> ! {
@@ -64,7 +65,8 @@
 //!         peripherals.LCD_CAM,
 //!         peripherals.RMT,
 //!         &clocks,
-//!     );
+//!         DisplayConfig::default(),
+//!     ).unwrap();
 //!     // Turn the display on
 //!     display.power_on();
 //!     delay.delay_millis(10);
@@ -86,13 +88,28 @@
 
 extern crate alloc;
 
+pub mod board;
+pub mod calibration;
+pub mod config;
 pub mod display;
+pub mod font;
+pub mod image;
+pub mod layer;
+pub mod power;
+pub mod refresh;
+pub mod snapshot;
+pub mod tone;
+pub mod tps65185;
+pub mod waveform;
 
 #[cfg(feature = "embedded-graphics")]
 pub mod graphics;
 
 mod battery;
+mod battery_history;
+mod draw_mode;
 mod ed047tc1;
+mod rle;
 mod rmt;
 
 /// Errors
@@ -102,10 +119,25 @@ pub enum Error {
     Rmt(esp_hal::rmt::Error),
     /// Pass-through
     Dma(esp_hal::dma::DmaError),
+    /// Pass-through
+    I2c(esp_hal::i2c::Error),
+    /// Pass-through
+    #[cfg(feature = "board-s3-pro")]
+    Pmic(tps65185::Error<esp_hal::i2c::Error>),
+    /// The hardware did not respond in time.
+    Timeout,
     /// Provided pixel coordinates exceed the display boundary.
     OutOfBounds,
     /// Provided color exceeds the allowed range of 0x0 - 0x0F
     InvalidColor,
+    /// Provided data does not match the expected format.
+    InvalidFormat,
+    /// A value of the [`DisplayConfig`] is out of its valid range.
+    InvalidConfig,
+    /// Reading or writing the persistent storage failed.
+    Storage,
+    /// The screen can't be updated while the display is powered off.
+    PoweredOff,
     Unknown,
 }
 
@@ -113,11 +145,24 @@ type Result<T> = core::result::Result<T, Error>;
 
 pub use crate::{
     battery::Battery,
+    battery_history::{
+        state_of_charge,
+        BatteryHistory,
+        ChargeState,
+        PowerSource,
+        PowerStatus,
+        Reading,
+        RuntimeEstimate,
+    },
+    board::{PinConfig, PowerSequence},
+    config::DisplayConfig,
     display::{Display, DrawMode},
-    ed047tc1::PinConfig,
+    power::{PowerMode, PoweredDisplay},
+    tone::ToneCurve,
 };
 
 /// Convenience macro to build the pin config struct.
+#[cfg(feature = "board-v23")]
 #[macro_export]
 macro_rules! pin_config {
     ($($name:ident),*) => {
@@ -142,3 +187,33 @@ macro_rules! pin_config {
         )*
     }
 }
+
+/// Convenience macro to build the pin config struct.
+///
+/// Besides the [`esp_hal::gpio::Io`] instance, the S3 Pro board needs the I2C
+/// peripheral the PMIC and the I/O expander are connected to, i.e.
+/// `pin_config!(io, peripherals.I2C0)`.
+#[cfg(feature = "board-s3-pro")]
+#[macro_export]
+macro_rules! pin_config {
+    ($io:ident, $i2c:expr) => {
+        lilygo_epd47::PinConfig {
+            data0: $io.pins.gpio5,
+            data1: $io.pins.gpio6,
+            data2: $io.pins.gpio7,
+            data3: $io.pins.gpio15,
+            data4: $io.pins.gpio16,
+            data5: $io.pins.gpio17,
+            data6: $io.pins.gpio18,
+            data7: $io.pins.gpio8,
+            leh: $io.pins.gpio42,
+            stv: $io.pins.gpio45,
+            lcd_dc: $io.pins.gpio41,
+            lcd_wrx: $io.pins.gpio4,
+            rmt: $io.pins.gpio48,
+            sda: $io.pins.gpio39,
+            scl: $io.pins.gpio40,
+            i2c: $i2c,
+        }
+    };
+}
@@ -0,0 +1,206 @@
+//! Power management of the panel.
+//!
+//! Updating the screen requires the high voltage rails of the panel, which
+//! should not be left on longer than necessary. Either power the display on
+//! and off explicitly, use the [`PoweredDisplay`] guard which powers the
+//! display off when it goes out of scope, or let the driver take care of it
+//! using [`PowerMode::Auto`].
+//!
+//! As a safety net, [`Display::set_max_on_time`] powers the display off from
+//! a timer interrupt once it has been on for too long, even if the
+//! application hangs. Dropping the [`Display`] powers it off and
+//! [`emergency_power_off`] can be called from a panic handler.
+
+use alloc::boxed::Box;
+use core::{
+    ops::{Deref, DerefMut},
+    ptr,
+    sync::atomic::{AtomicBool, AtomicPtr, Ordering},
+};
+
+use esp_hal::{
+    prelude::*,
+    timer::{ErasedTimer, Timer},
+};
+
+use crate::{board, display::Display, Error, Result};
+
+/// How the power of the display is managed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PowerMode {
+    /// The display is powered on and off by the application. Updating the
+    /// screen while the display is off returns [`crate::Error::PoweredOff`].
+    #[default]
+    Manual,
+    /// Every update of the screen powers the display on, waits for the rails
+    /// to settle and powers it off afterwards. Updates while the display is
+    /// already powered on leave it on.
+    Auto,
+}
+
+impl PowerMode {
+    /// Time to wait for the high voltage rails to settle after powering on.
+    pub const SETTLE_TIME_MS: u32 = 10;
+}
+
+/// Guard which keeps the display powered on and powers it off when dropped.
+/// Created by [`Display::powered`].
+///
+/// ```rust no_run
+/// # fn example(display: &mut lilygo_epd47::Display) -> Result<(), lilygo_epd47::Error> {
+/// use lilygo_epd47::DrawMode;
+///
+/// let mut display = display.powered()?;
+/// display.clear()?;
+/// display.flush(DrawMode::BlackOnWhite)?;
+/// // the display is powered off here
+/// # Ok(())
+/// # }
+/// ```
+pub struct PoweredDisplay<'d, 'a> {
+    display: &'d mut Display<'a>,
+}
+
+impl<'d, 'a> Deref for PoweredDisplay<'d, 'a> {
+    type Target = Display<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.display
+    }
+}
+
+impl<'d, 'a> DerefMut for PoweredDisplay<'d, 'a> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.display
+    }
+}
+
+impl<'d, 'a> Drop for PoweredDisplay<'d, 'a> {
+    fn drop(&mut self) {
+        // nothing sensible to do with the error here, call
+        // `Display::try_power_off` explicitly to handle it
+        let _ = self.display.try_power_off();
+    }
+}
+
+impl<'a> Display<'a> {
+    /// Power the display on, wait for the rails to settle and return a guard
+    /// which powers the display off again when dropped.
+    pub fn powered(&mut self) -> Result<PoweredDisplay<'_, 'a>> {
+        self.try_power_on()?;
+        self.settle();
+        Ok(PoweredDisplay { display: self })
+    }
+}
+
+/// Power off the panel from a panic handler, where the [`Display`] is out of
+/// reach.
+///
+/// ```rust no_run
+/// #[panic_handler]
+/// fn panic(_info: &core::panic::PanicInfo) -> ! {
+///     unsafe { lilygo_epd47::power::emergency_power_off() };
+///     loop {}
+/// }
+/// ```
+///
+/// # Safety
+///
+/// Steals the pins controlling the power rails, the display must not be used
+/// afterwards.
+pub unsafe fn emergency_power_off() {
+    board::emergency_power_off()
+}
+
+/// Timer of the current [`Watchdog`], accessed by its interrupt handler.
+static WATCHDOG_TIMER: AtomicPtr<ErasedTimer> = AtomicPtr::new(ptr::null_mut());
+/// Set by the interrupt handler once it has powered off the panel.
+static WATCHDOG_EXPIRED: AtomicBool = AtomicBool::new(false);
+
+/// Hardware timer powering off the panel once the maximum on-time is
+/// exceeded, see [`Display::set_max_on_time`].
+pub(crate) struct Watchdog {
+    /// Boxed to keep its address for [`WATCHDOG_TIMER`].
+    timer: Option<Box<ErasedTimer>>,
+    seconds: u32,
+}
+
+impl Watchdog {
+    /// Returns [`Error::InvalidConfig`] if the timer can't count `seconds`.
+    pub(crate) fn new<T: Timer>(timer: T, seconds: u32) -> Result<Self> {
+        let timer = Box::new(timer.into());
+        timer.stop();
+        timer
+            .load_value((seconds as u64 * 1_000_000).micros())
+            .map_err(|_| Error::InvalidConfig)?;
+        timer.set_interrupt_handler(watchdog_interrupt);
+        let timer_ptr: *const ErasedTimer = &*timer;
+        WATCHDOG_TIMER.store(timer_ptr.cast_mut(), Ordering::Release);
+        Ok(Watchdog {
+            timer: Some(timer),
+            seconds,
+        })
+    }
+
+    pub(crate) fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Arm the timer, called after powering on.
+    pub(crate) fn start(&self) {
+        let Some(timer) = &self.timer else {
+            return;
+        };
+        WATCHDOG_EXPIRED.store(false, Ordering::Release);
+        timer.stop();
+        timer.clear_interrupt();
+        timer.reset();
+        // the value has been checked by `new`
+        let _ = timer.load_value((self.seconds as u64 * 1_000_000).micros());
+        timer.enable_interrupt(true);
+        timer.start();
+    }
+
+    /// Disarm the timer, called after powering off.
+    pub(crate) fn stop(&self) {
+        if let Some(timer) = &self.timer {
+            timer.stop();
+            timer.enable_interrupt(false);
+            timer.clear_interrupt();
+        }
+    }
+
+    /// Whether the timer has powered off the panel since it has been armed.
+    pub(crate) fn expired(&self) -> bool {
+        WATCHDOG_EXPIRED.load(Ordering::Acquire)
+    }
+
+    /// Disarm the timer and hand it back.
+    pub(crate) fn release(mut self) -> ErasedTimer {
+        self.stop();
+        WATCHDOG_TIMER.store(ptr::null_mut(), Ordering::Release);
+        // `Drop` has nothing left to do
+        *self.timer.take().expect("the timer is only taken here")
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        if self.timer.is_some() {
+            self.stop();
+            WATCHDOG_TIMER.store(ptr::null_mut(), Ordering::Release);
+        }
+    }
+}
+
+#[handler]
+fn watchdog_interrupt() {
+    // Safety: the pointer is cleared before the watchdog releases the timer
+    if let Some(timer) = unsafe { WATCHDOG_TIMER.load(Ordering::Acquire).as_ref() } {
+        timer.clear_interrupt();
+        timer.stop();
+    }
+    // Safety: the display is told by `WATCHDOG_EXPIRED` that the rails are off
+    unsafe { board::emergency_power_off() };
+    WATCHDOG_EXPIRED.store(true, Ordering::Release);
+}
@@ -0,0 +1,229 @@
+//! Automatic full refresh policy.
+//!
+//! Partial updates leave traces of the previous content on the screen
+//! (ghosting), which add up over time. The [`RefreshPolicy`] of the
+//! [`Display`](crate::Display) escalates a [`flush`](crate::Display::flush)
+//! to a full refresh (clear and redraw) after a number of partial flushes
+//! or a time interval. Partial flushes are counted globally and for each
+//! [region](RefreshPolicy::REGIONS) of the screen, so a region which is
+//! updated frequently (e.g. a clock) can be refreshed without flashing the
+//! whole screen.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::{display::Display, Error, Result};
+
+/// Configuration of the automatic full refresh.
+///
+/// ```rust no_run
+/// use lilygo_epd47::refresh::RefreshPolicy;
+///
+/// // full refresh after 50 partial flushes or every hour, refresh regions
+/// // updated 10 times in a row
+/// let policy = RefreshPolicy::after_flushes(50)
+///     .with_interval(60 * 60)
+///     .with_region_limit(10);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RefreshPolicy {
+    /// Full refresh once this number of partial flushes has been reached.
+    pub max_partial_flushes: Option<u16>,
+    /// Refresh a region once this number of partial flushes touching the
+    /// region has been reached.
+    pub max_region_flushes: Option<u16>,
+    /// Full refresh if the last one is older than this interval (in seconds).
+    pub max_interval: Option<u32>,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        Self::NEVER
+    }
+}
+
+impl RefreshPolicy {
+    /// Number of regions the screen is split into. Regions are horizontal
+    /// bands of [`RefreshPolicy::REGION_HEIGHT`] rows.
+    pub const REGIONS: usize = 9;
+    /// Height of a single region.
+    pub const REGION_HEIGHT: u16 = Display::HEIGHT / Self::REGIONS as u16;
+    /// Bit mask of all regions.
+    pub(crate) const ALL_REGIONS: u16 = (1 << Self::REGIONS) - 1;
+
+    /// Never refresh automatically (default).
+    pub const NEVER: Self = RefreshPolicy {
+        max_partial_flushes: None,
+        max_region_flushes: None,
+        max_interval: None,
+    };
+
+    /// Turn every flush into a full refresh.
+    pub const EVERY_TIME: Self = Self::after_flushes(0);
+
+    /// Full refresh after `count` partial flushes.
+    pub const fn after_flushes(count: u16) -> Self {
+        RefreshPolicy {
+            max_partial_flushes: Some(count),
+            ..Self::NEVER
+        }
+    }
+
+    /// Additionally refresh regions after `count` partial flushes touching
+    /// the region.
+    pub const fn with_region_limit(self, count: u16) -> Self {
+        RefreshPolicy {
+            max_region_flushes: Some(count),
+            ..self
+        }
+    }
+
+    /// Additionally perform a full refresh if the last one is older than
+    /// `seconds`.
+    pub const fn with_interval(self, seconds: u32) -> Self {
+        RefreshPolicy {
+            max_interval: Some(seconds),
+            ..self
+        }
+    }
+}
+
+/// Action taken by a flush.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Escalation {
+    /// Partial update.
+    None,
+    /// Clear and redraw the whole screen.
+    Full,
+    /// Partial update, afterwards clear and redraw the regions (bit mask).
+    Regions(u16),
+}
+
+/// Partial flush counters. Times are passed in as `now`, the system time in
+/// microseconds.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RefreshState {
+    pub(crate) policy: RefreshPolicy,
+    partial_flushes: u16,
+    region_flushes: [u16; RefreshPolicy::REGIONS],
+    /// Time of the last full refresh in microseconds, negative if it happened
+    /// before a restored snapshot has been taken.
+    last_full: i64,
+}
+
+impl RefreshState {
+    pub(crate) fn new(policy: RefreshPolicy, now: u64) -> Self {
+        RefreshState {
+            policy,
+            partial_flushes: 0,
+            region_flushes: [0; RefreshPolicy::REGIONS],
+            last_full: now as i64,
+        }
+    }
+
+    pub(crate) fn partial_flushes(&self) -> u16 {
+        self.partial_flushes
+    }
+
+    /// Decide how to perform a flush touching the regions (bit mask) and
+    /// update the counters accordingly.
+    pub(crate) fn flush(&mut self, regions: u16, now: u64) -> Escalation {
+        // nothing is drawn, e.g. an empty framebuffer
+        if regions == 0 {
+            return Escalation::None;
+        }
+        let policy = self.policy;
+        let expired = policy
+            .max_interval
+            .is_some_and(|interval| now as i64 - self.last_full >= interval as i64 * 1_000_000);
+        if expired
+            || policy
+                .max_partial_flushes
+                .is_some_and(|max| self.partial_flushes >= max)
+        {
+            self.reset_all(now);
+            return Escalation::Full;
+        }
+        self.partial_flushes = self.partial_flushes.saturating_add(1);
+        let mut escalate = 0;
+        for region in 0..RefreshPolicy::REGIONS {
+            if regions & 1 << region == 0 {
+                continue;
+            }
+            let count = &mut self.region_flushes[region];
+            match policy.max_region_flushes {
+                Some(max) if *count >= max => {
+                    *count = 0;
+                    escalate |= 1 << region;
+                }
+                _ => *count = count.saturating_add(1),
+            }
+        }
+        match escalate {
+            0 => Escalation::None,
+            regions => Escalation::Regions(regions),
+        }
+    }
+
+    /// The whole screen has been refreshed.
+    pub(crate) fn reset_all(&mut self, now: u64) {
+        self.partial_flushes = 0;
+        self.region_flushes.fill(0);
+        self.last_full = now as i64;
+    }
+
+    /// Size of the serialized counters.
+    pub(crate) const SIZE: usize = 2 + 2 * RefreshPolicy::REGIONS + 4;
+
+    /// Serialize the counters and the time since the last full refresh.
+    pub(crate) fn to_bytes(self, out: &mut Vec<u8>, now: u64) {
+        out.extend_from_slice(&self.partial_flushes.to_le_bytes());
+        for count in self.region_flushes {
+            out.extend_from_slice(&count.to_le_bytes());
+        }
+        let age = ((now as i64 - self.last_full) / 1_000_000).clamp(0, u32::MAX as i64) as u32;
+        out.extend_from_slice(&age.to_le_bytes());
+    }
+
+    /// Restore counters serialized by [`RefreshState::to_bytes`]. The system
+    /// timer restarts on wake up, `asleep` is the time passed since the
+    /// counters have been serialized.
+    ///
+    /// Returns [`Error::InvalidFormat`] if the counters are inconsistent, i.e.
+    /// a region has been flushed more often than the whole screen. The
+    /// counters are left untouched in that case.
+    pub(crate) fn restore(
+        &mut self,
+        data: &[u8; Self::SIZE],
+        asleep: Duration,
+        now: u64,
+    ) -> Result<()> {
+        let u16_at = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let partial_flushes = u16_at(0);
+        let mut region_flushes = [0; RefreshPolicy::REGIONS];
+        for (region, count) in region_flushes.iter_mut().enumerate() {
+            *count = u16_at(2 + 2 * region);
+        }
+        // every partial flush counts for the regions it touches as well
+        if region_flushes.iter().any(|&count| count > partial_flushes) {
+            return Err(Error::InvalidFormat);
+        }
+        let age = &data[Self::SIZE - 4..];
+        let age = u32::from_le_bytes([age[0], age[1], age[2], age[3]]);
+        let age = (age as i64 * 1_000_000)
+            .saturating_add(asleep.as_micros().min(i64::MAX as u128) as i64);
+        self.partial_flushes = partial_flushes;
+        self.region_flushes = region_flushes;
+        self.last_full = (now as i64).saturating_sub(age);
+        Ok(())
+    }
+
+    /// The regions (bit mask) have been refreshed.
+    pub(crate) fn reset_regions(&mut self, regions: u16) {
+        for region in 0..RefreshPolicy::REGIONS {
+            if regions & 1 << region != 0 {
+                self.region_flushes[region] = 0;
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Run-length encoding of packed 4bpp images.
+//!
+//! This module only depends on `core` and `alloc`, it is shared with the
+//! `imgconvert` tool.
+//!
+//! A compressed image is a little endian blob:
+//!
+//! | Offset | Size | Content                   |
+//! |--------|------|---------------------------|
+//! | 0      | 4    | Magic `EPI4`              |
+//! | 4      | 1    | Version (1)               |
+//! | 5      | 1    | Reserved                  |
+//! | 6      | 2    | Width (`u16`)             |
+//! | 8      | 2    | Height (`u16`)            |
+//! | 10     | 2    | Reserved                  |
+//! | 12     | ...  | Rows                      |
+//!
+//! Each row is encoded independently as a sequence of spans covering exactly
+//! `width` pixels. The first byte `c` of a span determines its type:
+//!
+//! - `0x00..=0x7F`: `c + 1` literal pixels follow, packed like the framebuffer
+//!   (low nibble first, padded to a full byte).
+//! - `0x80..=0xFF`: a run, the next byte `b` holds the color in the low nibble.
+//!   The run is `((c & 0x7F) | (b >> 4) << 7) + 1` pixels long.
+
+use alloc::vec::Vec;
+
+pub const MAGIC: &[u8; 4] = b"EPI4";
+pub const VERSION: u8 = 1;
+pub const HEADER_SIZE: usize = 12;
+
+const MAX_LITERAL: usize = 0x80;
+const MAX_RUN: usize = 0x800;
+/// Runs shorter than this are stored as literals.
+const MIN_RUN: usize = 3;
+
+/// A decoded span of a row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Span<'a> {
+    /// `length` pixels of the same color.
+    Run { color: u8, length: usize },
+    /// `length` packed pixels.
+    Literal { data: &'a [u8], length: usize },
+}
+
+impl<'a> Span<'a> {
+    pub fn length(&self) -> usize {
+        match *self {
+            Span::Run { length, .. } | Span::Literal { length, .. } => length,
+        }
+    }
+}
+
+fn nibble(data: &[u8], x: usize) -> u8 {
+    match x % 2 {
+        1 => data[x / 2] >> 4,
+        _ => data[x / 2] & 0x0F,
+    }
+}
+
+/// Parse the header, returns the width and height as well as the encoded
+/// rows.
+pub fn parse_header(data: &[u8]) -> Option<(u16, u16, &[u8])> {
+    if data.len() < HEADER_SIZE || &data[0..4] != MAGIC || data[4] != VERSION {
+        return None;
+    }
+    let width = u16::from_le_bytes([data[6], data[7]]);
+    let height = u16::from_le_bytes([data[8], data[9]]);
+    Some((width, height, &data[HEADER_SIZE..]))
+}
+
+/// Decode a single row of `width` pixels from the start of `data`, calling
+/// `span` with the x position and the span. On success, `data` is advanced
+/// to the next row.
+///
+/// Returns `None` if the data is malformed.
+pub fn decode_row<'a>(
+    data: &mut &'a [u8],
+    width: usize,
+    mut span: impl FnMut(usize, Span<'a>),
+) -> Option<()> {
+    let mut input = *data;
+    let mut x = 0;
+    while x < width {
+        let (&control, rest) = input.split_first()?;
+        let decoded = match control {
+            0x00..=0x7F => {
+                let length = control as usize + 1;
+                let bytes = length.div_ceil(2);
+                if rest.len() < bytes {
+                    return None;
+                }
+                input = &rest[bytes..];
+                Span::Literal {
+                    data: &rest[..bytes],
+                    length,
+                }
+            }
+            _ => {
+                let (&color, rest) = rest.split_first()?;
+                input = rest;
+                Span::Run {
+                    color: color & 0x0F,
+                    length: ((control & 0x7F) as usize | (color as usize >> 4) << 7) + 1,
+                }
+            }
+        };
+        if x + decoded.length() > width {
+            return None;
+        }
+        span(x, decoded);
+        x += decoded.length();
+    }
+    *data = input;
+    Some(())
+}
+
+/// Check that `rows` contains exactly `height` rows of `width` pixels.
+pub fn validate(mut rows: &[u8], width: u16, height: u16) -> bool {
+    for _ in 0..height {
+        if decode_row(&mut rows, width as usize, |_, _| {}).is_none() {
+            return false;
+        }
+    }
+    rows.is_empty()
+}
+
+fn push_literal(out: &mut Vec<u8>, row: &[u8], start: usize, length: usize) {
+    out.push((length - 1) as u8);
+    for i in (start..start + length).step_by(2) {
+        let high = match i + 1 < start + length {
+            true => nibble(row, i + 1) << 4,
+            false => 0,
+        };
+        out.push(nibble(row, i) | high);
+    }
+}
+
+/// Encode a single packed row of `width` pixels.
+pub fn encode_row(row: &[u8], width: usize, out: &mut Vec<u8>) {
+    let mut literal_start = 0;
+    let mut x = 0;
+    while x < width {
+        let color = nibble(row, x);
+        let mut run = 1;
+        while x + run < width && run < MAX_RUN && nibble(row, x + run) == color {
+            run += 1;
+        }
+        if run < MIN_RUN {
+            x += run;
+            continue;
+        }
+        while literal_start < x {
+            let length = (x - literal_start).min(MAX_LITERAL);
+            push_literal(out, row, literal_start, length);
+            literal_start += length;
+        }
+        let length = run - 1;
+        out.push(0x80 | (length & 0x7F) as u8);
+        out.push(color | ((length >> 7) as u8) << 4);
+        x += run;
+        literal_start = x;
+    }
+    while literal_start < width {
+        let length = (width - literal_start).min(MAX_LITERAL);
+        push_literal(out, row, literal_start, length);
+        literal_start += length;
+    }
+}
+
+/// Compress a packed 4bpp image, rows are `(width + 1) / 2` bytes long.
+pub fn encode(data: &[u8], width: u16, height: u16) -> Vec<u8> {
+    let mut out = Vec::with_capacity(HEADER_SIZE + data.len() / 4);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[VERSION, 0]);
+    out.extend_from_slice(&width.to_le_bytes());
+    out.extend_from_slice(&height.to_le_bytes());
+    out.extend_from_slice(&[0, 0]);
+    let stride = (width as usize).div_ceil(2);
+    if stride == 0 {
+        return out;
+    }
+    for row in data.chunks(stride).take(height as usize) {
+        encode_row(row, width as usize, &mut out);
+    }
+    out
+}
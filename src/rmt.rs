@@ -1,47 +1,69 @@
-use core::ops::DerefMut;
+use core::ptr::addr_of_mut;
 
 use esp_hal::{
     clock::Clocks,
-    gpio::GpioPin,
-    into_ref,
-    peripheral::{Peripheral, PeripheralRef},
+    gpio::OutputPin,
+    peripheral::Peripheral,
     peripherals,
     prelude::*,
     rmt,
-    rmt::{Channel, PulseCode, TxChannel, TxChannelCreator},
+    rmt::{Channel, PulseCode, SingleShotTxTransaction, TxChannel, TxChannelCreator},
     Blocking,
 };
 
-pub(crate) struct Rmt<'a> {
-    tx_channel: Option<Channel<Blocking, 1>>,
-    clocks: &'a Clocks<'a>,
-    rmt: PeripheralRef<'a, peripherals::RMT>,
+/// Maximum number of pulses sent in one transmission.
+const QUEUE_SIZE: usize = 16;
+
+const EMPTY_PULSE: PulseCode = PulseCode {
+    level1: false,
+    length1: 0,
+    level2: false,
+    length2: 0,
+};
+
+type TxChannel1 = Channel<Blocking, 1>;
+type Transaction = SingleShotTxTransaction<'static, TxChannel1, PulseCode>;
+
+/// Two pulse buffers, one is filled while the other one is transmitted. Each
+/// buffer holds an additional pulse for the end marker.
+fn pulse_buffer(index: usize) -> &'static mut [PulseCode; QUEUE_SIZE + 1] {
+    static mut BUFFERS: [[PulseCode; QUEUE_SIZE + 1]; 2] = [[EMPTY_PULSE; QUEUE_SIZE + 1]; 2];
+    unsafe { &mut (*addr_of_mut!(BUFFERS))[index] }
 }
 
-impl<'a> Rmt<'a> {
-    pub(crate) fn new(rmt: impl Peripheral<P = peripherals::RMT> + 'a, clocks: &'a Clocks) -> Self {
-        into_ref!(rmt);
-        Rmt {
-            tx_channel: None,
-            clocks,
-            rmt,
-        }
-    }
+enum State {
+    /// The channel is idle.
+    Idle(TxChannel1),
+    /// A transmission is in flight, the channel is reclaimed once it is done.
+    Busy(Transaction),
+    /// The channel is taken while switching between the other states.
+    Taken,
+}
 
-    fn ensure_channel(&mut self) -> Result<(), crate::Error> {
-        if self.tx_channel.is_some() {
-            return Ok(());
-        }
-        let rmt = rmt::Rmt::new(
-            unsafe { self.rmt.deref_mut().clone_unchecked() }, // TODO: find better solution
-            80.MHz(),
-            self.clocks,
-        )
-        .map_err(crate::Error::Rmt)?;
+/// Pulse generator for the CKV line.
+///
+/// Pulses are queued and sent in batches. A transmission runs in the
+/// background until the channel is needed again, so non-blocking pulses
+/// overlap with the data transfer of the row.
+pub(crate) struct Rmt {
+    state: State,
+    /// Index of the buffer currently being filled.
+    buffer: usize,
+    /// Number of pulses queued in the buffer.
+    queued: usize,
+}
+
+impl Rmt {
+    pub(crate) fn new<'a>(
+        rmt: impl Peripheral<P = peripherals::RMT> + 'a,
+        ckv: impl Peripheral<P = impl OutputPin> + 'a,
+        clocks: &'a Clocks,
+    ) -> crate::Result<Self> {
+        let rmt = rmt::Rmt::new(rmt, 80.MHz(), clocks).map_err(crate::Error::Rmt)?;
         let tx_channel = rmt
             .channel1
             .configure(
-                unsafe { GpioPin::<38>::steal() }, // TODO: find better solution
+                ckv,
                 rmt::TxChannelConfig {
                     clk_divider: 8,
                     idle_output_level: false,
@@ -52,46 +74,82 @@ impl<'a> Rmt<'a> {
                 },
             )
             .map_err(crate::Error::Rmt)?;
-        self.tx_channel = Some(tx_channel);
+        Ok(Rmt {
+            state: State::Idle(tx_channel),
+            buffer: 0,
+            queued: 0,
+        })
+    }
+
+    /// Send a single pulse, any queued pulses are sent first. If `wait` is
+    /// set, this blocks until the pulse is done.
+    pub(crate) fn pulse(&mut self, high: u16, low: u16, wait: bool) -> crate::Result<()> {
+        self.queue(high, low)?;
+        self.send()?;
+        if wait {
+            self.wait()?;
+        }
         Ok(())
     }
 
-    pub(crate) fn pulse(&mut self, high: u16, low: u16, wait: bool) -> Result<(), crate::Error> {
-        self.ensure_channel()?;
-        let tx_channel = self.tx_channel.take().ok_or(crate::Error::Unknown)?;
-        let data = if high > 0 {
-            [
-                PulseCode {
-                    level1: true,
-                    length1: high,
-                    level2: false,
-                    length2: low,
-                },
-                PulseCode::default(), // end of pulse indicator
-            ]
+    /// Queue a pulse. The queue is sent once it is full, by [`Rmt::send`] or
+    /// with the next [`Rmt::pulse`].
+    pub(crate) fn queue(&mut self, high: u16, low: u16) -> crate::Result<()> {
+        let pulse = if high > 0 {
+            PulseCode {
+                level1: true,
+                length1: high,
+                level2: false,
+                length2: low,
+            }
         } else {
-            [
-                PulseCode {
-                    level1: true,
-                    length1: low,
-                    level2: false,
-                    length2: 0,
-                },
-                // FIXME: find more elegant solution
-                PulseCode::default(), /* end of pulse indicator (redundant, but simplifies the
-                                       * code) */
-            ]
+            PulseCode {
+                level1: true,
+                length1: low,
+                level2: false,
+                length2: 0,
+            }
         };
-        let tx = tx_channel.transmit(&data);
-        // FIXME: This is the culprit.. We need the channel later again but can't wait
-        // due to some time sensitive operations. Not sure how to solve this
-        if wait {
-            self.tx_channel = Some(
-                tx.wait()
-                    .map_err(|(err, _)| err)
-                    .map_err(crate::Error::Rmt)?,
-            );
+        pulse_buffer(self.buffer)[self.queued] = pulse;
+        self.queued += 1;
+        // a zero length terminates the transmission, nothing can follow it
+        if self.queued == QUEUE_SIZE || high == 0 {
+            self.send()?;
+        }
+        Ok(())
+    }
+
+    /// Start transmitting the queued pulses without waiting for them.
+    pub(crate) fn send(&mut self) -> crate::Result<()> {
+        if self.queued == 0 {
+            return Ok(());
         }
+        let tx_channel = self.reclaim()?;
+        let buffer = pulse_buffer(self.buffer);
+        buffer[self.queued] = EMPTY_PULSE; // end of pulse indicator
+        let buffer: &'static [PulseCode] = buffer;
+        self.state = State::Busy(tx_channel.transmit(&buffer[..=self.queued]));
+        self.buffer ^= 1;
+        self.queued = 0;
+        Ok(())
+    }
+
+    /// Wait until the transmission in flight is done.
+    pub(crate) fn wait(&mut self) -> crate::Result<()> {
+        let tx_channel = self.reclaim()?;
+        self.state = State::Idle(tx_channel);
         Ok(())
     }
+
+    /// Take the channel, waiting for the transmission in flight.
+    fn reclaim(&mut self) -> crate::Result<TxChannel1> {
+        match core::mem::replace(&mut self.state, State::Taken) {
+            State::Idle(tx_channel) => Ok(tx_channel),
+            State::Busy(tx) => tx.wait().map_err(|(err, tx_channel)| {
+                self.state = State::Idle(tx_channel);
+                crate::Error::Rmt(err)
+            }),
+            State::Taken => Err(crate::Error::Unknown),
+        }
+    }
 }
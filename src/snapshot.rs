@@ -0,0 +1,94 @@
+//! Persist the display state across deep sleep.
+//!
+//! The driver keeps track of the content of the screen to redraw it on
+//! automatic refreshes (see [`crate::refresh`]). After waking from deep sleep
+//! this information is lost, a snapshot taken before going to sleep restores
+//! it together with the refresh counters.
+//!
+//! A snapshot is the run-length encoded screen content (see
+//! [`crate::image::CompressedImage`]), a screen with some text on a white
+//! background takes a few KB and fits into the RTC fast memory:
+//!
+//! ```rust no_run
+//! use esp_hal::prelude::*;
+//!
+//! #[ram(rtc_fast)]
+//! static mut SNAPSHOT: [u8; 4096] = [0; 4096];
+//! #[ram(rtc_fast)]
+//! static mut SNAPSHOT_LEN: usize = 0;
+//! ```
+//!
+//! Larger snapshots can be stored in flash.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::{
+    display::{self, Display},
+    image::CompressedImage,
+    refresh::RefreshState,
+    rle,
+    Error,
+    Result,
+};
+
+const MAGIC: &[u8; 4] = b"EPS4";
+const VERSION: u8 = 2;
+/// Flag of the header, the screen is [inverted](Display::set_inverted).
+const INVERTED: u8 = 1 << 0;
+const HEADER_SIZE: usize = 6 + RefreshState::SIZE;
+
+impl<'a> Display<'a> {
+    /// Take a snapshot of the screen content, the refresh counters and whether
+    /// the screen is inverted.
+    ///
+    /// Content in the framebuffer which has not been flushed yet is not part
+    /// of the snapshot.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut snapshot = Vec::new();
+        snapshot.extend_from_slice(MAGIC);
+        let flags = match self.is_inverted() {
+            true => INVERTED,
+            false => 0,
+        };
+        snapshot.extend_from_slice(&[VERSION, flags]);
+        self.refresh_state().to_bytes(&mut snapshot, display::now());
+        snapshot.extend(rle::encode(self.screen(), Self::WIDTH, Self::HEIGHT));
+        snapshot
+    }
+
+    /// Restore a snapshot taken by [`Display::snapshot`], e.g. after waking
+    /// from deep sleep. The screen is not updated.
+    ///
+    /// The timer of the driver restarts on wake up, `asleep` is the time
+    /// passed since the snapshot has been taken (e.g. measured by the RTC,
+    /// which keeps running in deep sleep). It is added to the age of the last
+    /// full refresh for
+    /// [`RefreshPolicy::max_interval`](crate::refresh::RefreshPolicy::max_interval).
+    ///
+    /// Returns [`Error::InvalidFormat`] if the snapshot is malformed, the
+    /// state of the display is left untouched in that case.
+    pub fn restore(&mut self, snapshot: &[u8], asleep: Duration) -> Result<()> {
+        if snapshot.len() < HEADER_SIZE || &snapshot[0..4] != MAGIC || snapshot[4] != VERSION {
+            return Err(Error::InvalidFormat);
+        }
+        let screen = CompressedImage::new(&snapshot[HEADER_SIZE..])?;
+        if screen.width() != Self::WIDTH || screen.height() != Self::HEIGHT {
+            return Err(Error::InvalidFormat);
+        }
+        let mut counters = [0u8; RefreshState::SIZE];
+        counters.copy_from_slice(&snapshot[6..HEADER_SIZE]);
+        let mut refresh = *self.refresh_state();
+        refresh.restore(&counters, asleep, display::now())?;
+        let mut buffer = [0u8; Self::WIDTH as usize / 2];
+        let stride = buffer.len();
+        // switch first, the stored content replaces the flipped screen
+        self.set_inverted(snapshot[5] & INVERTED != 0);
+        let content = self.screen_mut();
+        screen.decode(&mut buffer, |y, row| {
+            content[y as usize * stride..(y as usize + 1) * stride].copy_from_slice(row)
+        })?;
+        *self.refresh_state_mut() = refresh;
+        Ok(())
+    }
+}
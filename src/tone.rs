@@ -0,0 +1,91 @@
+//! Tone mapping of the gray levels.
+//!
+//! A [`ToneCurve`] maps each of the 16 levels in the framebuffer to the level
+//! drawn on the screen. It is applied by [`Display::flush`] and
+//! [`Display::flush_waveform`], so the same content can be drawn with
+//! different mappings, e.g. photos with [`ToneCurve::SRGB`] and UI with
+//! [`ToneCurve::HIGH_CONTRAST_TEXT`], without re-quantizing the assets.
+//!
+//! ```rust no_run
+//! # fn example(display: &mut lilygo_epd47::Display) -> Result<(), lilygo_epd47::Error> {
+//! use lilygo_epd47::{tone::ToneCurve, DrawMode};
+//!
+//! display.set_tone_curve(ToneCurve::SRGB);
+//! // draw the photo
+//! display.flush(DrawMode::BlackOnWhite)?;
+//! display.set_tone_curve(ToneCurve::LINEAR);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`Display::flush`]: crate::Display::flush
+//! [`Display::flush_waveform`]: crate::Display::flush_waveform
+
+use crate::{Error, Result};
+
+/// Mapping of the 16 gray levels, indexed by the level in the framebuffer.
+///
+/// White (`0xF`) should be mapped to white, otherwise the untouched pixels of
+/// every updated row are darkened as well.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ToneCurve {
+    levels: [u8; 16],
+}
+
+impl Default for ToneCurve {
+    fn default() -> Self {
+        Self::LINEAR
+    }
+}
+
+impl ToneCurve {
+    /// Every level is drawn as is (default).
+    pub const LINEAR: ToneCurve = ToneCurve {
+        levels: [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    };
+    /// Levels are treated as gamma encoded (`15 * (v / 15)^(1 / 2.2)`),
+    /// spreading dark tones over more levels. Suited for photos. Level 14 is
+    /// kept below white.
+    pub const SRGB: ToneCurve = ToneCurve {
+        levels: [0, 4, 6, 7, 8, 9, 10, 11, 11, 12, 12, 13, 14, 14, 14, 15],
+    };
+    /// Pushes light and dark levels to white and black, keeping a steep ramp
+    /// in between for anti-aliased edges. Suited for text and UI.
+    pub const HIGH_CONTRAST_TEXT: ToneCurve = ToneCurve {
+        levels: [0, 0, 0, 0, 1, 3, 5, 7, 8, 10, 12, 14, 15, 15, 15, 15],
+    };
+
+    /// Create a curve from the level drawn for each framebuffer level.
+    ///
+    /// Returns [`Error::InvalidColor`] if a level is greater than 0x0F.
+    pub fn new(levels: [u8; 16]) -> Result<Self> {
+        match levels.iter().all(|&level| level <= 0x0F) {
+            true => Ok(ToneCurve { levels }),
+            false => Err(Error::InvalidColor),
+        }
+    }
+
+    /// The level drawn for each framebuffer level.
+    pub fn levels(&self) -> [u8; 16] {
+        self.levels
+    }
+
+    /// The level drawn for the framebuffer level.
+    pub fn map(&self, level: u8) -> u8 {
+        self.levels[(level & 0x0F) as usize]
+    }
+
+    /// Whether the curve leaves all levels untouched.
+    pub fn is_linear(&self) -> bool {
+        *self == Self::LINEAR
+    }
+
+    /// Mapping of framebuffer bytes (two pixels).
+    pub(crate) fn byte_lut(&self) -> [u8; 256] {
+        let mut lut = [0u8; 256];
+        for (byte, mapped) in lut.iter_mut().enumerate() {
+            *mapped = self.map((byte >> 4) as u8) << 4 | self.map(byte as u8);
+        }
+        lut
+    }
+}
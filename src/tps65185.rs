@@ -0,0 +1,372 @@
+//! Driver for the TPS65185 / TPS651851 e-paper PMIC.
+//!
+//! The driver only depends on [`embedded_hal::i2c::I2c`] (and
+//! [`embedded_hal::delay::DelayNs`] for polling) and can therefore be used
+//! independently of the rest of this crate.
+
+use embedded_hal::{delay::DelayNs, i2c::I2c};
+
+/// Default I2C address of the PMIC.
+pub const ADDRESS: u8 = 0x68;
+
+const REG_TMST_VALUE: u8 = 0x00;
+const REG_ENABLE: u8 = 0x01;
+const REG_VCOM1: u8 = 0x03;
+const REG_VCOM2: u8 = 0x04;
+const REG_INT_EN1: u8 = 0x05;
+const REG_INT1: u8 = 0x07;
+const REG_UPSEQ0: u8 = 0x09;
+const REG_DWNSEQ0: u8 = 0x0B;
+const REG_TMST1: u8 = 0x0D;
+const REG_PG: u8 = 0x0F;
+const REG_REVID: u8 = 0x10;
+
+const VCOM2_PROG: u8 = 1 << 6;
+const VCOM2_VCOM8: u8 = 1 << 0;
+const TMST1_READ_THERM: u8 = 1 << 7;
+const TMST1_CONV_END: u8 = 1 << 5;
+/// Power good bits of all rails in the PG register.
+const PG_ALL: u8 = 0xFA;
+
+/// Poll interval in milliseconds.
+const POLL_INTERVAL: u32 = 1;
+
+/// Errors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error<E> {
+    /// Pass-through
+    I2c(E),
+    /// The PMIC did not finish the operation in time.
+    Timeout,
+    /// Provided VCOM exceeds the allowed range of 0 - 5110 mV
+    InvalidVcom,
+    /// Provided strobe or delay exceeds the allowed range of 0 - 3
+    InvalidSequence,
+}
+
+/// Content of the ENABLE register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Enable(pub u8);
+
+impl Enable {
+    /// Transition to active mode, runs the power-up sequence.
+    pub const ACTIVE: Self = Enable(1 << 7);
+    /// Transition to standby mode, runs the power-down sequence.
+    pub const STANDBY: Self = Enable(1 << 6);
+    /// 3.3V power switch.
+    pub const V3P3: Self = Enable(1 << 5);
+    /// VCOM buffer.
+    pub const VCOM: Self = Enable(1 << 4);
+    /// Positive gate driver rail.
+    pub const VDDH: Self = Enable(1 << 3);
+    /// Positive source driver rail.
+    pub const VPOS: Self = Enable(1 << 2);
+    /// Negative gate driver rail.
+    pub const VEE: Self = Enable(1 << 1);
+    /// Negative source driver rail.
+    pub const VNEG: Self = Enable(1 << 0);
+    /// All rails including VCOM and the 3.3V switch.
+    pub const ALL_RAILS: Self = Enable(0x3F);
+
+    /// Whether all bits of `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for Enable {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Enable(self.0 | rhs.0)
+    }
+}
+
+/// Content of the interrupt registers INT1 (high byte) and INT2 (low byte).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interrupts(pub u16);
+
+impl Interrupts {
+    /// Panel temperature changed by more than the configured threshold.
+    pub const DTX: Self = Interrupts(1 << 15);
+    /// Thermal shutdown.
+    pub const TSD: Self = Interrupts(1 << 14);
+    /// Thermal shutdown early warning.
+    pub const HOT: Self = Interrupts(1 << 13);
+    /// Thermistor hot.
+    pub const TMST_HOT: Self = Interrupts(1 << 12);
+    /// Thermistor cold.
+    pub const TMST_COLD: Self = Interrupts(1 << 11);
+    /// Input under-voltage lockout.
+    pub const UVLO: Self = Interrupts(1 << 10);
+    /// VCOM measurement (acquisition) completed.
+    pub const ACQC: Self = Interrupts(1 << 9);
+    /// VCOM programming completed.
+    pub const PRGC: Self = Interrupts(1 << 8);
+    /// VB under-voltage.
+    pub const VB_UV: Self = Interrupts(1 << 7);
+    /// VDDH under-voltage.
+    pub const VDDH_UV: Self = Interrupts(1 << 6);
+    /// VN under-voltage.
+    pub const VN_UV: Self = Interrupts(1 << 5);
+    /// VPOS under-voltage.
+    pub const VPOS_UV: Self = Interrupts(1 << 4);
+    /// VEE under-voltage.
+    pub const VEE_UV: Self = Interrupts(1 << 3);
+    /// VCOM fault.
+    pub const VCOMF: Self = Interrupts(1 << 2);
+    /// VNEG under-voltage.
+    pub const VNEG_UV: Self = Interrupts(1 << 1);
+    /// Thermistor conversion completed.
+    pub const EOC: Self = Interrupts(1 << 0);
+
+    /// Interrupts which indicate a fault (the rails have been shut down).
+    pub const FAULTS: Self = Interrupts(
+        Self::TSD.0
+            | Self::UVLO.0
+            | Self::VB_UV.0
+            | Self::VDDH_UV.0
+            | Self::VN_UV.0
+            | Self::VPOS_UV.0
+            | Self::VEE_UV.0
+            | Self::VCOMF.0
+            | Self::VNEG_UV.0,
+    );
+
+    /// Whether all bits of `other` are set.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether any fault is flagged.
+    pub fn is_fault(&self) -> bool {
+        self.0 & Self::FAULTS.0 != 0
+    }
+}
+
+/// Power-up or power-down sequence of the rails.
+///
+/// Each rail is assigned to one of four strobes (0 - 3), the strobes are
+/// executed in order. The delays are applied after the corresponding strobe,
+/// 0 - 3 equals 3/6/9/12 ms on power-up and 6/12/24/48 ms on power-down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sequence {
+    /// Strobe of the positive gate driver rail.
+    pub vddh: u8,
+    /// Strobe of the positive source driver rail.
+    pub vpos: u8,
+    /// Strobe of the negative gate driver rail.
+    pub vee: u8,
+    /// Strobe of the negative source driver rail.
+    pub vneg: u8,
+    /// Delay after each strobe.
+    pub delays: [u8; 4],
+}
+
+impl Sequence {
+    /// Power-up sequence VNEG, VEE, VPOS, VDDH with 6ms delays.
+    pub const POWER_UP: Self = Sequence {
+        vddh: 3,
+        vpos: 2,
+        vee: 1,
+        vneg: 0,
+        delays: [1, 1, 1, 1],
+    };
+    /// Power-down sequence VDDH, VPOS, VEE, VNEG with 6ms delays.
+    pub const POWER_DOWN: Self = Sequence {
+        vddh: 0,
+        vpos: 1,
+        vee: 2,
+        vneg: 3,
+        delays: [0, 0, 0, 0],
+    };
+
+    fn encode<E>(&self) -> Result<[u8; 2], Error<E>> {
+        let values = [self.vddh, self.vpos, self.vee, self.vneg];
+        if values.iter().chain(self.delays.iter()).any(|v| *v > 3) {
+            return Err(Error::InvalidSequence);
+        }
+        let strobes = values.iter().fold(0, |acc, v| (acc << 2) | v);
+        let delays = self.delays.iter().rev().fold(0, |acc, v| (acc << 2) | v);
+        Ok([strobes, delays])
+    }
+}
+
+/// TPS65185 PMIC on an I2C bus.
+///
+/// Generates the source and gate driver rails and VCOM of the panel and
+/// measures the panel temperature via the thermistor input.
+pub struct Tps65185<I2C> {
+    i2c: I2C,
+    address: u8,
+}
+
+impl<I2C, E> Tps65185<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    /// Maximum VCOM in millivolts.
+    pub const MAX_VCOM: u16 = 5110;
+    /// Maximum number of polls (every millisecond) before an operation times
+    /// out.
+    pub const POLL_RETRIES: u32 = 500;
+
+    /// Create a new driver using the default [`ADDRESS`].
+    pub fn new(i2c: I2C) -> Self {
+        Self::with_address(i2c, ADDRESS)
+    }
+
+    /// Create a new driver using a custom address.
+    pub fn with_address(i2c: I2C, address: u8) -> Self {
+        Tps65185 { i2c, address }
+    }
+
+    /// Release the underlying bus.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+
+    /// Access the underlying bus, e.g. to talk to other devices on the bus.
+    pub(crate) fn i2c(&mut self) -> &mut I2C {
+        &mut self.i2c
+    }
+
+    fn read(&mut self, register: u8) -> Result<u8, Error<E>> {
+        let mut buffer = [0u8; 1];
+        self.i2c
+            .write_read(self.address, &[register], &mut buffer)
+            .map_err(Error::I2c)?;
+        Ok(buffer[0])
+    }
+
+    fn write(&mut self, register: u8, value: u8) -> Result<(), Error<E>> {
+        self.i2c
+            .write(self.address, &[register, value])
+            .map_err(Error::I2c)
+    }
+
+    fn poll(
+        &mut self,
+        delay: &mut impl DelayNs,
+        mut done: impl FnMut(&mut Self) -> Result<bool, Error<E>>,
+    ) -> Result<(), Error<E>> {
+        for _ in 0..Self::POLL_RETRIES {
+            if done(self)? {
+                return Ok(());
+            }
+            delay.delay_ms(POLL_INTERVAL);
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Content of the revision register.
+    pub fn revision(&mut self) -> Result<u8, Error<E>> {
+        self.read(REG_REVID)
+    }
+
+    /// Content of the ENABLE register.
+    pub fn enabled(&mut self) -> Result<Enable, Error<E>> {
+        self.read(REG_ENABLE).map(Enable)
+    }
+
+    /// Write the ENABLE register.
+    pub fn set_enabled(&mut self, enable: Enable) -> Result<(), Error<E>> {
+        self.write(REG_ENABLE, enable.0)
+    }
+
+    /// Configure the order and timing in which the rails are enabled.
+    pub fn set_power_up_sequence(&mut self, sequence: Sequence) -> Result<(), Error<E>> {
+        let [strobes, delays] = sequence.encode()?;
+        self.write(REG_UPSEQ0, strobes)?;
+        self.write(REG_UPSEQ0 + 1, delays)
+    }
+
+    /// Configure the order and timing in which the rails are disabled.
+    pub fn set_power_down_sequence(&mut self, sequence: Sequence) -> Result<(), Error<E>> {
+        let [strobes, delays] = sequence.encode()?;
+        self.write(REG_DWNSEQ0, strobes)?;
+        self.write(REG_DWNSEQ0 + 1, delays)
+    }
+
+    /// Whether all rails are in regulation.
+    pub fn power_good(&mut self) -> Result<bool, Error<E>> {
+        Ok(self.read(REG_PG)? & PG_ALL == PG_ALL)
+    }
+
+    /// Run the power-up sequence and wait until all rails are in regulation.
+    pub fn power_up(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        self.set_enabled(Enable::ACTIVE | Enable::ALL_RAILS)?;
+        self.poll(delay, |pmic| pmic.power_good())
+    }
+
+    /// Run the power-down sequence and enter standby.
+    pub fn power_down(&mut self) -> Result<(), Error<E>> {
+        self.set_enabled(Enable::STANDBY)
+    }
+
+    /// Read the VCOM setting in millivolts. VCOM is negative, the value is
+    /// the magnitude.
+    pub fn vcom(&mut self) -> Result<u16, Error<E>> {
+        let low = self.read(REG_VCOM1)? as u16;
+        let high = (self.read(REG_VCOM2)? & VCOM2_VCOM8) as u16;
+        Ok(((high << 8) | low) * 10)
+    }
+
+    /// Set VCOM in millivolts (10mV resolution). VCOM is negative, the value
+    /// is the magnitude. The setting is volatile, see [`Self::program_vcom`].
+    pub fn set_vcom(&mut self, millivolts: u16) -> Result<(), Error<E>> {
+        if millivolts > Self::MAX_VCOM {
+            return Err(Error::InvalidVcom);
+        }
+        let value = millivolts / 10;
+        self.write(REG_VCOM1, value as u8)?;
+        let vcom2 = self.read(REG_VCOM2)?;
+        self.write(
+            REG_VCOM2,
+            (vcom2 & !(VCOM2_VCOM8 | VCOM2_PROG)) | (value >> 8) as u8,
+        )
+    }
+
+    /// Store the current VCOM setting in the EEPROM of the PMIC so it
+    /// survives a power cycle.
+    pub fn program_vcom(&mut self, delay: &mut impl DelayNs) -> Result<(), Error<E>> {
+        let vcom2 = self.read(REG_VCOM2)?;
+        // clear pending interrupts
+        self.interrupts()?;
+        self.write(REG_VCOM2, vcom2 | VCOM2_PROG)?;
+        let mut interrupts = Interrupts(0);
+        self.poll(delay, |pmic| {
+            interrupts.0 |= pmic.interrupts()?.0;
+            Ok(interrupts.contains(Interrupts::PRGC))
+        })
+    }
+
+    /// Read the pending interrupts. Reading clears them.
+    pub fn interrupts(&mut self) -> Result<Interrupts, Error<E>> {
+        let mut buffer = [0u8; 2];
+        self.i2c
+            .write_read(self.address, &[REG_INT1], &mut buffer)
+            .map_err(Error::I2c)?;
+        Ok(Interrupts(u16::from_be_bytes(buffer)))
+    }
+
+    /// Select which interrupts assert the interrupt line.
+    pub fn set_interrupt_enable(&mut self, interrupts: Interrupts) -> Result<(), Error<E>> {
+        let [int1, int2] = interrupts.0.to_be_bytes();
+        self.i2c
+            .write(self.address, &[REG_INT_EN1, int1, int2])
+            .map_err(Error::I2c)
+    }
+
+    /// Trigger a conversion and read the panel temperature (thermistor) in
+    /// degrees celsius.
+    pub fn temperature(&mut self, delay: &mut impl DelayNs) -> Result<i8, Error<E>> {
+        let tmst1 = self.read(REG_TMST1)?;
+        self.write(REG_TMST1, tmst1 | TMST1_READ_THERM)?;
+        self.poll(
+            delay,
+            |pmic| Ok(pmic.read(REG_TMST1)? & TMST1_CONV_END != 0),
+        )?;
+        Ok(self.read(REG_TMST_VALUE)? as i8)
+    }
+}
@@ -1,13630 +1,351 @@
-#![allow(unused)]
-pub struct Phases {}
+//! Waveforms driving the panel from one gray level to another.
+//!
+//! A waveform consists of phases, one frame each. For every phase a lookup
+//! table tells whether a pixel is darkened, lightened or left alone, given
+//! its current level (from) and its target level (to). The phases depend on
+//! the temperature, so a waveform holds a set of phases for each temperature
+//! range.
+//!
+//! The built-in [ED047TC2 waveforms](Waveform::ed047tc2) are taken from
+//! [epdiy]. Each mode is gated by a cargo feature (`waveform-du`,
+//! `waveform-gc16` and `waveform-gl16`, all enabled by default), so firmware
+//! only pays for the modes it uses: 9 KiB of flash for DU and 19 KiB each for
+//! GC16 and GL16. Custom waveforms are loaded at runtime from a single little
+//! endian blob:
+//!
+//! | Offset | Size | Content                             |
+//! |--------|------|-------------------------------------|
+//! | 0      | 4    | Magic `EPW4`                        |
+//! | 4      | 1    | Version (1)                         |
+//! | 5      | 1    | Number of temperature ranges        |
+//! | 6      | 2    | Reserved                            |
+//! | 8      | ...  | Temperature ranges, see below       |
+//!
+//! Each temperature range is made up of:
+//!
+//! | Offset | Size       | Content                                         |
+//! |--------|------------|-------------------------------------------------|
+//! | 0      | 1          | Lower bound (`i8`, °C, inclusive)               |
+//! | 1      | 1          | Upper bound (`i8`, °C, exclusive)               |
+//! | 2      | 1          | Number of phases (1 to [`MAX_PHASES`])          |
+//! | 3      | 1          | Flags, bit 0: phase times present               |
+//! | 4      | 64 * n     | Lookup tables of the phases                     |
+//! | ...    | 2 * n      | Phase times (`u16`, ticks of 0.1µs) if flagged  |
+//!
+//! The lookup table of a phase has 4 bytes for each target level. Each byte
+//! covers 4 current levels with 2 bits each, the highest bits hold the first
+//! level: `0` leaves the pixel alone, `1` darkens and `2` lightens it.
+//! Temperature ranges must be sorted and must not overlap.
+//!
+//! [epdiy]: https://github.com/vroland/epdiy
 
-const EPD_WP_ED047TC2_1_5_DATA: [[[u8; 4]; 16]; 25] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa0, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa8, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x80, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa0, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x01, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0x00, 0x00, 0x15, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-    ],
-    [
-        [0x00, 0x01, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
+use alloc::vec::Vec;
 
-// const EpdWaveformPhases epd_wp_ED047TC2_1_5 = [ .phases = 25, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_5_data[0] ];
-const EPD_WP_ED047TC2_1_6_DATA: [[[u8; 4]; 16]; 22] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x84, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x84, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa8, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x01, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa0, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa0, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x18],
-    ],
-    [
-        [0x00, 0x01, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x18],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x98],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_6 = [ .phases = 22, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_6_data[0] ];
-const EPD_WP_ED047TC2_1_7_DATA: [[[u8; 4]; 16]; 22] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x01, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa0, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x01, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x90],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_7 = [ .phases = 22, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_7_data[0] ];
-const EPD_WP_ED047TC2_1_8_DATA: [[[u8; 4]; 16]; 22] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x00, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa8, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x00, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x04],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x88],
-    ],
-    [
-        [0x00, 0x01, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x88],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x88],
-    ],
-    [
-        [0x00, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x88],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x01, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_8 = [ .phases = 22, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_8_data[0] ];
-const EPD_WP_ED047TC2_1_9_DATA: [[[u8; 4]; 16]; 18] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa0, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x15],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa0, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa8, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x15, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x80, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x01, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x00],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x04],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x84],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_9 = [ .phases = 18, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_9_data[0] ];
-const EPD_WP_ED047TC2_1_10_DATA: [[[u8; 4]; 16]; 17] = [
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x90, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x01],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x90, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa4, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa8, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x01, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa0, 0x40],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x40],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x90],
-    ],
-    [
-        [0x00, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_10 = [ .phases = 17, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_10_data[0] ];
-const EPD_WP_ED047TC2_1_11_DATA: [[[u8; 4]; 16]; 15] = [
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa0, 0x00, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x00, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x80, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x05, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x90, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x00, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xa0, 0x00, 0x54],
-    ],
-    [
-        [0x00, 0x05, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x40, 0xa8],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x85, 0xa8],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_1_11 = [ .phases = 15, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_1_11_data[0] ];
-//const EpdWaveformPhases* epd_wm_ed047tc2_1_ranges[7] = [ &epd_wp_ED047TC2_1_5,&epd_wp_ED047TC2_1_6,&epd_wp_ED047TC2_1_7,&epd_wp_ED047TC2_1_8,&epd_wp_ED047TC2_1_9,&epd_wp_ED047TC2_1_10,&epd_wp_ED047TC2_1_11 ];
-//const EpdWaveformMode epd_wm_ed047tc2_1 = [ .type = 1, .temp_ranges = 7, .range_data = &epd_wm_ed047tc2_1_ranges[0] ];
-const EPD_WP_ED047TC2_2_5_DATA: [[[u8; 4]; 16]; 46] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x88, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x10, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x08, 0x00],
-        [0x00, 0x02, 0x00, 0x80],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa2, 0x88, 0x20, 0x88],
-        [0x22, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0xa2, 0x08],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x44, 0x08, 0x00],
-        [0xa0, 0x02, 0x20, 0xa8],
-        [0x08, 0x62, 0x88, 0x80],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x08, 0x22, 0x88, 0x80],
-        [0x00, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x24, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x80, 0x82, 0xa2, 0x08],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x84, 0x84, 0x28, 0x80],
-        [0xa0, 0x02, 0x20, 0xaa],
-        [0xa8, 0xaa, 0x8a, 0x80],
-        [0x22, 0x84, 0x00, 0x28],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x00, 0x80, 0x28, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x08, 0x00, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x28, 0x00, 0x00, 0x00],
-        [0x00, 0x40, 0x20, 0x00],
-        [0x80, 0xa2, 0xa2, 0x88],
-        [0x00, 0x00, 0x20, 0x02],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x84, 0x88, 0x2a, 0xa2],
-        [0xa0, 0xaa, 0xa8, 0xa8],
-        [0xa8, 0xaa, 0x8a, 0x80],
-        [0x2a, 0xaa, 0x08, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x80, 0x28, 0x08],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x28, 0x24, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x29, 0x80, 0x00, 0x00],
-        [0x00, 0x40, 0x20, 0x00],
-        [0x80, 0xa2, 0xa2, 0x88],
-        [0x00, 0x02, 0xa0, 0x00],
-        [0x02, 0x00, 0x00, 0x81],
-        [0x88, 0xa8, 0xaa, 0xa9],
-        [0xa2, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0x8a, 0x81],
-        [0x2a, 0xaa, 0x2a, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x80, 0x28, 0x04],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa4],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x28, 0xa8, 0x80, 0x00],
-        [0x00, 0x20, 0x00, 0x00],
-        [0x2a, 0x84, 0xa0, 0x08],
-        [0x2a, 0x68, 0x20, 0x80],
-        [0x80, 0xa2, 0xa2, 0xa8],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x02, 0x80, 0x00, 0xa9],
-        [0x9a, 0xa8, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x89],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x8a, 0x28, 0x04],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x64],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x00, 0x88, 0x02, 0x00],
-        [0x29, 0xa8, 0x80, 0x00],
-        [0x00, 0x28, 0x08, 0x02],
-        [0x2a, 0x88, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa0],
-        [0xaa, 0xa2, 0xa2, 0xa9],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x02, 0xa0, 0x00, 0xa9],
-        [0x9a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0x8a, 0xa8, 0x84],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x00, 0x8a, 0x02, 0x08],
-        [0x2a, 0xa8, 0x88, 0x00],
-        [0x00, 0x28, 0x8a, 0x01],
-        [0x2a, 0x88, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x0a, 0xaa, 0x80, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x6a, 0x8a, 0xaa, 0x56],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x20, 0x8a, 0x0a, 0x08],
-        [0x2a, 0xa8, 0xaa, 0x00],
-        [0x00, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x80, 0x02, 0xa0, 0x81],
-        [0x2a, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0x69, 0x8a, 0xaa, 0x56],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0xa0, 0x8a, 0x0a, 0x0a],
-        [0xaa, 0xaa, 0xaa, 0x02],
-        [0x08, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xa0, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x82, 0x82, 0xa0, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0x8a, 0xaa, 0x55],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0xa2, 0x8a, 0x2a, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x28, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x89],
-        [0x2a, 0xaa, 0xa8, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0xa0, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0xaa, 0xaa, 0x55],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x58],
-        [0x2a, 0xaa, 0xa5, 0x05],
-        [0xaa, 0x8a, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x01],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0xaa, 0xaa, 0x55],
-    ],
-    [
-        [0xaa, 0xaa, 0x96, 0xa8],
-        [0xaa, 0xaa, 0x55, 0x09],
-        [0xaa, 0x8a, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x51],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x98, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa4, 0x55],
-        [0xaa, 0xa8, 0x8a, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x54, 0xaa, 0xaa, 0x55],
-    ],
-    [
-        [0xaa, 0xa5, 0x96, 0xa8],
-        [0xaa, 0xa5, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xa9, 0x90, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa6, 0x99, 0x55, 0x55],
-        [0x94, 0xaa, 0xa6, 0x51],
-    ],
-    [
-        [0xaa, 0xa5, 0x56, 0xa8],
-        [0xaa, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x98, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xa9, 0x15, 0x55],
-        [0x92, 0x95, 0x65, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x94, 0xaa, 0x96, 0x01],
-    ],
-    [
-        [0xa9, 0x55, 0x56, 0xa8],
-        [0xa9, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x98, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x9a, 0x85, 0x55, 0x55],
-        [0x94, 0x95, 0x55, 0x55],
-        [0x99, 0xa9, 0x95, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x94, 0xaa, 0x96, 0x01],
-    ],
-    [
-        [0x95, 0x55, 0x56, 0xa8],
-        [0xa5, 0x55, 0x5a, 0xa5],
-        [0xaa, 0xa6, 0xaa, 0x05],
-        [0xaa, 0xaa, 0x6a, 0xa5],
-        [0x2a, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x96, 0x65],
-        [0xaa, 0xaa, 0x1a, 0x59],
-        [0xaa, 0x59, 0x51, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0x98, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x94, 0x6a, 0x96, 0x01],
-    ],
-    [
-        [0x95, 0x55, 0x55, 0x94],
-        [0x95, 0x55, 0xaa, 0x95],
-        [0xaa, 0xa6, 0xaa, 0x05],
-        [0xaa, 0xaa, 0x6a, 0x95],
-        [0x2a, 0xaa, 0xa5, 0x55],
-        [0x9a, 0x6a, 0x55, 0x55],
-        [0xaa, 0x85, 0x5a, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xa8, 0xaa, 0x00, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0x91, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x90, 0x69, 0x95, 0x01],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x96, 0x5a, 0xaa, 0x15],
-        [0xaa, 0xa6, 0xa9, 0x05],
-        [0xaa, 0x9a, 0x65, 0x55],
-        [0x2a, 0x96, 0x65, 0x55],
-        [0x96, 0x65, 0x55, 0x55],
-        [0xa1, 0x95, 0x56, 0x55],
-        [0x28, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x52, 0x55],
-        [0xa9, 0x01, 0x05, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x01],
-    ],
-    [
-        [0x55, 0x55, 0x69, 0x55],
-        [0x9a, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa6, 0xa5, 0x85],
-        [0x9a, 0x56, 0x55, 0x55],
-        [0xaa, 0x95, 0x65, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x56, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0xa1, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x01],
-    ],
-    [
-        [0x55, 0x5a, 0xa9, 0x55],
-        [0x9a, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa5, 0xa5, 0x85],
-        [0x92, 0x55, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x15, 0x56, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x01],
-    ],
-    [
-        [0x56, 0xaa, 0xa9, 0x55],
-        [0x99, 0xaa, 0xa1, 0x55],
-        [0xaa, 0x65, 0x95, 0x45],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x54, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa0, 0x55, 0x55, 0x01],
-    ],
-    [
-        [0x6a, 0xaa, 0xaa, 0x55],
-        [0x95, 0xaa, 0x15, 0x55],
-        [0x9a, 0x65, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa0, 0x55, 0x55, 0x01],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0xa5, 0x55, 0x55],
-        [0x99, 0x65, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0x55, 0x55, 0x01],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x65, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x01],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x41, 0x01],
-    ],
-    [
-        [0xaa, 0xaa, 0x56, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x69, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x41, 0x01],
-    ],
-    [
-        [0xa9, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x68, 0x55, 0x55, 0x96],
-        [0x65, 0x55, 0x55, 0x56],
-        [0x6a, 0x65, 0x55, 0xa6],
-        [0xaa, 0x94, 0x49, 0x02],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0x2a, 0x8a, 0xaa],
-        [0x6a, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x15, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x51, 0x56],
-        [0x65, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x40, 0xaa],
-        [0x55, 0x54, 0x45, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x56, 0xa6, 0x50],
-        [0x55, 0x56, 0x95, 0x56],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x95, 0x04],
-        [0x45, 0x05, 0x55, 0xaa],
-        [0x55, 0x55, 0x55, 0xaa],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x48, 0x58, 0x50],
-        [0x55, 0x55, 0x41, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x54, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x15],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x41, 0x04, 0x44, 0x12],
-        [0x6a, 0x2a, 0x21, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x69],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x05, 0x15, 0x16],
-        [0x41, 0x41, 0x15, 0x02],
-        [0x6a, 0x00, 0xa0, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x66],
-        [0x55, 0x59, 0x59, 0xa6],
-        [0x60, 0x00, 0x00, 0x0a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x6a, 0x59, 0x51, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x50, 0x55, 0x55, 0x52],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x55, 0x55, 0x02],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa5, 0x96, 0xa6, 0x5a],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x56],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_5 = [ .phases = 46, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_5_data[0] ];
-const EPD_WP_ED047TC2_2_6_DATA: [[[u8; 4]; 16]; 43] = [
-    [
-        [0x02, 0xaa, 0x00, 0x00],
-        [0x20, 0x22, 0xa8, 0x80],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x22, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x22, 0xa8, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x02, 0x08, 0x80],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x2a, 0xaa, 0x00, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x22, 0xaa, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x02, 0x08, 0x80],
-        [0x00, 0xa0, 0x20, 0x2a],
-        [0x28, 0x22, 0xa8, 0xa8],
-        [0x28, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0xa0],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x02, 0x08, 0x80],
-        [0x00, 0xa8, 0x22, 0x2a],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x80],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0x2a, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0xa0],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa2, 0x82, 0x08, 0x80],
-        [0x08, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x42],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x88, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x20],
-        [0x20, 0x00, 0x00, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0x2a, 0xaa, 0xa8],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x82, 0x88, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0x94],
-        [0x24, 0x00, 0x28, 0x42],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x20, 0x80, 0x00, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x2a, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x00],
-        [0xaa, 0x8a, 0x8a, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x24, 0x00, 0x28, 0x42],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x22],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x22, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0xa0, 0xa0, 0xa8, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x2a, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xa6, 0xaa, 0xaa, 0x95],
-        [0x24, 0x00, 0x26, 0x41],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x00],
-        [0x20, 0xaa, 0xaa, 0x42],
-        [0x80, 0x00, 0x00, 0x22],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0x80, 0x20, 0x28],
-        [0xa2, 0xa8, 0xaa, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x01],
-        [0x20, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa6, 0xaa, 0xa6, 0x55],
-        [0x56, 0x02, 0x95, 0x55],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x04],
-        [0x20, 0xaa, 0xa5, 0x4a],
-        [0x80, 0x00, 0x00, 0x29],
-        [0xa0, 0x88, 0x00, 0x26],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0x80, 0x20, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x01],
-        [0x20, 0x80, 0x00, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x98],
-        [0x88, 0x80, 0x00, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa5, 0xa9, 0x65, 0x55],
-        [0x56, 0x0a, 0x95, 0x55],
-    ],
-    [
-        [0xaa, 0xa9, 0xa0, 0x24],
-        [0x20, 0xa9, 0x55, 0x4a],
-        [0x80, 0x00, 0x00, 0x29],
-        [0xa0, 0x88, 0x00, 0x25],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0xa0, 0x28, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0xa0, 0x00, 0x00, 0x01],
-        [0x20, 0xa2, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0x88, 0xa2, 0xa8, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa5, 0x95, 0x55, 0x55],
-        [0x56, 0x0a, 0x95, 0x55],
-    ],
-    [
-        [0xaa, 0x95, 0xa8, 0xa4],
-        [0x20, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x29],
-        [0xa2, 0x88, 0x00, 0x15],
-        [0x2a, 0x80, 0x00, 0x01],
-        [0x20, 0xa8, 0x28, 0x14],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xa0, 0x00, 0x00, 0x01],
-        [0xa2, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0x88, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x56, 0x89, 0x55, 0x55],
-    ],
-    [
-        [0xaa, 0x95, 0x98, 0x94],
-        [0xa0, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x25],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x00, 0x21],
-        [0x20, 0xaa, 0xa8, 0x94],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xa0, 0x22, 0xa0, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0xa8, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xa9, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0xa5, 0x55, 0x15],
-    ],
-    [
-        [0xa9, 0x55, 0x9a, 0x98],
-        [0xa8, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x00, 0x21],
-        [0xa2, 0xaa, 0xaa, 0x94],
-        [0xaa, 0xaa, 0xaa, 0x89],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xa2, 0x2a, 0xa0, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x56],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0xa6, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0xa5, 0x55, 0x15],
-    ],
-    [
-        [0xa5, 0x55, 0x5a, 0xa8],
-        [0xa8, 0x95, 0x55, 0x45],
-        [0xa0, 0x82, 0x20, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x20, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x85, 0x69],
-        [0xaa, 0x2a, 0xa8, 0x29],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa9, 0xa9, 0xa5, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0xa5, 0x41, 0x15],
-    ],
-    [
-        [0x95, 0x55, 0x6a, 0x68],
-        [0xa8, 0x55, 0x55, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x20, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xa9, 0x55, 0x69],
-        [0xaa, 0x2a, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x99, 0xa9, 0x65, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x65, 0x40, 0x15],
-    ],
-    [
-        [0x95, 0x55, 0x66, 0x58],
-        [0xa8, 0x55, 0x5a, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x15],
-        [0xaa, 0xa8, 0xa0, 0x15],
-        [0xaa, 0x62, 0xa8, 0x19],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x95],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0x2a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa9, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x65, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x91, 0x55, 0x40, 0x15],
-    ],
-    [
-        [0x95, 0x56, 0xa5, 0x94],
-        [0xa8, 0x56, 0xaa, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x95],
-        [0xaa, 0xa8, 0xa0, 0x15],
-        [0xaa, 0x6a, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x89, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x99, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x50, 0x00, 0x15],
-    ],
-    [
-        [0x55, 0x6a, 0x89, 0x94],
-        [0x98, 0x6a, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa0, 0x95],
-        [0xaa, 0xa6, 0xa0, 0x15],
-        [0x8a, 0x6a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x96, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xa6, 0xaa, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x89, 0x50, 0x00, 0x15],
-    ],
-    [
-        [0x56, 0xaa, 0x9a, 0x14],
-        [0x98, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x66, 0xa2, 0x15],
-        [0x99, 0x6a, 0xaa, 0x95],
-        [0xaa, 0x6a, 0x95, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xa6, 0xa9, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0xa9, 0x10, 0x00, 0x05],
-    ],
-    [
-        [0x5a, 0xaa, 0x12, 0x54],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x66, 0xaa, 0x15],
-        [0x95, 0x6a, 0xaa, 0x95],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0xa9, 0x10, 0x00, 0x05],
-    ],
-    [
-        [0x6a, 0xaa, 0x55, 0x54],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x9a, 0x66, 0xaa, 0x15],
-        [0x95, 0x6a, 0xaa, 0x95],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x8a, 0x55, 0x55, 0x55],
-        [0xaa, 0x00, 0x00, 0x01],
-    ],
-    [
-        [0x6a, 0xaa, 0x55, 0x55],
-        [0x9a, 0x96, 0xaa, 0x41],
-        [0x6a, 0xaa, 0xaa, 0x55],
-        [0x99, 0x66, 0xaa, 0x95],
-        [0x95, 0x6a, 0x95, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x59, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0x99, 0x65],
-        [0xaa, 0x00, 0x00, 0x01],
-    ],
-    [
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x96, 0x56, 0xa5, 0x51],
-        [0x6a, 0x69, 0x8a, 0x55],
-        [0x95, 0x66, 0xaa, 0x95],
-        [0x95, 0x69, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x01],
-    ],
-    [
-        [0xaa, 0xa8, 0x55, 0x55],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x6a, 0x65, 0x58, 0x55],
-        [0x95, 0x66, 0x59, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x01],
-    ],
-    [
-        [0xa9, 0x45, 0x55, 0x45],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa5, 0x55, 0x55, 0x41],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x95, 0x55, 0x55, 0x41],
-        [0x56, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x56, 0x59, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x2a],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x59, 0x55, 0x55, 0x2a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x15, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x15],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x40],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x6a, 0x95, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x59],
-        [0x15, 0x55, 0x55, 0x82],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x55],
-        [0x99, 0x50, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x6a],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x99, 0x55, 0x6a],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_6 = [ .phases = 43, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_6_data[0] ];
-const EPD_WP_ED047TC2_2_7_DATA: [[[u8; 4]; 16]; 40] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x0a, 0x08, 0x80],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x80, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa0, 0x80, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x00, 0x12, 0x22, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x1a, 0x20, 0x00, 0x00],
-        [0x01, 0x80, 0x00, 0x00],
-        [0x88, 0x00, 0x00, 0x00],
-        [0x00, 0x10, 0x80, 0x00],
-        [0x20, 0x0a, 0x88, 0x82],
-        [0x80, 0x00, 0x05, 0x08],
-        [0x0a, 0xa0, 0x00, 0x20],
-        [0x20, 0x00, 0x00, 0x20],
-        [0x00, 0x80, 0x00, 0x08],
-        [0x80, 0x28, 0x08, 0x80],
-        [0x2a, 0x80, 0xa0, 0x28],
-        [0x20, 0x00, 0x80, 0x04],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x11, 0x22, 0xa2, 0x00],
-        [0x04, 0x04, 0x00, 0x00],
-        [0x2a, 0xa0, 0x20, 0x00],
-        [0x22, 0xa0, 0x00, 0x00],
-        [0x98, 0x12, 0x00, 0x0a],
-        [0x18, 0xa0, 0x80, 0x00],
-        [0x28, 0x0a, 0x8a, 0x82],
-        [0x88, 0x88, 0x09, 0x28],
-        [0x0a, 0xa2, 0x20, 0x20],
-        [0x20, 0x00, 0x02, 0xa0],
-        [0x00, 0xa4, 0x00, 0x08],
-        [0xa8, 0xa8, 0xa8, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x20, 0x02, 0x88, 0x84],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x22, 0x22, 0xa2, 0x28],
-        [0x04, 0x08, 0x00, 0x00],
-        [0x2a, 0xa0, 0x28, 0x00],
-        [0x2a, 0xa0, 0x20, 0x0a],
-        [0x98, 0x56, 0x02, 0x8a],
-        [0xa8, 0xa4, 0x80, 0x00],
-        [0x28, 0x0a, 0xaa, 0x82],
-        [0x88, 0xaa, 0x0a, 0x28],
-        [0x2a, 0xaa, 0xa8, 0x22],
-        [0x20, 0x40, 0xa2, 0xa2],
-        [0x12, 0xa8, 0xa0, 0x28],
-        [0xa8, 0xaa, 0xaa, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x82, 0xa8, 0x85],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0xa0, 0x20, 0x00],
-        [0x26, 0x22, 0xa2, 0x28],
-        [0x05, 0x08, 0x00, 0x00],
-        [0x2a, 0xa0, 0x28, 0x00],
-        [0x2a, 0xa0, 0x28, 0x0a],
-        [0x99, 0xaa, 0x82, 0x8a],
-        [0xaa, 0xa8, 0xa2, 0x00],
-        [0x28, 0x8a, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x28],
-        [0x2a, 0xaa, 0xa8, 0xa2],
-        [0x20, 0xa6, 0xa2, 0xa2],
-        [0x92, 0xaa, 0xa8, 0x28],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x98],
-        [0x58, 0x8a, 0xa8, 0x95],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xa0, 0x20, 0x00],
-        [0x2a, 0x66, 0xa2, 0x28],
-        [0x09, 0x0a, 0x00, 0x00],
-        [0x2a, 0xa2, 0x28, 0x00],
-        [0x2a, 0xa0, 0xa8, 0x0a],
-        [0xa9, 0xaa, 0x82, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x00],
-        [0x28, 0x8a, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0x25, 0xa6, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xa8, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0x8a, 0xaa, 0x95],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0x20, 0x80],
-        [0x2a, 0xa6, 0xa2, 0x28],
-        [0x19, 0x0a, 0x00, 0x00],
-        [0x2a, 0xa2, 0xa8, 0x00],
-        [0x2a, 0xaa, 0xa8, 0x89],
-        [0xaa, 0xaa, 0x8a, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x02],
-        [0x2a, 0x8a, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0xaa, 0xa9, 0x95],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0xa0, 0x82],
-        [0xaa, 0xaa, 0xa2, 0x28],
-        [0x9a, 0x2a, 0x00, 0x00],
-        [0x2a, 0xaa, 0xa8, 0x04],
-        [0x2a, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0x8a, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0xaa, 0xa9, 0x95],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0xa8, 0x82],
-        [0xaa, 0xaa, 0xa2, 0x28],
-        [0x9a, 0x2a, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x88],
-        [0x2a, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x8a, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x96],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0x5a, 0xaa, 0xa9, 0x55],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x14],
-        [0x0a, 0xaa, 0xa8, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x28],
-        [0xaa, 0x2a, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0x2a, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x5a, 0xaa, 0x69, 0x55],
-    ],
-    [
-        [0xa8, 0x50, 0x00, 0x14],
-        [0x8a, 0xaa, 0x9a, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x16],
-        [0xaa, 0x2a, 0x08, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0xa9, 0xa5, 0x55, 0x55],
-        [0x9a, 0xaa, 0x65, 0x51],
-    ],
-    [
-        [0x98, 0x50, 0x00, 0x14],
-        [0x8a, 0xaa, 0x9a, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0x2a, 0x08, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa6, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0xa9, 0x64, 0x51],
-    ],
-    [
-        [0x98, 0x50, 0x00, 0x28],
-        [0x8a, 0x6a, 0x9a, 0x41],
-        [0xaa, 0xaa, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x18, 0x80],
-        [0xaa, 0xaa, 0x96, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa9, 0x65],
-        [0xaa, 0xaa, 0x69, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x59],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x80, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0xa9, 0x54, 0x51],
-    ],
-    [
-        [0x94, 0x50, 0x00, 0x28],
-        [0xa6, 0x55, 0x55, 0x61],
-        [0xaa, 0xaa, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x28, 0x80],
-        [0xa9, 0x9a, 0x96, 0x95],
-        [0xaa, 0xaa, 0x96, 0x55],
-        [0xaa, 0xa9, 0x69, 0x65],
-        [0xaa, 0xaa, 0x69, 0x95],
-        [0xaa, 0xa5, 0x65, 0x55],
-        [0xaa, 0xa6, 0x25, 0x55],
-        [0xaa, 0x01, 0x55, 0x55],
-        [0xaa, 0xaa, 0x59, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0xa5, 0x54, 0x41],
-    ],
-    [
-        [0x94, 0xa0, 0x00, 0x28],
-        [0xa5, 0x55, 0x55, 0x51],
-        [0xaa, 0xa9, 0x59, 0x15],
-        [0xaa, 0xaa, 0x68, 0xa1],
-        [0xa5, 0x59, 0x55, 0x55],
-        [0xaa, 0x5a, 0x55, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0xaa, 0xa5, 0x15, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x65, 0x54, 0x41],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x55, 0x59],
-        [0xaa, 0xa9, 0x59, 0x15],
-        [0xaa, 0xaa, 0x68, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x65, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa8, 0x65, 0x54, 0x41],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x65, 0x55],
-        [0x8a, 0x99, 0x59, 0x15],
-        [0xaa, 0xa5, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0xa5, 0x65, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x92, 0x25, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x65, 0x95],
-        [0x89, 0x99, 0x55, 0x15],
-        [0xaa, 0xa5, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x26, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x96, 0x25, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0x50, 0x80, 0x15],
-        [0xa5, 0x95, 0x65, 0x95],
-        [0x99, 0x95, 0x55, 0x15],
-        [0xaa, 0x95, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x26, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x14, 0x00],
-    ],
-    [
-        [0x58, 0x5a, 0x80, 0x15],
-        [0xa9, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0xaa, 0x95, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x14, 0x00],
-    ],
-    [
-        [0x98, 0x5a, 0x80, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x2a, 0x95, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa6, 0x54, 0x14, 0x00],
-    ],
-    [
-        [0x98, 0x5a, 0x48, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x25, 0x95, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x2a, 0x55, 0x55, 0x65],
-        [0xa6, 0x54, 0x10, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x68, 0x55],
-        [0x6a, 0xaa, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x25, 0x55, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x66],
-        [0x2a, 0xaa, 0xaa, 0x6a],
-        [0xa6, 0x10, 0x00, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x64, 0x41],
-        [0x5a, 0xaa, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xa6, 0x10, 0x08, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x94, 0x81],
-        [0x5a, 0x6a, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x46, 0x0a, 0x9a, 0x81],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x45, 0x0a, 0xa9, 0x41],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x15, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x44, 0x85, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x45, 0x0a, 0x65, 0x41],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0x55, 0x55, 0x69],
-        [0x55, 0x55, 0x55, 0x69],
-        [0x55, 0x56, 0x11, 0x59],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x66],
-        [0x65, 0x44, 0x42, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x46, 0x05, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x51],
-        [0x55, 0xaa, 0xaa, 0x54],
-        [0x45, 0x55, 0x55, 0x56],
-        [0x59, 0x59, 0x11, 0x56],
-        [0x55, 0x55, 0x14, 0x52],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x51, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x46, 0x05, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x40, 0x55, 0x55, 0x80],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x04, 0x02, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x59, 0x55, 0x65, 0x46],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x45, 0x05, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x40, 0x00, 0x00, 0x42],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x64, 0x0a, 0x9a, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x45, 0x05, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x65, 0x55, 0x55, 0x55],
-        [0x55, 0x64, 0x55, 0x41],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x6a, 0x2a, 0x82, 0x2a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x44],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x40],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x95, 0x55, 0x51, 0x42],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x50, 0x50, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_7 = [ .phases = 40, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_7_data[0] ];
-const EPD_WP_ED047TC2_2_8_DATA: [[[u8; 4]; 16]; 38] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x08, 0x00, 0x8a, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x80, 0x08, 0x82],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x02, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0x00, 0x8a, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x82],
-        [0x20, 0x00, 0x00, 0x08],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x02, 0x82, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0x08, 0xaa, 0x08],
-        [0x00, 0x00, 0x08, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0x20, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x82],
-        [0xa0, 0x00, 0x00, 0x0a],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x02, 0x82, 0x00, 0x2a],
-        [0x80, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x00, 0x20, 0x08, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0xa0, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x81],
-        [0xa0, 0x00, 0x00, 0x05],
-        [0x20, 0x00, 0x00, 0x01],
-        [0x02, 0x82, 0x00, 0xa9],
-        [0x80, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x12, 0x28, 0xaa, 0x01],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x88, 0xa0, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x82, 0xa8, 0x08, 0x81],
-        [0x08, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x02, 0x0a, 0x80],
-        [0x88, 0x00, 0x02, 0x81],
-        [0xa0, 0x00, 0x00, 0x25],
-        [0x28, 0x00, 0x00, 0x21],
-        [0x22, 0x82, 0x00, 0xa5],
-        [0x80, 0x00, 0x00, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x19, 0x2a, 0xaa, 0x81],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x8a, 0xa8, 0x8a, 0x0a],
-        [0x00, 0x20, 0x00, 0x28],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x0a],
-        [0x82, 0xaa, 0x08, 0x81],
-        [0x08, 0x20, 0x02, 0x02],
-        [0x08, 0x00, 0x00, 0x08],
-        [0x28, 0x02, 0x0a, 0x80],
-        [0x88, 0x08, 0x0a, 0x81],
-        [0xa0, 0x00, 0x08, 0x25],
-        [0x2a, 0x00, 0x0a, 0x21],
-        [0x22, 0xa2, 0x00, 0x95],
-        [0x80, 0x02, 0x00, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x19, 0xaa, 0xaa, 0x81],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x00],
-        [0x8a, 0xaa, 0x8a, 0x0a],
-        [0x02, 0x20, 0x00, 0x28],
-        [0x88, 0x00, 0x00, 0x02],
-        [0x20, 0x00, 0x00, 0x09],
-        [0xa2, 0xaa, 0xa8, 0x85],
-        [0x8a, 0x28, 0x0a, 0x09],
-        [0x88, 0x00, 0x00, 0x08],
-        [0x28, 0x02, 0x8a, 0x80],
-        [0x88, 0x0a, 0x0a, 0x81],
-        [0xa8, 0x02, 0x08, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0x2a, 0xaa, 0x2a, 0x95],
-        [0xa0, 0x02, 0x82, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x19, 0xaa, 0xaa, 0x81],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x00],
-        [0x8a, 0xaa, 0xaa, 0x09],
-        [0x02, 0x20, 0x08, 0x04],
-        [0x88, 0x00, 0x00, 0x21],
-        [0x28, 0x00, 0x00, 0x09],
-        [0xaa, 0xaa, 0xa8, 0x85],
-        [0x8a, 0xaa, 0x0a, 0x09],
-        [0x88, 0x08, 0x00, 0x04],
-        [0xaa, 0x82, 0x8a, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xa8, 0x22, 0xa8, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0x2a, 0x95],
-        [0xa0, 0x22, 0x82, 0x91],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x6a, 0xaa, 0x85],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x10],
-        [0x8a, 0xaa, 0xaa, 0x09],
-        [0x22, 0x20, 0x28, 0x14],
-        [0x88, 0x00, 0x00, 0x25],
-        [0x28, 0x00, 0x00, 0x05],
-        [0xaa, 0xaa, 0xa8, 0x45],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x88, 0x0a, 0x00, 0x85],
-        [0xaa, 0xa2, 0x8a, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0x2a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa2, 0x2a, 0x8a, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x6a, 0xa6, 0x85],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x90],
-        [0x8a, 0xaa, 0x65, 0x09],
-        [0xaa, 0x22, 0xaa, 0x15],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x00, 0x00, 0x25],
-        [0xaa, 0xaa, 0xa8, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xa8, 0x2a, 0x80, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa2, 0xaa, 0x8a, 0x95],
-        [0xa9, 0xaa, 0x66, 0x55],
-        [0x15, 0x6a, 0x56, 0x45],
-    ],
-    [
-        [0x94, 0x00, 0x05, 0x90],
-        [0x8a, 0xaa, 0x65, 0x29],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x08, 0x00, 0x25],
-        [0xaa, 0xaa, 0xa6, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0x2a, 0x8a, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x45],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x29, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa9, 0xa9, 0x55, 0x55],
-        [0x25, 0x6a, 0x55, 0x55],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0x64],
-        [0x8a, 0xaa, 0x65, 0x19],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x0a, 0x0a, 0x15],
-        [0xaa, 0x6a, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x8a, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa9, 0x69, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x99, 0xa5, 0x55, 0x55],
-        [0x21, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0x64],
-        [0xaa, 0x9a, 0x65, 0x19],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x8a, 0x00, 0x00, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xaa, 0x5a, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa8, 0x55],
-        [0x9a, 0xaa, 0xa6, 0x55],
-        [0x9a, 0xaa, 0xa5, 0x55],
-        [0xa9, 0x69, 0xaa, 0x55],
-        [0xaa, 0xa9, 0x6a, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x22, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0xa5],
-        [0xaa, 0x5a, 0x65, 0x15],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0xaa, 0xa0, 0x08, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xaa, 0x55, 0x56, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x9a, 0xa9, 0x56, 0x55],
-        [0x9a, 0xa1, 0x55, 0x55],
-        [0xa9, 0x69, 0x95, 0x55],
-        [0xaa, 0xa9, 0x69, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x22, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x68, 0x00, 0x0a, 0x95],
-        [0xa6, 0x55, 0x55, 0x15],
-        [0xaa, 0x12, 0x8a, 0x15],
-        [0xaa, 0xa0, 0x28, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xa9, 0x55, 0x56, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa9, 0xa5, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0x91, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x9a, 0xa9, 0x65, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa2, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x68, 0x00, 0x0a, 0x55],
-        [0xa6, 0x55, 0x9a, 0x15],
-        [0xaa, 0x12, 0x06, 0x55],
-        [0xaa, 0xaa, 0x28, 0x15],
-        [0xa8, 0x2a, 0xaa, 0x15],
-        [0xa9, 0x55, 0x56, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x99, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa2, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x68, 0x00, 0x05, 0x55],
-        [0xa5, 0x55, 0x9a, 0x25],
-        [0xa9, 0x91, 0x55, 0x55],
-        [0xaa, 0xaa, 0x28, 0x15],
-        [0xa8, 0x2a, 0xaa, 0x95],
-        [0x59, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x6a, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xa4, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x68, 0x00, 0x05, 0x55],
-        [0xa5, 0x65, 0x9a, 0x25],
-        [0x99, 0x91, 0x55, 0x55],
-        [0xaa, 0xaa, 0xa8, 0x95],
-        [0x9a, 0x25, 0x65, 0x95],
-        [0x51, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xa6, 0x85, 0x65, 0x55],
-        [0x9a, 0x29, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x15, 0x55, 0x55],
-    ],
-    [
-        [0x54, 0x00, 0x05, 0x55],
-        [0xa9, 0xa5, 0x9a, 0x25],
-        [0x99, 0x91, 0x55, 0x55],
-        [0xa6, 0xaa, 0xaa, 0x95],
-        [0x96, 0xa5, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x85, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x96, 0x55, 0x55, 0x55],
-        [0xaa, 0x95, 0x11, 0x56],
-    ],
-    [
-        [0x94, 0x00, 0x05, 0x55],
-        [0x69, 0xaa, 0xaa, 0x25],
-        [0x95, 0x99, 0x55, 0x55],
-        [0xa6, 0xaa, 0x96, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x56],
-        [0xa6, 0x55, 0x55, 0x59],
-        [0xaa, 0x95, 0x21, 0x56],
-    ],
-    [
-        [0x94, 0x00, 0x85, 0x51],
-        [0x6a, 0xaa, 0xaa, 0xa5],
-        [0x95, 0x99, 0x55, 0x55],
-        [0x65, 0x59, 0x96, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x66],
-        [0xaa, 0x56, 0x99, 0x6a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x94, 0x22, 0x85, 0x51],
-        [0x6a, 0xaa, 0x20, 0x95],
-        [0x55, 0x59, 0x55, 0x55],
-        [0x55, 0x55, 0x56, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x66, 0x56, 0x95, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x96, 0x2a, 0x65, 0x01],
-        [0xa2, 0x8a, 0x65, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x15, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x96, 0xa9, 0x50, 0x01],
-        [0xa6, 0x0a, 0x65, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0x4a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x66, 0x96, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x42, 0x95, 0x90, 0x01],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x52],
-        [0x65, 0x54, 0x01, 0x2a],
-        [0x65, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x41, 0x56, 0xa0, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x64],
-        [0x66, 0x6a, 0xa9, 0x50],
-        [0x55, 0x55, 0x55, 0x64],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x45, 0x50, 0x00, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x41, 0x6a, 0x60, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x5a, 0x95, 0x5a],
-        [0x55, 0x15, 0x55, 0x20],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x42, 0xa9, 0x50, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x65, 0x6a, 0xa6],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x41, 0x50, 0x15, 0x6a],
-        [0x45, 0x54, 0x55, 0x91],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x42, 0x95, 0x50, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x65, 0x55, 0x55, 0x54],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x45, 0x54, 0x10, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x41, 0x55, 0x50, 0x15],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xa2, 0xaa, 0xa2],
-        [0x52, 0xa6, 0x65, 0x92],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x41, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xa2],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x84, 0x50, 0x05, 0x0a],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x50, 0x15, 0x48],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x81, 0x51, 0x11, 0x68],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x55, 0x55, 0x44],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_8 = [ .phases = 38, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_8_data[0] ];
-const EPD_WP_ED047TC2_2_9_DATA: [[[u8; 4]; 16]; 38] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x0a, 0x82, 0x02, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x80, 0x02, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x0a, 0x8a, 0x82, 0x00],
-        [0x40, 0x08, 0x00, 0x40],
-        [0x00, 0x80, 0x22, 0x08],
-        [0x00, 0x08, 0x08, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x10, 0x04, 0x00, 0x00],
-        [0x11, 0x40, 0x20, 0x00],
-        [0x81, 0x00, 0x00, 0x20],
-        [0x28, 0x08, 0x00, 0x82],
-        [0x00, 0x20, 0x80, 0x80],
-        [0x00, 0x00, 0x02, 0x80],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0x6a, 0x8a, 0x8a, 0x00],
-        [0x90, 0x08, 0x01, 0x40],
-        [0x00, 0x80, 0x22, 0x08],
-        [0x00, 0x28, 0x88, 0x0a],
-        [0x80, 0x08, 0x00, 0x00],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x80, 0x00],
-        [0x14, 0x14, 0x00, 0x00],
-        [0x21, 0x50, 0x20, 0x80],
-        [0x81, 0x18, 0x00, 0xa0],
-        [0x29, 0x0a, 0x02, 0x81],
-        [0x2a, 0xa2, 0xaa, 0xa0],
-        [0x00, 0x2a, 0x02, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xa0, 0x08, 0x02, 0x80],
-        [0x80, 0x88, 0x22, 0x08],
-        [0x20, 0xa8, 0x89, 0x0a],
-        [0x80, 0xa8, 0x00, 0x02],
-        [0xaa, 0x8a, 0x0a, 0x08],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x08, 0x80, 0x00],
-        [0x24, 0x54, 0x22, 0x80],
-        [0x25, 0x52, 0x2a, 0xa0],
-        [0x86, 0xa8, 0x20, 0xa8],
-        [0xaa, 0x2a, 0x82, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x00, 0xaa, 0x22, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0x8a, 0x2a, 0x80],
-        [0x80, 0x88, 0x22, 0x08],
-        [0x22, 0xaa, 0x8a, 0x0a],
-        [0x82, 0xa8, 0x00, 0x02],
-        [0xaa, 0xaa, 0x0a, 0x0a],
-        [0x00, 0x08, 0x02, 0x28],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x2a, 0x8a, 0x80, 0x00],
-        [0xa5, 0xaa, 0xa2, 0xa0],
-        [0x26, 0x96, 0xaa, 0xa0],
-        [0x8a, 0xa8, 0xa0, 0xa8],
-        [0xaa, 0x2a, 0x8a, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x00, 0xaa, 0x22, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0x8a, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x0a],
-        [0xa2, 0xa8, 0x02, 0x02],
-        [0xaa, 0xaa, 0x2a, 0x0a],
-        [0x00, 0x08, 0x02, 0x2a],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x2a, 0xaa, 0x80, 0x00],
-        [0xa9, 0xaa, 0xa2, 0xa0],
-        [0x26, 0xa6, 0xaa, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x2a, 0x8a, 0x41],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x08, 0xaa, 0x12, 0x41],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0x8a, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x29],
-        [0xa2, 0xa8, 0x02, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x00, 0x08, 0x02, 0x2a],
-        [0x00, 0x00, 0x08, 0x80],
-        [0x2a, 0xaa, 0x8a, 0x22],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0xaa, 0x12, 0x41],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x20, 0x08, 0x02, 0x28],
-        [0x00, 0x02, 0x0a, 0xa0],
-        [0x2a, 0xaa, 0x8a, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0xaa, 0x11, 0x45],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x81, 0x88, 0xa2, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0x8a, 0x02, 0x28],
-        [0x00, 0x82, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa6, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0x91, 0x45],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0x89, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x82, 0x88, 0xaa, 0x24],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0xaa, 0x82, 0x04],
-        [0x20, 0xa2, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x29],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x96, 0xa5, 0x65, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0x91, 0x45],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x10],
-        [0xaa, 0xaa, 0x89, 0x80],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0xa2, 0xa8, 0x9a, 0x14],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x8a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0x2a, 0xaa, 0x8a, 0x15],
-        [0x20, 0xaa, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x5a],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x56],
-        [0x2a, 0x99, 0x91, 0x45],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x94],
-        [0xaa, 0xa9, 0x45, 0x44],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0xa2, 0xa8, 0x99, 0x16],
-        [0xaa, 0xa6, 0xa6, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xa9, 0xa5],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0x2a, 0xaa, 0xaa, 0x69],
-        [0x2a, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x5a],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0x55, 0x56],
-        [0x1a, 0x95, 0x99, 0x05],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x64],
-        [0xa9, 0x65, 0x45, 0x48],
-        [0xaa, 0xaa, 0xa9, 0xa2],
-        [0xa2, 0x6a, 0x99, 0x15],
-        [0xaa, 0xa5, 0x65, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xa5, 0xa5],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0x2a, 0xaa, 0x6a, 0x95],
-        [0xaa, 0xaa, 0x99, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0x99, 0x55, 0x55],
-        [0x19, 0x55, 0x89, 0x05],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0xa4],
-        [0xa5, 0x65, 0x45, 0x4a],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xa2, 0x6a, 0x99, 0x15],
-        [0xa9, 0x95, 0x65, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa5, 0x95, 0xa5],
-        [0xaa, 0xa6, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0x2a, 0xaa, 0x6a, 0x95],
-        [0xaa, 0xaa, 0x59, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa6, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x99, 0x55, 0x55],
-        [0x15, 0x55, 0x49, 0x15],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0x54],
-        [0xa5, 0x65, 0x45, 0x88],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xaa, 0x66, 0x11, 0x15],
-        [0xa9, 0x55, 0x65, 0x55],
-        [0xaa, 0x96, 0xa9, 0x95],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa6, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0x26, 0xa5, 0x65, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x15],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0x55],
-        [0xa5, 0x55, 0x46, 0x89],
-        [0xaa, 0xa6, 0xa5, 0x61],
-        [0xaa, 0x66, 0x51, 0x15],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x28, 0x56, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xa6, 0xa9, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x15],
-    ],
-    [
-        [0xa0, 0x00, 0x00, 0x55],
-        [0x95, 0x56, 0x8a, 0x99],
-        [0xaa, 0xaa, 0x9a, 0x51],
-        [0xaa, 0x66, 0x55, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x55, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x45, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x16],
-    ],
-    [
-        [0xa0, 0x00, 0x00, 0x55],
-        [0x9a, 0x9a, 0x8a, 0x65],
-        [0xaa, 0xa5, 0x56, 0x51],
-        [0x6a, 0x56, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x96],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x05, 0x55, 0x45, 0x16],
-    ],
-    [
-        [0xa0, 0x00, 0x20, 0x51],
-        [0x5a, 0xaa, 0x8a, 0x65],
-        [0xa5, 0x65, 0x56, 0x59],
-        [0x69, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x45, 0x12],
-    ],
-    [
-        [0x60, 0x28, 0xa0, 0x51],
-        [0x6a, 0xaa, 0x8a, 0x65],
-        [0x95, 0x55, 0x56, 0x55],
-        [0x59, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x84, 0x55, 0x44, 0x12],
-    ],
-    [
-        [0x6a, 0xaa, 0xa8, 0x11],
-        [0x6a, 0xaa, 0x8a, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x45, 0x44, 0x12],
-    ],
-    [
-        [0x5a, 0xaa, 0x64, 0x01],
-        [0xa6, 0x99, 0x45, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x44, 0x46, 0x12],
-    ],
-    [
-        [0x5a, 0x95, 0x56, 0x01],
-        [0x95, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x99, 0x01],
-        [0x95, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x56, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x95],
-        [0x95, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x25, 0x6a, 0x99, 0x01],
-        [0x15, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x65],
-        [0x56, 0x5a, 0x56, 0x56],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x95, 0x55, 0x41, 0x56],
-        [0x95, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x5a, 0x66, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x2a, 0xaa, 0x56, 0x05],
-        [0x55, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x95, 0x61],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x54, 0x55],
-        [0x55, 0x54, 0x00, 0x15],
-        [0x91, 0x55, 0x15, 0x42],
-        [0x55, 0x55, 0x24, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x2a, 0x95, 0x56, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x65, 0x49, 0x91],
-        [0x55, 0x55, 0x54, 0x44],
-        [0x55, 0x55, 0x55, 0x49],
-        [0x15, 0x55, 0x55, 0x54],
-        [0x55, 0x51, 0x54, 0x0a],
-        [0x55, 0x04, 0x00, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x54, 0x10, 0x55],
-        [0x65, 0x56, 0x9a, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0xa2, 0x1a, 0x06, 0x50],
-        [0x68, 0xa0, 0xa8, 0xaa],
-        [0x55, 0x45, 0x55, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x45, 0x04, 0x00, 0xaa],
-        [0x55, 0x55, 0x65, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x51, 0x05, 0x01, 0x02],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x58],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x58],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x6a, 0xaa, 0xa9, 0xa4],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_9 = [ .phases = 38, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_9_data[0] ];
-const EPD_WP_ED047TC2_2_10_DATA: [[[u8; 4]; 16]; 44] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x80, 0x00, 0x08, 0x02],
-        [0x00, 0x80, 0x00, 0x08],
-        [0x10, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x02, 0x00, 0x00, 0x00],
-        [0xa2, 0x88, 0x20, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x80, 0x20, 0x00],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x82, 0x00, 0x00],
-        [0xa8, 0x00, 0x08, 0x82],
-        [0x00, 0x80, 0x20, 0x0a],
-        [0x92, 0x00, 0x00, 0x8a],
-        [0x2a, 0x20, 0xa2, 0xa8],
-        [0x00, 0x00, 0x00, 0x08],
-    ],
-    [
-        [0x02, 0x00, 0x00, 0x00],
-        [0xa2, 0x8a, 0x20, 0x05],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x05, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x00, 0x80, 0x20, 0x00],
-        [0x02, 0xa0, 0xa0, 0x00],
-        [0x00, 0x82, 0x00, 0x02],
-        [0xaa, 0x20, 0x08, 0x82],
-        [0x84, 0x80, 0xa0, 0x0a],
-        [0xa2, 0x84, 0x00, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x08],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xa2, 0xaa, 0xa0, 0x05],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x29, 0x15, 0x42, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x02, 0xa0, 0x20, 0x80],
-        [0x02, 0xaa, 0xa8, 0x00],
-        [0x00, 0x82, 0x00, 0x02],
-        [0xaa, 0xa8, 0x2a, 0x82],
-        [0x9a, 0x90, 0xa8, 0x0a],
-        [0xa6, 0xa8, 0x00, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x22, 0x00, 0x80, 0x2a],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xa2, 0xaa, 0xa8, 0x09],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x29, 0x1a, 0x46, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x02, 0xaa, 0x28, 0x80],
-        [0x2a, 0xaa, 0xa8, 0x00],
-        [0x02, 0x82, 0x00, 0x02],
-        [0xaa, 0xa8, 0x2a, 0x8a],
-        [0xaa, 0xa8, 0xaa, 0x0a],
-        [0xaa, 0xaa, 0x08, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x22, 0x8a, 0x88, 0x26],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x09],
-        [0x00, 0x00, 0x00, 0x90],
-        [0x02, 0xa0, 0x08, 0x02],
-        [0x2a, 0x2a, 0x86, 0x20],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x80],
-        [0x2a, 0xaa, 0xa8, 0x20],
-        [0x02, 0x82, 0x00, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xa8, 0xaa, 0x0a],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x1a, 0x8a, 0x8a, 0xa6],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x0a],
-        [0x80, 0x00, 0x00, 0x90],
-        [0x02, 0xaa, 0x2a, 0x02],
-        [0x2a, 0xaa, 0x8a, 0x20],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xa2, 0xa2, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x1a, 0xaa, 0x4a, 0x55],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x08],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x02, 0x00, 0x90],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x16, 0xaa, 0x46, 0x55],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x08],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x02, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x82, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x15, 0x9a, 0x65, 0x55],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x22, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x82, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x15, 0x95, 0x65, 0x55],
-    ],
-    [
-        [0x81, 0x00, 0x00, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0xa2, 0x22, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x0a, 0x02, 0x82, 0x20],
-        [0x00, 0x0a, 0x00, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa2, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0xa8],
-        [0xaa, 0xaa, 0x9a, 0x2a],
-        [0xa2, 0xa2, 0x02, 0xaa],
-        [0x02, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x0a, 0x82, 0x82, 0xa0],
-        [0x00, 0x8a, 0x80, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x22],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa2, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0xa8],
-        [0xaa, 0x66, 0x9a, 0x2a],
-        [0xa2, 0xa2, 0x22, 0xaa],
-        [0x02, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xa9, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0x2a, 0xa2, 0xa0, 0x80],
-        [0x02, 0x8a, 0x80, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x20],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xa0, 0x61],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0x94],
-        [0xaa, 0x65, 0x56, 0x26],
-        [0xa2, 0xaa, 0x22, 0xaa],
-        [0x82, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xa9, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x82],
-        [0x02, 0x8a, 0x82, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x21],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xa8, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xa0, 0x65],
-        [0xaa, 0xaa, 0x20, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x41, 0x00, 0x40, 0x94],
-        [0x69, 0x65, 0x56, 0x26],
-        [0xa2, 0xaa, 0x22, 0xaa],
-        [0x82, 0xaa, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xa2, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x21],
-        [0x2a, 0xaa, 0xaa, 0x9a],
-        [0xaa, 0x28, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0x80, 0x65],
-        [0xaa, 0x2a, 0x00, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x42, 0x00, 0x48, 0xa4],
-        [0x59, 0x55, 0x55, 0xa9],
-        [0xa6, 0xaa, 0x22, 0x69],
-        [0x82, 0xaa, 0xaa, 0x25],
-        [0x2a, 0xaa, 0xaa, 0x9a],
-        [0xa8, 0x80, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x29],
-        [0x2a, 0xaa, 0x56, 0x99],
-        [0xaa, 0x28, 0xa8, 0xa9],
-        [0xaa, 0x82, 0x80, 0x65],
-        [0xaa, 0x2a, 0x00, 0x85],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x42, 0x00, 0x48, 0x64],
-        [0x59, 0x59, 0x65, 0xa9],
-        [0xa6, 0xaa, 0x22, 0x69],
-        [0x82, 0xaa, 0xa0, 0x65],
-        [0x2a, 0xaa, 0xa9, 0x56],
-        [0xaa, 0x80, 0x00, 0x09],
-        [0xaa, 0xa9, 0xa9, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0x2a, 0x86, 0x69],
-        [0x2a, 0x55, 0x55, 0x99],
-        [0xaa, 0x69, 0x89, 0xa9],
-        [0x96, 0x82, 0x00, 0x45],
-        [0xaa, 0x22, 0x10, 0x05],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0x62, 0x00, 0x4a, 0x54],
-        [0x59, 0x9a, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x22, 0x65],
-        [0x82, 0xaa, 0xa5, 0x45],
-        [0x26, 0xaa, 0xa9, 0x55],
-        [0xaa, 0x80, 0x00, 0x09],
-        [0xaa, 0xa9, 0x29, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0x55, 0x54, 0x69],
-        [0x21, 0x55, 0x55, 0x55],
-        [0xaa, 0x69, 0x45, 0xa9],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x22, 0x10, 0x05],
-        [0x89, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0xa2, 0x00, 0x8a, 0x54],
-        [0x66, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x22, 0x55],
-        [0x82, 0x95, 0x55, 0x55],
-        [0x16, 0xa5, 0x55, 0x55],
-        [0xa2, 0xa0, 0x20, 0x09],
-        [0xaa, 0xa9, 0x29, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xa9, 0x55, 0x55, 0x59],
-        [0x11, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa1, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0xa2, 0x00, 0x86, 0x54],
-        [0xa6, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x22, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x16, 0x5a, 0xa9, 0x55],
-        [0xa2, 0xaa, 0x28, 0x01],
-        [0xaa, 0xa9, 0x29, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x55, 0x55, 0x55],
-    ],
-    [
-        [0xa1, 0x00, 0x85, 0x54],
-        [0xaa, 0xa2, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x21, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0xa5, 0x55, 0x55],
-        [0xa2, 0xaa, 0xa8, 0x01],
-        [0xaa, 0xa9, 0x69, 0x55],
-        [0xaa, 0xa5, 0x69, 0xa5],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x45, 0x55, 0x55],
-    ],
-    [
-        [0xa1, 0x00, 0x85, 0x54],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0xaa, 0x99, 0x21, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x16, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0xa2, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa5, 0x69, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x2a, 0x65, 0x85, 0x55],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x54],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x99, 0x61, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0x65, 0x55, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x2a, 0x65, 0x89, 0x95],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x50],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0x8a, 0xa9],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x10],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x95, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-    ],
-    [
-        [0x51, 0x20, 0x41, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x99, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x16, 0x6a, 0x94, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x50, 0x2a, 0x40, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x18, 0x2a, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x28, 0xa9, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x28, 0x95, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x24, 0x95, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x99],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x99],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x9a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x14, 0x6a, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x65],
-        [0x55, 0x14, 0x54, 0x56],
-        [0x41, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x14, 0x6a, 0x10, 0x01],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x11],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x15],
-        [0x6a, 0xaa, 0xaa, 0x99],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x18, 0x96, 0x10, 0x05],
-        [0x55, 0x55, 0x55, 0x19],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x21],
-        [0xa9, 0x55, 0x56, 0xa5],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x66, 0xa9, 0x69, 0x54],
-        [0x55, 0x50, 0x14, 0x1a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x46],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x18, 0x95, 0x10, 0x45],
-        [0x55, 0x55, 0x55, 0x15],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x6a, 0x6a, 0x9a, 0x11],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x69, 0x55, 0x55, 0x59],
-        [0x51, 0x56, 0x16, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x18, 0x55, 0x10, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x65, 0x56, 0x54],
-        [0x55, 0x15, 0x45, 0x02],
-        [0x80, 0x00, 0x00, 0x0a],
-        [0x55, 0x55, 0x55, 0x16],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x15, 0x55, 0x15, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x59, 0x55, 0x55, 0x14],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x65, 0x45, 0x54, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_10 = [ .phases = 44, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_10_data[0] ];
-const EPD_WP_ED047TC2_2_11_DATA: [[[u8; 4]; 16]; 57] = [
-    [
-        [0x20, 0x8a, 0x80, 0x00],
-        [0x15, 0x55, 0x5a, 0xa4],
-        [0x44, 0x10, 0x0a, 0x41],
-        [0x40, 0x00, 0x09, 0x54],
-        [0x10, 0x50, 0x00, 0x00],
-        [0x00, 0x00, 0x04, 0x28],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x40, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x40, 0x00, 0x68],
-        [0x00, 0x00, 0x40, 0x00],
-        [0x00, 0x00, 0x0a, 0x54],
-        [0x00, 0x0a, 0x80, 0x00],
-        [0x10, 0x09, 0x60, 0x00],
-        [0x00, 0x00, 0x00, 0x50],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x0a, 0x60, 0x0a, 0xa8],
-        [0x54, 0x54, 0x8a, 0x41],
-        [0x41, 0x02, 0x09, 0x54],
-        [0x15, 0x50, 0x06, 0x24],
-        [0x00, 0x08, 0xa4, 0x28],
-        [0x44, 0x00, 0x09, 0xa8],
-        [0x05, 0x00, 0x2a, 0xa8],
-        [0x01, 0x10, 0x46, 0xa8],
-        [0x04, 0x00, 0x00, 0xa8],
-        [0x00, 0x40, 0x00, 0x54],
-        [0x04, 0x00, 0x44, 0xa8],
-        [0x00, 0x00, 0x8a, 0x54],
-        [0x00, 0x0a, 0x8a, 0x58],
-        [0x50, 0x19, 0x65, 0x54],
-        [0x00, 0x00, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x2a, 0x6a, 0xaa, 0xa8],
-        [0x55, 0x94, 0x89, 0x55],
-        [0x41, 0x02, 0x09, 0x54],
-        [0x15, 0x50, 0x66, 0x24],
-        [0x40, 0x04, 0xa4, 0x68],
-        [0x44, 0x0a, 0x09, 0x54],
-        [0x05, 0x08, 0x55, 0x54],
-        [0x11, 0x14, 0x56, 0x54],
-        [0x04, 0x40, 0x00, 0x58],
-        [0x01, 0x40, 0x0a, 0x54],
-        [0x04, 0x0a, 0x44, 0xa8],
-        [0x01, 0x00, 0x8a, 0x54],
-        [0x00, 0x0a, 0x6a, 0x54],
-        [0x50, 0x19, 0x65, 0x54],
-        [0x00, 0x00, 0x01, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x2a, 0x2a, 0xaa, 0xa8],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x51, 0x0a, 0x05, 0x54],
-        [0x15, 0x50, 0x66, 0xa4],
-        [0x40, 0x04, 0x54, 0x54],
-        [0x44, 0x05, 0x09, 0x54],
-        [0x05, 0x18, 0x55, 0x54],
-        [0x51, 0x14, 0x56, 0x54],
-        [0x04, 0x40, 0x02, 0x54],
-        [0x01, 0x40, 0x8a, 0x54],
-        [0x04, 0x05, 0x44, 0x58],
-        [0x01, 0x0a, 0x85, 0x54],
-        [0x01, 0x05, 0x65, 0x54],
-        [0x54, 0x15, 0x55, 0x54],
-        [0x00, 0x00, 0x01, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x96, 0xa5, 0x55, 0x55],
-        [0x51, 0x09, 0x05, 0x55],
-        [0x15, 0x50, 0x65, 0x94],
-        [0x44, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x54],
-        [0x05, 0x56, 0x55, 0x54],
-        [0x51, 0x14, 0x55, 0x54],
-        [0x14, 0x40, 0x82, 0x54],
-        [0x01, 0x40, 0x8a, 0x54],
-        [0x04, 0x05, 0x44, 0x54],
-        [0x01, 0x0a, 0x45, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x54],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x9a, 0xa6, 0x55, 0x55],
-        [0x51, 0x49, 0x25, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x14, 0x55, 0x55],
-        [0x14, 0x40, 0x8a, 0x54],
-        [0x01, 0x40, 0x85, 0x54],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x0a, 0x65, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x9a, 0xa6, 0x55, 0x55],
-        [0x51, 0x55, 0x25, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x14, 0x40, 0xa9, 0x54],
-        [0x01, 0x40, 0x45, 0x55],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x05, 0x65, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x14, 0x40, 0x69, 0x55],
-        [0x01, 0x40, 0x65, 0x55],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x52, 0x55, 0x54],
-        [0x54, 0x14, 0x56, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x40, 0x65, 0x55],
-        [0x01, 0x50, 0x65, 0x55],
-        [0x04, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x92, 0x55, 0x55],
-        [0x55, 0x14, 0x56, 0x54],
-        [0x54, 0x55, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x40, 0x55, 0x55],
-        [0x01, 0x50, 0x55, 0x55],
-        [0x05, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x91, 0x55, 0x15, 0x55],
-        [0x16, 0x92, 0x55, 0x55],
-        [0x55, 0x14, 0x56, 0x55],
-        [0x54, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x48, 0x55, 0x55],
-        [0x01, 0x50, 0x55, 0x55],
-        [0x05, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0x92, 0x55, 0x95, 0x55],
-        [0x6a, 0x91, 0x55, 0x55],
-        [0x55, 0x54, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x48, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x05, 0x15, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa5, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x6a, 0x91, 0x55, 0x55],
-        [0x95, 0x54, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x4a, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa5, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x4a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x6a, 0xa1, 0x55, 0x55],
-        [0x95, 0x54, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x46, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa9, 0x15, 0x55, 0x55],
-        [0x00, 0x10, 0x45, 0x54],
-    ],
-    [
-        [0xaa, 0xa5, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x95, 0x56, 0x55],
-        [0x6a, 0xa5, 0x55, 0x55],
-        [0x99, 0x54, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x55],
-        [0x56, 0x55, 0x55, 0x55],
-        [0x15, 0x46, 0x55, 0x55],
-        [0x01, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x56, 0x05, 0x55, 0x55],
-        [0xa9, 0x66, 0x55, 0x55],
-        [0x00, 0x10, 0x45, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xa6, 0xa5, 0x56, 0x55],
-        [0x6a, 0xa5, 0x55, 0x55],
-        [0xa9, 0x54, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x15, 0x45, 0x55, 0x55],
-        [0x11, 0x55, 0x55, 0x55],
-        [0x19, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xa9, 0x66, 0x95, 0x55],
-        [0x00, 0x10, 0x55, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xa6, 0x96],
-        [0xa6, 0xa5, 0x56, 0x55],
-        [0x6a, 0xa5, 0x99, 0x55],
-        [0xaa, 0x68, 0x59, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x5a, 0x95, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x19, 0x45, 0x55, 0x55],
-        [0x11, 0x95, 0x55, 0x55],
-        [0x19, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x66, 0x15, 0x55, 0x55],
-        [0xaa, 0x66, 0x9a, 0x55],
-        [0x00, 0x14, 0x55, 0x54],
-    ],
-    [
-        [0x9a, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x96],
-        [0xaa, 0xa6, 0x56, 0x55],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0x68, 0x59, 0x55],
-        [0xa9, 0x65, 0x55, 0x55],
-        [0x5a, 0xa5, 0x55, 0x55],
-        [0xaa, 0x65, 0x55, 0x55],
-        [0x29, 0x85, 0x55, 0x55],
-        [0x12, 0x95, 0x55, 0x55],
-        [0x59, 0x55, 0x56, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xa6, 0x15, 0x55, 0x55],
-        [0xaa, 0x6a, 0x9a, 0x55],
-        [0x00, 0x14, 0x55, 0x54],
-    ],
-    [
-        [0x95, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x56, 0x55],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0xa8, 0x59, 0x55],
-        [0xa9, 0x65, 0x55, 0x55],
-        [0x9a, 0xa5, 0x55, 0x55],
-        [0xaa, 0x69, 0x95, 0x55],
-        [0x2a, 0x85, 0x55, 0x55],
-        [0x52, 0x95, 0x55, 0x55],
-        [0x5a, 0x55, 0x95, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xaa, 0x1a, 0x95, 0x55],
-        [0xaa, 0x6a, 0xaa, 0xa9],
-        [0x00, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x56, 0xa9],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0xa8, 0xa9, 0x55],
-        [0xa9, 0xaa, 0x56, 0x55],
-        [0xaa, 0xa5, 0x95, 0x55],
-        [0xaa, 0x69, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x52, 0x95, 0x55, 0x55],
-        [0x5a, 0x6a, 0x99, 0x55],
-        [0x66, 0x15, 0x55, 0x55],
-        [0xaa, 0x1a, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x20, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x55, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xa5, 0x5a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xa5, 0x99, 0x59],
-        [0xaa, 0xa8, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0x6a, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x5a, 0x6a, 0x99, 0x55],
-        [0xa6, 0x15, 0x55, 0xa9],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x5a, 0x8a, 0x00],
-        [0xaa, 0xaa, 0x55, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xa5, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x6a, 0xaa, 0x99, 0x55],
-        [0xaa, 0x1a, 0x9a, 0xa9],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x6a, 0x8a, 0x00],
-        [0xaa, 0x55, 0x5a, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xaa],
-        [0xaa, 0xa9, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0xa5, 0x55, 0x95],
-        [0x6a, 0xaa, 0x99, 0x55],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0xaa, 0x8a, 0x00],
-        [0xa9, 0x55, 0x5a, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x66, 0xa5, 0x55, 0xa9],
-        [0xaa, 0xaa, 0x99, 0x55],
-        [0xaa, 0x2a, 0xaa, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x12, 0x45, 0x55, 0x54],
-    ],
-    [
-        [0x6a, 0xaa, 0x8a, 0x00],
-        [0xa5, 0x55, 0xa5, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x95, 0x55, 0xa5],
-        [0xa6, 0xa5, 0x9a, 0xa9],
-        [0xaa, 0xaa, 0x99, 0xa5],
-        [0xaa, 0x2a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x41, 0x55, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x95, 0x6a, 0xa5, 0x56],
-        [0xaa, 0x69, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xa9, 0xa9, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x95, 0x96, 0xa9],
-        [0xa6, 0xa5, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x99, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x40, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x85, 0x00],
-        [0x55, 0x6a, 0xa5, 0x56],
-        [0xa9, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xa9, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x95, 0xaa, 0xaa],
-        [0xaa, 0xa5, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x40, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x45, 0x00],
-        [0x5a, 0x6a, 0x55, 0x56],
-        [0xa9, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x99, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x01, 0x40, 0x02, 0xa0],
-    ],
-    [
-        [0xaa, 0xa5, 0x45, 0x00],
-        [0x6a, 0x55, 0x55, 0x56],
-        [0x69, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x40, 0x02, 0xa8],
-    ],
-    [
-        [0xaa, 0x55, 0x45, 0x00],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x65, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0xa5, 0x55, 0x45, 0x00],
-        [0x66, 0x95, 0x55, 0x55],
-        [0x65, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x5a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x99, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x55, 0x69],
-        [0x69, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x5a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0xa9, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x5a, 0xa9, 0xaa],
-        [0xa5, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x0a, 0xa9],
-    ],
-    [
-        [0x55, 0x55, 0x40, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0xa9, 0xaa],
-        [0x95, 0x6a, 0x66, 0xaa],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x8a, 0xa9],
-    ],
-    [
-        [0x55, 0x50, 0x00, 0x80],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0x99, 0xaa],
-        [0x55, 0x46, 0x66, 0xaa],
-        [0xa8, 0x6a, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x01, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x8a, 0xa9],
-    ],
-    [
-        [0x50, 0x00, 0x00, 0x80],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0x95, 0x56],
-        [0x55, 0x45, 0x55, 0xa6],
-        [0x65, 0x96, 0x96, 0xaa],
-        [0x26, 0x8a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x02, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x08, 0x28, 0xaa, 0xa9],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x60],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x45, 0x55, 0xa6],
-        [0x55, 0x96, 0x66, 0xaa],
-        [0x2a, 0x4a, 0xaa, 0xaa],
-        [0xaa, 0x8a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x88, 0x2a, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x20, 0x50],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x66, 0x6a],
-        [0x12, 0x86, 0xaa, 0xaa],
-        [0x85, 0x4a, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xa8, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x61, 0x09, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x98, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x45, 0x99, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x56, 0xaa],
-        [0x94, 0x6a, 0xaa, 0xaa],
-        [0x56, 0x65, 0x55, 0x55],
-        [0xa6, 0x55, 0x66, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x00, 0x00, 0x25, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x68, 0x95, 0x55, 0x55],
-        [0xa9, 0x8a, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x00, 0x05, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xa4, 0x6a, 0xaa, 0xaa],
-        [0x02, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x01, 0x50, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_2_11 = [ .phases = 57, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_2_11_data[0] ];
-//const EpdWaveformPhases* epd_wm_ed047tc2_2_ranges[7] = [ &epd_wp_ED047TC2_2_5,&epd_wp_ED047TC2_2_6,&epd_wp_ED047TC2_2_7,&epd_wp_ED047TC2_2_8,&epd_wp_ED047TC2_2_9,&epd_wp_ED047TC2_2_10,&epd_wp_ED047TC2_2_11 ];
-//const EpdWaveformMode epd_wm_ed047tc2_2 = [ .type = 2, .temp_ranges = 7, .range_data = &epd_wm_ed047tc2_2_ranges[0] ];
-const EPD_WP_ED047TC2_5_5_DATA: [[[u8; 4]; 16]; 46] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x88, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x10, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x08, 0x00],
-        [0x00, 0x02, 0x00, 0x80],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa2, 0x88, 0x20, 0x88],
-        [0x22, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0xa2, 0x08],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x44, 0x08, 0x00],
-        [0xa0, 0x02, 0x20, 0xa8],
-        [0x08, 0x62, 0x88, 0x80],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x08, 0x22, 0x88, 0x80],
-        [0x00, 0x80, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x04, 0x00, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x24, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x80, 0x82, 0xa2, 0x08],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x84, 0x84, 0x28, 0x80],
-        [0xa0, 0x02, 0x20, 0xaa],
-        [0xa8, 0xaa, 0x8a, 0x80],
-        [0x22, 0x84, 0x00, 0x28],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x00, 0x80, 0x28, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x08, 0x00, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x28, 0x00, 0x00, 0x00],
-        [0x00, 0x40, 0x20, 0x00],
-        [0x80, 0xa2, 0xa2, 0x88],
-        [0x00, 0x00, 0x20, 0x02],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x84, 0x88, 0x2a, 0xa2],
-        [0xa0, 0xaa, 0xa8, 0xa8],
-        [0xa8, 0xaa, 0x8a, 0x80],
-        [0x2a, 0xaa, 0x08, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x80, 0x28, 0x08],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x28, 0x24, 0x80, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x29, 0x80, 0x00, 0x00],
-        [0x00, 0x40, 0x20, 0x00],
-        [0x80, 0xa2, 0xa2, 0x88],
-        [0x00, 0x02, 0xa0, 0x00],
-        [0x02, 0x00, 0x00, 0x81],
-        [0x88, 0xa8, 0xaa, 0xa9],
-        [0xa2, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0x8a, 0x81],
-        [0x2a, 0xaa, 0x2a, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x80, 0x28, 0x04],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0xa4],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0x00, 0x08, 0x00, 0x00],
-        [0x28, 0xa8, 0x80, 0x00],
-        [0x00, 0x20, 0x00, 0x00],
-        [0x2a, 0x84, 0xa0, 0x08],
-        [0x2a, 0x68, 0x20, 0x80],
-        [0x80, 0xa2, 0xa2, 0xa8],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x02, 0x80, 0x00, 0xa9],
-        [0x9a, 0xa8, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x89],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x22, 0x8a, 0x28, 0x04],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x64],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x00, 0x88, 0x02, 0x00],
-        [0x29, 0xa8, 0x80, 0x00],
-        [0x00, 0x28, 0x08, 0x02],
-        [0x2a, 0x88, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa0],
-        [0xaa, 0xa2, 0xa2, 0xa9],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x02, 0xa0, 0x00, 0xa9],
-        [0x9a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0x8a, 0xa8, 0x84],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x00, 0x8a, 0x02, 0x08],
-        [0x2a, 0xa8, 0x88, 0x00],
-        [0x00, 0x28, 0x8a, 0x01],
-        [0x2a, 0x88, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x80, 0x02, 0xa0, 0x01],
-        [0x0a, 0xaa, 0x80, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x6a, 0x8a, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0xa8, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x20, 0x8a, 0x0a, 0x08],
-        [0x2a, 0xa8, 0xaa, 0x00],
-        [0x00, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xa8, 0x08],
-        [0x2a, 0xaa, 0xa0, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x80, 0x02, 0xa0, 0x81],
-        [0x2a, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0x69, 0x8a, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0xa0, 0x8a, 0x0a, 0x0a],
-        [0xaa, 0xaa, 0xaa, 0x02],
-        [0x08, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xa0, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x82, 0x82, 0xa0, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0x8a, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0xa2, 0x8a, 0x2a, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x28, 0x2a, 0x8a, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x89],
-        [0x2a, 0xaa, 0xa8, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0xa0, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0xaa, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x58],
-        [0x2a, 0xaa, 0xa5, 0x05],
-        [0xaa, 0x8a, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x01],
-        [0x2a, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x01],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x59, 0xaa, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x96, 0xa8],
-        [0xaa, 0xaa, 0x55, 0x09],
-        [0xaa, 0x8a, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x51],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x98, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa4, 0x55],
-        [0xaa, 0xa8, 0x8a, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x54, 0xaa, 0xaa, 0x54],
-    ],
-    [
-        [0xaa, 0xa5, 0x96, 0xa8],
-        [0xaa, 0xa5, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa8, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xa9, 0x90, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa6, 0x99, 0x55, 0x55],
-        [0x94, 0xaa, 0xa6, 0x50],
-    ],
-    [
-        [0xaa, 0xa5, 0x56, 0xa8],
-        [0xaa, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x98, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xa9, 0x15, 0x55],
-        [0x92, 0x95, 0x65, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x94, 0xaa, 0x96, 0x00],
-    ],
-    [
-        [0xa9, 0x55, 0x56, 0xa8],
-        [0xa9, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x2a, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x98, 0x51],
-        [0xaa, 0xa9, 0x59, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x9a, 0x85, 0x55, 0x55],
-        [0x94, 0x95, 0x55, 0x55],
-        [0x99, 0xa9, 0x95, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x94, 0xaa, 0x96, 0x00],
-    ],
-    [
-        [0x95, 0x55, 0x56, 0xa8],
-        [0xa5, 0x55, 0x5a, 0xa5],
-        [0xaa, 0xa6, 0xaa, 0x05],
-        [0xaa, 0xaa, 0x6a, 0xa5],
-        [0x2a, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x96, 0x65],
-        [0xaa, 0xaa, 0x1a, 0x59],
-        [0xaa, 0x59, 0x51, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0x98, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x94, 0x6a, 0x96, 0x00],
-    ],
-    [
-        [0x95, 0x55, 0x55, 0x94],
-        [0x95, 0x55, 0xaa, 0x95],
-        [0xaa, 0xa6, 0xaa, 0x05],
-        [0xaa, 0xaa, 0x6a, 0x95],
-        [0x2a, 0xaa, 0xa5, 0x55],
-        [0x9a, 0x6a, 0x55, 0x55],
-        [0xaa, 0x85, 0x5a, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0xaa, 0xa9, 0x5a, 0x55],
-        [0xa8, 0xaa, 0x00, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0x91, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x90, 0x69, 0x95, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x96, 0x5a, 0xaa, 0x15],
-        [0xaa, 0xa6, 0xa9, 0x05],
-        [0xaa, 0x9a, 0x65, 0x55],
-        [0x2a, 0x96, 0x65, 0x55],
-        [0x96, 0x65, 0x55, 0x55],
-        [0xa1, 0x95, 0x56, 0x55],
-        [0x28, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x52, 0x55],
-        [0xa9, 0x01, 0x05, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x69, 0x55],
-        [0x9a, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa6, 0xa5, 0x85],
-        [0x9a, 0x56, 0x55, 0x55],
-        [0xaa, 0x95, 0x65, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x56, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0xa1, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x00],
-    ],
-    [
-        [0x55, 0x5a, 0xa9, 0x55],
-        [0x9a, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa5, 0xa5, 0x85],
-        [0x92, 0x55, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x15, 0x56, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa0, 0x65, 0x55, 0x00],
-    ],
-    [
-        [0x56, 0xaa, 0xa9, 0x55],
-        [0x99, 0xaa, 0xa1, 0x55],
-        [0xaa, 0x65, 0x95, 0x45],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x54, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa0, 0x55, 0x55, 0x00],
-    ],
-    [
-        [0x6a, 0xaa, 0xaa, 0x55],
-        [0x95, 0xaa, 0x15, 0x55],
-        [0x9a, 0x65, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa0, 0x55, 0x55, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0xa5, 0x55, 0x55],
-        [0x99, 0x65, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa8, 0x55, 0x55, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x65, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x41, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0x56, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x69, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x41, 0x00],
-    ],
-    [
-        [0xa9, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x68, 0x55, 0x55, 0x96],
-        [0x65, 0x55, 0x55, 0x56],
-        [0x6a, 0x65, 0x55, 0xa6],
-        [0xaa, 0x94, 0x49, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0x2a, 0x8a, 0xaa],
-        [0x6a, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x15, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x51, 0x56],
-        [0x65, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x40, 0xaa],
-        [0x55, 0x54, 0x45, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x56, 0xa6, 0x50],
-        [0x55, 0x56, 0x95, 0x56],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x95, 0x04],
-        [0x45, 0x05, 0x55, 0xaa],
-        [0x55, 0x55, 0x55, 0xaa],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x48, 0x58, 0x50],
-        [0x55, 0x55, 0x41, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x54, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x15],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x41, 0x04, 0x44, 0x12],
-        [0x6a, 0x2a, 0x21, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x69],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x05, 0x15, 0x16],
-        [0x41, 0x41, 0x15, 0x02],
-        [0x6a, 0x00, 0xa0, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x66],
-        [0x55, 0x59, 0x59, 0xa6],
-        [0x60, 0x00, 0x00, 0x0a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x6a, 0x59, 0x51, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x50, 0x55, 0x55, 0x52],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x55, 0x55, 0x02],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa5, 0x96, 0xa6, 0x5a],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x56],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_5 = [ .phases = 46, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_5_data[0] ];
-const EPD_WP_ED047TC2_5_6_DATA: [[[u8; 4]; 16]; 43] = [
-    [
-        [0x02, 0xaa, 0x00, 0x00],
-        [0x20, 0x22, 0xa8, 0x80],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x22, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x22, 0xa8, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x02, 0x08, 0x80],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x2a, 0xaa, 0x00, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x20, 0x22, 0xaa, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x02, 0x08, 0x80],
-        [0x00, 0xa0, 0x20, 0x2a],
-        [0x28, 0x22, 0xa8, 0xa8],
-        [0x28, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0xa0],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x02, 0x08, 0x80],
-        [0x00, 0xa8, 0x22, 0x2a],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x80],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0x2a, 0xaa, 0x80],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x22, 0x2a, 0xaa, 0xa0],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xa2, 0x82, 0x08, 0x80],
-        [0x08, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x40],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x88, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x20],
-        [0x20, 0x00, 0x00, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0x2a, 0xaa, 0xa8],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x82, 0x88, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0x94],
-        [0x24, 0x00, 0x28, 0x40],
-    ],
-    [
-        [0xaa, 0xaa, 0x20, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x20, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0x20, 0x80, 0x00, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x2a, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x00],
-        [0xaa, 0x8a, 0x8a, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x24, 0x00, 0x28, 0x40],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x00],
-        [0x20, 0xaa, 0xaa, 0x80],
-        [0x80, 0x00, 0x00, 0x22],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x22, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x28],
-        [0xa0, 0xa0, 0xa8, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x2a, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xa6, 0xaa, 0xaa, 0x95],
-        [0x24, 0x00, 0x26, 0x40],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x00],
-        [0x20, 0xaa, 0xaa, 0x42],
-        [0x80, 0x00, 0x00, 0x22],
-        [0x20, 0x88, 0x00, 0x2a],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0x80, 0x20, 0x28],
-        [0xa2, 0xa8, 0xaa, 0x02],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x01],
-        [0x20, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x08, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa6, 0xaa, 0xa6, 0x55],
-        [0x56, 0x02, 0x95, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0xa0, 0x04],
-        [0x20, 0xaa, 0xa5, 0x4a],
-        [0x80, 0x00, 0x00, 0x29],
-        [0xa0, 0x88, 0x00, 0x26],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0x80, 0x20, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0x2a, 0xaa, 0x82],
-        [0x80, 0x00, 0x00, 0x01],
-        [0x20, 0x80, 0x00, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x98],
-        [0x88, 0x80, 0x00, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa5, 0xa9, 0x65, 0x55],
-        [0x56, 0x0a, 0x95, 0x54],
-    ],
-    [
-        [0xaa, 0xa9, 0xa0, 0x24],
-        [0x20, 0xa9, 0x55, 0x4a],
-        [0x80, 0x00, 0x00, 0x29],
-        [0xa0, 0x88, 0x00, 0x25],
-        [0x2a, 0x80, 0x00, 0x02],
-        [0x20, 0xa0, 0x28, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0x2a, 0xaa, 0x80],
-        [0xa0, 0x00, 0x00, 0x01],
-        [0x20, 0xa2, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0x88, 0xa2, 0xa8, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa5, 0x95, 0x55, 0x55],
-        [0x56, 0x0a, 0x95, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0xa8, 0xa4],
-        [0x20, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x29],
-        [0xa2, 0x88, 0x00, 0x15],
-        [0x2a, 0x80, 0x00, 0x01],
-        [0x20, 0xa8, 0x28, 0x14],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xa0, 0x00, 0x00, 0x01],
-        [0xa2, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0x88, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x56, 0x89, 0x55, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0x98, 0x94],
-        [0xa0, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x25],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x00, 0x21],
-        [0x20, 0xaa, 0xa8, 0x94],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xa0, 0x22, 0xa0, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x94],
-        [0xa8, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xa9, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0xa5, 0x55, 0x14],
-    ],
-    [
-        [0xa9, 0x55, 0x9a, 0x98],
-        [0xa8, 0xa9, 0x55, 0x45],
-        [0xa0, 0x00, 0x00, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x00, 0x21],
-        [0xa2, 0xaa, 0xaa, 0x94],
-        [0xaa, 0xaa, 0xaa, 0x89],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xa2, 0x2a, 0xa0, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x56],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0xa6, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0xa5, 0x55, 0x14],
-    ],
-    [
-        [0xa5, 0x55, 0x5a, 0xa8],
-        [0xa8, 0x95, 0x55, 0x45],
-        [0xa0, 0x82, 0x20, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x20, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x85, 0x69],
-        [0xaa, 0x2a, 0xa8, 0x29],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa9, 0xa9, 0xa5, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0xa5, 0x41, 0x14],
-    ],
-    [
-        [0x95, 0x55, 0x6a, 0x68],
-        [0xa8, 0x55, 0x55, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x15],
-        [0xaa, 0x88, 0x00, 0x15],
-        [0xaa, 0x80, 0x20, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xa9, 0x55, 0x69],
-        [0xaa, 0x2a, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x99, 0xa9, 0x65, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x65, 0x40, 0x14],
-    ],
-    [
-        [0x95, 0x55, 0x66, 0x58],
-        [0xa8, 0x55, 0x5a, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x15],
-        [0xaa, 0xa8, 0xa0, 0x15],
-        [0xaa, 0x62, 0xa8, 0x19],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x95],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0x2a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa9, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x65, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x91, 0x55, 0x40, 0x14],
-    ],
-    [
-        [0x95, 0x56, 0xa5, 0x94],
-        [0xa8, 0x56, 0xaa, 0x85],
-        [0xa8, 0xaa, 0xa0, 0x95],
-        [0xaa, 0xa8, 0xa0, 0x15],
-        [0xaa, 0x6a, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x89, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x99, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x50, 0x00, 0x14],
-    ],
-    [
-        [0x55, 0x6a, 0x89, 0x94],
-        [0x98, 0x6a, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xa0, 0x95],
-        [0xaa, 0xa6, 0xa0, 0x15],
-        [0x8a, 0x6a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x96, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xa6, 0xaa, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x89, 0x50, 0x00, 0x14],
-    ],
-    [
-        [0x56, 0xaa, 0x9a, 0x14],
-        [0x98, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x66, 0xa2, 0x15],
-        [0x99, 0x6a, 0xaa, 0x95],
-        [0xaa, 0x6a, 0x95, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xa6, 0xa9, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0xa9, 0x10, 0x00, 0x04],
-    ],
-    [
-        [0x5a, 0xaa, 0x12, 0x54],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x66, 0xaa, 0x15],
-        [0x95, 0x6a, 0xaa, 0x95],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0xa9, 0x10, 0x00, 0x04],
-    ],
-    [
-        [0x6a, 0xaa, 0x55, 0x54],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x9a, 0x66, 0xaa, 0x15],
-        [0x95, 0x6a, 0xaa, 0x95],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x8a, 0x55, 0x55, 0x55],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x6a, 0xaa, 0x55, 0x55],
-        [0x9a, 0x96, 0xaa, 0x41],
-        [0x6a, 0xaa, 0xaa, 0x55],
-        [0x99, 0x66, 0xaa, 0x95],
-        [0x95, 0x6a, 0x95, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x59, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0x99, 0x65],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x96, 0x56, 0xa5, 0x51],
-        [0x6a, 0x69, 0x8a, 0x55],
-        [0x95, 0x66, 0xaa, 0x95],
-        [0x95, 0x69, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xaa, 0xa8, 0x55, 0x55],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x6a, 0x65, 0x58, 0x55],
-        [0x95, 0x66, 0x59, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa9, 0x45, 0x55, 0x45],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa5, 0x55, 0x55, 0x41],
-        [0x96, 0x55, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x95, 0x55, 0x55, 0x41],
-        [0x56, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x56, 0x59, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x2a],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x59, 0x55, 0x55, 0x2a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x15, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x00, 0x15],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x40],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x6a, 0x95, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x59],
-        [0x15, 0x55, 0x55, 0x82],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x55],
-        [0x99, 0x50, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x55, 0x6a],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0x99, 0x55, 0x6a],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_6 = [ .phases = 43, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_6_data[0] ];
-const EPD_WP_ED047TC2_5_7_DATA: [[[u8; 4]; 16]; 40] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x04, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x0a, 0x08, 0x80],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x80, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa0, 0x80, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x00, 0x12, 0x22, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x1a, 0x20, 0x00, 0x00],
-        [0x01, 0x80, 0x00, 0x00],
-        [0x88, 0x00, 0x00, 0x00],
-        [0x00, 0x10, 0x80, 0x00],
-        [0x20, 0x0a, 0x88, 0x82],
-        [0x80, 0x00, 0x05, 0x08],
-        [0x0a, 0xa0, 0x00, 0x20],
-        [0x20, 0x00, 0x00, 0x20],
-        [0x00, 0x80, 0x00, 0x08],
-        [0x80, 0x28, 0x08, 0x80],
-        [0x2a, 0x80, 0xa0, 0x28],
-        [0x20, 0x00, 0x80, 0x04],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x11, 0x22, 0xa2, 0x00],
-        [0x04, 0x04, 0x00, 0x00],
-        [0x2a, 0xa0, 0x20, 0x00],
-        [0x22, 0xa0, 0x00, 0x00],
-        [0x98, 0x12, 0x00, 0x0a],
-        [0x18, 0xa0, 0x80, 0x00],
-        [0x28, 0x0a, 0x8a, 0x82],
-        [0x88, 0x88, 0x09, 0x28],
-        [0x0a, 0xa2, 0x20, 0x20],
-        [0x20, 0x00, 0x02, 0xa0],
-        [0x00, 0xa4, 0x00, 0x08],
-        [0xa8, 0xa8, 0xa8, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x20, 0x02, 0x88, 0x84],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0x80, 0x20, 0x00],
-        [0x22, 0x22, 0xa2, 0x28],
-        [0x04, 0x08, 0x00, 0x00],
-        [0x2a, 0xa0, 0x28, 0x00],
-        [0x2a, 0xa0, 0x20, 0x0a],
-        [0x98, 0x56, 0x02, 0x8a],
-        [0xa8, 0xa4, 0x80, 0x00],
-        [0x28, 0x0a, 0xaa, 0x82],
-        [0x88, 0xaa, 0x0a, 0x28],
-        [0x2a, 0xaa, 0xa8, 0x22],
-        [0x20, 0x40, 0xa2, 0xa2],
-        [0x12, 0xa8, 0xa0, 0x28],
-        [0xa8, 0xaa, 0xaa, 0xa0],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x82, 0xa8, 0x84],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x08, 0xa0, 0x20, 0x00],
-        [0x26, 0x22, 0xa2, 0x28],
-        [0x05, 0x08, 0x00, 0x00],
-        [0x2a, 0xa0, 0x28, 0x00],
-        [0x2a, 0xa0, 0x28, 0x0a],
-        [0x99, 0xaa, 0x82, 0x8a],
-        [0xaa, 0xa8, 0xa2, 0x00],
-        [0x28, 0x8a, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x28],
-        [0x2a, 0xaa, 0xa8, 0xa2],
-        [0x20, 0xa6, 0xa2, 0xa2],
-        [0x92, 0xaa, 0xa8, 0x28],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x98],
-        [0x58, 0x8a, 0xa8, 0x94],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xa0, 0x20, 0x00],
-        [0x2a, 0x66, 0xa2, 0x28],
-        [0x09, 0x0a, 0x00, 0x00],
-        [0x2a, 0xa2, 0x28, 0x00],
-        [0x2a, 0xa0, 0xa8, 0x0a],
-        [0xa9, 0xaa, 0x82, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x00],
-        [0x28, 0x8a, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0x25, 0xa6, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xa8, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0x8a, 0xaa, 0x94],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0x20, 0x80],
-        [0x2a, 0xa6, 0xa2, 0x28],
-        [0x19, 0x0a, 0x00, 0x00],
-        [0x2a, 0xa2, 0xa8, 0x00],
-        [0x2a, 0xaa, 0xa8, 0x89],
-        [0xaa, 0xaa, 0x8a, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x02],
-        [0x2a, 0x8a, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0xaa, 0xa9, 0x94],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0xa0, 0x82],
-        [0xaa, 0xaa, 0xa2, 0x28],
-        [0x9a, 0x2a, 0x00, 0x00],
-        [0x2a, 0xaa, 0xa8, 0x04],
-        [0x2a, 0xaa, 0xa8, 0xa9],
-        [0xaa, 0xaa, 0x8a, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x82],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x58, 0xaa, 0xa9, 0x94],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x00],
-        [0x0a, 0xaa, 0xa8, 0x82],
-        [0xaa, 0xaa, 0xa2, 0x28],
-        [0x9a, 0x2a, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x88],
-        [0x2a, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x8a, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x96],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0x5a, 0xaa, 0xa9, 0x54],
-    ],
-    [
-        [0xa8, 0xa0, 0x00, 0x14],
-        [0x0a, 0xaa, 0xa8, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x28],
-        [0xaa, 0x2a, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0x2a, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x5a, 0xaa, 0x69, 0x54],
-    ],
-    [
-        [0xa8, 0x50, 0x00, 0x14],
-        [0x8a, 0xaa, 0x9a, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x16],
-        [0xaa, 0x2a, 0x08, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0xa9, 0xa5, 0x55, 0x55],
-        [0x9a, 0xaa, 0x65, 0x50],
-    ],
-    [
-        [0x98, 0x50, 0x00, 0x14],
-        [0x8a, 0xaa, 0x9a, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0x2a, 0x08, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa6, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0xa9, 0x64, 0x50],
-    ],
-    [
-        [0x98, 0x50, 0x00, 0x28],
-        [0x8a, 0x6a, 0x9a, 0x41],
-        [0xaa, 0xaa, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x18, 0x80],
-        [0xaa, 0xaa, 0x96, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xa9, 0x65],
-        [0xaa, 0xaa, 0x69, 0x95],
-        [0xaa, 0xaa, 0xa5, 0x59],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x80, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0xa9, 0x54, 0x50],
-    ],
-    [
-        [0x94, 0x50, 0x00, 0x28],
-        [0xa6, 0x55, 0x55, 0x61],
-        [0xaa, 0xaa, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x28, 0x80],
-        [0xa9, 0x9a, 0x96, 0x95],
-        [0xaa, 0xaa, 0x96, 0x55],
-        [0xaa, 0xa9, 0x69, 0x65],
-        [0xaa, 0xaa, 0x69, 0x95],
-        [0xaa, 0xa5, 0x65, 0x55],
-        [0xaa, 0xa6, 0x25, 0x55],
-        [0xaa, 0x01, 0x55, 0x55],
-        [0xaa, 0xaa, 0x59, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0xa5, 0x54, 0x40],
-    ],
-    [
-        [0x94, 0xa0, 0x00, 0x28],
-        [0xa5, 0x55, 0x55, 0x51],
-        [0xaa, 0xa9, 0x59, 0x15],
-        [0xaa, 0xaa, 0x68, 0xa1],
-        [0xa5, 0x59, 0x55, 0x55],
-        [0xaa, 0x5a, 0x55, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0xaa, 0xa5, 0x15, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x65, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x55, 0x59],
-        [0xaa, 0xa9, 0x59, 0x15],
-        [0xaa, 0xaa, 0x68, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x65, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa8, 0x65, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x65, 0x55],
-        [0x8a, 0x99, 0x59, 0x15],
-        [0xaa, 0xa5, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0xa5, 0x65, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x92, 0x25, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0xa0, 0x00, 0x14],
-        [0xa5, 0x55, 0x65, 0x95],
-        [0x89, 0x99, 0x55, 0x15],
-        [0xaa, 0xa5, 0xaa, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x26, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x96, 0x25, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x54, 0x40],
-    ],
-    [
-        [0x64, 0x50, 0x80, 0x15],
-        [0xa5, 0x95, 0x65, 0x95],
-        [0x99, 0x95, 0x55, 0x15],
-        [0xaa, 0x95, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x26, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x14, 0x00],
-    ],
-    [
-        [0x58, 0x5a, 0x80, 0x15],
-        [0xa9, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0xaa, 0x95, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa4, 0x55, 0x14, 0x00],
-    ],
-    [
-        [0x98, 0x5a, 0x80, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x2a, 0x95, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xa6, 0x54, 0x14, 0x00],
-    ],
-    [
-        [0x98, 0x5a, 0x48, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x25, 0x95, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x2a, 0x55, 0x55, 0x65],
-        [0xa6, 0x54, 0x10, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x68, 0x55],
-        [0x6a, 0xaa, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x25, 0x55, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x66],
-        [0x2a, 0xaa, 0xaa, 0x6a],
-        [0xa6, 0x10, 0x00, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x64, 0x41],
-        [0x5a, 0xaa, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x15],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xa6, 0x10, 0x08, 0x00],
-    ],
-    [
-        [0x9a, 0x55, 0x94, 0x81],
-        [0x5a, 0x6a, 0x9a, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x46, 0x0a, 0x9a, 0x81],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x45, 0x0a, 0xa9, 0x41],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x15, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x44, 0x85, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x45, 0x0a, 0x65, 0x41],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0x55, 0x55, 0x69],
-        [0x55, 0x55, 0x55, 0x69],
-        [0x55, 0x56, 0x11, 0x59],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x66],
-        [0x65, 0x44, 0x42, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x46, 0x05, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x51],
-        [0x55, 0xaa, 0xaa, 0x54],
-        [0x45, 0x55, 0x55, 0x56],
-        [0x59, 0x59, 0x11, 0x56],
-        [0x55, 0x55, 0x14, 0x52],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x51, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x46, 0x05, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x61],
-        [0x40, 0x55, 0x55, 0x80],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x04, 0x02, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x59, 0x55, 0x65, 0x46],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x45, 0x05, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x40, 0x00, 0x00, 0x42],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x64, 0x0a, 0x9a, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x45, 0x05, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x65, 0x55, 0x55, 0x55],
-        [0x55, 0x64, 0x55, 0x41],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x6a, 0x2a, 0x82, 0x2a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x44],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x40],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x42],
-        [0x95, 0x55, 0x51, 0x42],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x50, 0x50, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_7 = [ .phases = 40, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_7_data[0] ];
-const EPD_WP_ED047TC2_5_8_DATA: [[[u8; 4]; 16]; 38] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x08, 0x00, 0x8a, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x80, 0x08, 0x82],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x02, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0x00, 0x8a, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x82],
-        [0x20, 0x00, 0x00, 0x08],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x02, 0x82, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0x08, 0xaa, 0x08],
-        [0x00, 0x00, 0x08, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0x20, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x82],
-        [0xa0, 0x00, 0x00, 0x0a],
-        [0x20, 0x00, 0x00, 0x00],
-        [0x02, 0x82, 0x00, 0x2a],
-        [0x80, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x00, 0x20, 0x08, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x08, 0xa0, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0xa0, 0x08, 0x81],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x81],
-        [0xa0, 0x00, 0x00, 0x05],
-        [0x20, 0x00, 0x00, 0x01],
-        [0x02, 0x82, 0x00, 0xa9],
-        [0x80, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x12, 0x28, 0xaa, 0x00],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x88, 0xa0, 0x8a, 0x02],
-        [0x00, 0x20, 0x00, 0x20],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x82, 0xa8, 0x08, 0x81],
-        [0x08, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x08],
-        [0x00, 0x02, 0x0a, 0x80],
-        [0x88, 0x00, 0x02, 0x81],
-        [0xa0, 0x00, 0x00, 0x25],
-        [0x28, 0x00, 0x00, 0x21],
-        [0x22, 0x82, 0x00, 0xa5],
-        [0x80, 0x00, 0x00, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x19, 0x2a, 0xaa, 0x80],
-    ],
-    [
-        [0xa8, 0x00, 0x0a, 0x00],
-        [0x8a, 0xa8, 0x8a, 0x0a],
-        [0x00, 0x20, 0x00, 0x28],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x20, 0x00, 0x00, 0x0a],
-        [0x82, 0xaa, 0x08, 0x81],
-        [0x08, 0x20, 0x02, 0x02],
-        [0x08, 0x00, 0x00, 0x08],
-        [0x28, 0x02, 0x0a, 0x80],
-        [0x88, 0x08, 0x0a, 0x81],
-        [0xa0, 0x00, 0x08, 0x25],
-        [0x2a, 0x00, 0x0a, 0x21],
-        [0x22, 0xa2, 0x00, 0x95],
-        [0x80, 0x02, 0x00, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0x19, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x00],
-        [0x8a, 0xaa, 0x8a, 0x0a],
-        [0x02, 0x20, 0x00, 0x28],
-        [0x88, 0x00, 0x00, 0x02],
-        [0x20, 0x00, 0x00, 0x09],
-        [0xa2, 0xaa, 0xa8, 0x85],
-        [0x8a, 0x28, 0x0a, 0x09],
-        [0x88, 0x00, 0x00, 0x08],
-        [0x28, 0x02, 0x8a, 0x80],
-        [0x88, 0x0a, 0x0a, 0x81],
-        [0xa8, 0x02, 0x08, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0x2a, 0xaa, 0x2a, 0x95],
-        [0xa0, 0x02, 0x82, 0x81],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x19, 0xaa, 0xaa, 0x80],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x00],
-        [0x8a, 0xaa, 0xaa, 0x09],
-        [0x02, 0x20, 0x08, 0x04],
-        [0x88, 0x00, 0x00, 0x21],
-        [0x28, 0x00, 0x00, 0x09],
-        [0xaa, 0xaa, 0xa8, 0x85],
-        [0x8a, 0xaa, 0x0a, 0x09],
-        [0x88, 0x08, 0x00, 0x04],
-        [0xaa, 0x82, 0x8a, 0x82],
-        [0xaa, 0xaa, 0xaa, 0x81],
-        [0xa8, 0x22, 0xa8, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0x2a, 0x95],
-        [0xa0, 0x22, 0x82, 0x91],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x6a, 0xaa, 0x84],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x10],
-        [0x8a, 0xaa, 0xaa, 0x09],
-        [0x22, 0x20, 0x28, 0x14],
-        [0x88, 0x00, 0x00, 0x25],
-        [0x28, 0x00, 0x00, 0x05],
-        [0xaa, 0xaa, 0xa8, 0x45],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x88, 0x0a, 0x00, 0x85],
-        [0xaa, 0xa2, 0x8a, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0x2a, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa2, 0x2a, 0x8a, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0x15, 0x6a, 0xa6, 0x84],
-    ],
-    [
-        [0xa8, 0x00, 0x05, 0x90],
-        [0x8a, 0xaa, 0x65, 0x09],
-        [0xaa, 0x22, 0xaa, 0x15],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x00, 0x00, 0x25],
-        [0xaa, 0xaa, 0xa8, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xa8, 0x2a, 0x80, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa2, 0xaa, 0x8a, 0x95],
-        [0xa9, 0xaa, 0x66, 0x55],
-        [0x15, 0x6a, 0x56, 0x44],
-    ],
-    [
-        [0x94, 0x00, 0x05, 0x90],
-        [0x8a, 0xaa, 0x65, 0x29],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x08, 0x00, 0x25],
-        [0xaa, 0xaa, 0xa6, 0x65],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0x2a, 0x8a, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x45],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0x29, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xa9, 0xa9, 0x55, 0x55],
-        [0x25, 0x6a, 0x55, 0x54],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0x64],
-        [0x8a, 0xaa, 0x65, 0x19],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x88, 0x00, 0x00, 0x25],
-        [0xa8, 0x0a, 0x0a, 0x15],
-        [0xaa, 0x6a, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x8a, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa9, 0x69, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x99, 0xa5, 0x55, 0x55],
-        [0x21, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0x64],
-        [0xaa, 0x9a, 0x65, 0x19],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0x8a, 0x00, 0x00, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xaa, 0x5a, 0xa6, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa8, 0x55],
-        [0x9a, 0xaa, 0xa6, 0x55],
-        [0x9a, 0xaa, 0xa5, 0x55],
-        [0xa9, 0x69, 0xaa, 0x55],
-        [0xaa, 0xa9, 0x6a, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x22, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x94, 0x00, 0x0a, 0xa5],
-        [0xaa, 0x5a, 0x65, 0x15],
-        [0xaa, 0x22, 0xaa, 0x95],
-        [0xaa, 0xa0, 0x08, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xaa, 0x55, 0x56, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x15],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x55],
-        [0x9a, 0xa9, 0x56, 0x55],
-        [0x9a, 0xa1, 0x55, 0x55],
-        [0xa9, 0x69, 0x95, 0x55],
-        [0xaa, 0xa9, 0x69, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x22, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x68, 0x00, 0x0a, 0x95],
-        [0xa6, 0x55, 0x55, 0x15],
-        [0xaa, 0x12, 0x8a, 0x15],
-        [0xaa, 0xa0, 0x28, 0x15],
-        [0xa8, 0x2a, 0x8a, 0x15],
-        [0xa9, 0x55, 0x56, 0x55],
-        [0xaa, 0xaa, 0xa5, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa9, 0xa5, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0x91, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x9a, 0xa9, 0x65, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa2, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x68, 0x00, 0x0a, 0x55],
-        [0xa6, 0x55, 0x9a, 0x15],
-        [0xaa, 0x12, 0x06, 0x55],
-        [0xaa, 0xaa, 0x28, 0x15],
-        [0xa8, 0x2a, 0xaa, 0x15],
-        [0xa9, 0x55, 0x56, 0x55],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xaa, 0xa5, 0x55, 0x55],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x99, 0x95, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa2, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x68, 0x00, 0x05, 0x55],
-        [0xa5, 0x55, 0x9a, 0x25],
-        [0xa9, 0x91, 0x55, 0x55],
-        [0xaa, 0xaa, 0x28, 0x15],
-        [0xa8, 0x2a, 0xaa, 0x95],
-        [0x59, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x6a, 0x55],
-        [0xaa, 0xa9, 0x65, 0x55],
-        [0xa4, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x68, 0x00, 0x05, 0x55],
-        [0xa5, 0x65, 0x9a, 0x25],
-        [0x99, 0x91, 0x55, 0x55],
-        [0xaa, 0xaa, 0xa8, 0x95],
-        [0x9a, 0x25, 0x65, 0x95],
-        [0x51, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xa6, 0x85, 0x65, 0x55],
-        [0x9a, 0x29, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x54, 0x00, 0x05, 0x55],
-        [0xa9, 0xa5, 0x9a, 0x25],
-        [0x99, 0x91, 0x55, 0x55],
-        [0xa6, 0xaa, 0xaa, 0x95],
-        [0x96, 0xa5, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x85, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x96, 0x55, 0x55, 0x55],
-        [0xaa, 0x95, 0x11, 0x54],
-    ],
-    [
-        [0x94, 0x00, 0x05, 0x55],
-        [0x69, 0xaa, 0xaa, 0x25],
-        [0x95, 0x99, 0x55, 0x55],
-        [0xa6, 0xaa, 0x96, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x56],
-        [0xa6, 0x55, 0x55, 0x59],
-        [0xaa, 0x95, 0x21, 0x54],
-    ],
-    [
-        [0x94, 0x00, 0x85, 0x51],
-        [0x6a, 0xaa, 0xaa, 0xa5],
-        [0x95, 0x99, 0x55, 0x55],
-        [0x65, 0x59, 0x96, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x66],
-        [0xaa, 0x56, 0x99, 0x6a],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x94, 0x22, 0x85, 0x51],
-        [0x6a, 0xaa, 0x20, 0x95],
-        [0x55, 0x59, 0x55, 0x55],
-        [0x55, 0x55, 0x56, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x66, 0x56, 0x95, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x96, 0x2a, 0x65, 0x01],
-        [0xa2, 0x8a, 0x65, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x15, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x96, 0xa9, 0x50, 0x01],
-        [0xa6, 0x0a, 0x65, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0x4a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x66, 0x96, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x42, 0x95, 0x90, 0x01],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x52],
-        [0x65, 0x54, 0x01, 0x2a],
-        [0x65, 0x55, 0x55, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x41, 0x56, 0xa0, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x64],
-        [0x66, 0x6a, 0xa9, 0x50],
-        [0x55, 0x55, 0x55, 0x64],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x45, 0x50, 0x00, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x41, 0x6a, 0x60, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x5a, 0x95, 0x5a],
-        [0x55, 0x15, 0x55, 0x20],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x42, 0xa9, 0x50, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x65, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x65, 0x6a, 0xa6],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x41, 0x50, 0x15, 0x6a],
-        [0x45, 0x54, 0x55, 0x91],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x42, 0x95, 0x50, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x51],
-        [0x65, 0x55, 0x55, 0x54],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x45, 0x54, 0x10, 0x6a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x41, 0x55, 0x50, 0x15],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xa2, 0xaa, 0xa2],
-        [0x52, 0xa6, 0x65, 0x92],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x41, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xa2],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x84, 0x50, 0x05, 0x0a],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x50, 0x15, 0x48],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x81, 0x51, 0x11, 0x68],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x45, 0x55, 0x55, 0x44],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_8 = [ .phases = 38, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_8_data[0] ];
-const EPD_WP_ED047TC2_5_9_DATA: [[[u8; 4]; 16]; 38] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x0a, 0x82, 0x02, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x80, 0x02, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x0a, 0x8a, 0x82, 0x00],
-        [0x40, 0x08, 0x00, 0x40],
-        [0x00, 0x80, 0x22, 0x08],
-        [0x00, 0x08, 0x08, 0x08],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x10, 0x04, 0x00, 0x00],
-        [0x11, 0x40, 0x20, 0x00],
-        [0x81, 0x00, 0x00, 0x20],
-        [0x28, 0x08, 0x00, 0x82],
-        [0x00, 0x20, 0x80, 0x80],
-        [0x00, 0x00, 0x02, 0x80],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0x6a, 0x8a, 0x8a, 0x00],
-        [0x90, 0x08, 0x01, 0x40],
-        [0x00, 0x80, 0x22, 0x08],
-        [0x00, 0x28, 0x88, 0x0a],
-        [0x80, 0x08, 0x00, 0x00],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x80, 0x00],
-        [0x14, 0x14, 0x00, 0x00],
-        [0x21, 0x50, 0x20, 0x80],
-        [0x81, 0x18, 0x00, 0xa0],
-        [0x29, 0x0a, 0x02, 0x81],
-        [0x2a, 0xa2, 0xaa, 0xa0],
-        [0x00, 0x2a, 0x02, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xa0, 0x08, 0x02, 0x80],
-        [0x80, 0x88, 0x22, 0x08],
-        [0x20, 0xa8, 0x89, 0x0a],
-        [0x80, 0xa8, 0x00, 0x02],
-        [0xaa, 0x8a, 0x0a, 0x08],
-        [0x00, 0x08, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x08, 0x80, 0x00],
-        [0x24, 0x54, 0x22, 0x80],
-        [0x25, 0x52, 0x2a, 0xa0],
-        [0x86, 0xa8, 0x20, 0xa8],
-        [0xaa, 0x2a, 0x82, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x00, 0xaa, 0x22, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0x8a, 0x2a, 0x80],
-        [0x80, 0x88, 0x22, 0x08],
-        [0x22, 0xaa, 0x8a, 0x0a],
-        [0x82, 0xa8, 0x00, 0x02],
-        [0xaa, 0xaa, 0x0a, 0x0a],
-        [0x00, 0x08, 0x02, 0x28],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x2a, 0x8a, 0x80, 0x00],
-        [0xa5, 0xaa, 0xa2, 0xa0],
-        [0x26, 0x96, 0xaa, 0xa0],
-        [0x8a, 0xa8, 0xa0, 0xa8],
-        [0xaa, 0x2a, 0x8a, 0x81],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x00, 0xaa, 0x22, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0x8a, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x0a],
-        [0xa2, 0xa8, 0x02, 0x02],
-        [0xaa, 0xaa, 0x2a, 0x0a],
-        [0x00, 0x08, 0x02, 0x2a],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x2a, 0xaa, 0x80, 0x00],
-        [0xa9, 0xaa, 0xa2, 0xa0],
-        [0x26, 0xa6, 0xaa, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x2a, 0x8a, 0x41],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x08, 0xaa, 0x12, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0x8a, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x29],
-        [0xa2, 0xa8, 0x02, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x00, 0x08, 0x02, 0x2a],
-        [0x00, 0x00, 0x08, 0x80],
-        [0x2a, 0xaa, 0x8a, 0x22],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x8a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x69],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0xaa, 0x12, 0x40],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x80, 0x88, 0x22, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x09],
-        [0x20, 0x08, 0x02, 0x28],
-        [0x00, 0x02, 0x0a, 0xa0],
-        [0x2a, 0xaa, 0x8a, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x28, 0xaa, 0x11, 0x44],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0x8a, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x81, 0x88, 0xa2, 0x24],
-        [0xaa, 0xaa, 0x8a, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0x8a, 0x02, 0x28],
-        [0x00, 0x82, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xa6, 0xaa, 0xa9, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0x91, 0x44],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x20],
-        [0xaa, 0xaa, 0x89, 0x80],
-        [0xaa, 0xaa, 0xaa, 0x80],
-        [0x82, 0x88, 0xaa, 0x24],
-        [0xaa, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0x0a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0xaa, 0x82, 0x04],
-        [0x20, 0xa2, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x29],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x96, 0xa5, 0x65, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x54],
-        [0x2a, 0xaa, 0x91, 0x44],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x10],
-        [0xaa, 0xaa, 0x89, 0x80],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0xa2, 0xa8, 0x9a, 0x14],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x8a, 0x21],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0x2a, 0xaa, 0x8a, 0x15],
-        [0x20, 0xaa, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0x25],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x5a],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x96, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x56],
-        [0x2a, 0x99, 0x91, 0x44],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x94],
-        [0xaa, 0xa9, 0x45, 0x44],
-        [0xaa, 0xaa, 0xaa, 0xa0],
-        [0xa2, 0xa8, 0x99, 0x16],
-        [0xaa, 0xa6, 0xa6, 0x95],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xa9, 0xa5],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0x2a, 0xaa, 0xaa, 0x69],
-        [0x2a, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x5a],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0xaa, 0x55, 0x56],
-        [0x1a, 0x95, 0x99, 0x04],
-    ],
-    [
-        [0x80, 0x00, 0x00, 0x64],
-        [0xa9, 0x65, 0x45, 0x48],
-        [0xaa, 0xaa, 0xa9, 0xa2],
-        [0xa2, 0x6a, 0x99, 0x15],
-        [0xaa, 0xa5, 0x65, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x99],
-        [0xaa, 0xaa, 0xa5, 0xa5],
-        [0xaa, 0xaa, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0x2a, 0xaa, 0x6a, 0x95],
-        [0xaa, 0xaa, 0x99, 0x55],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0xaa, 0xaa, 0x9a, 0x55],
-        [0x95, 0x95, 0x55, 0x55],
-        [0xaa, 0x99, 0x55, 0x55],
-        [0x19, 0x55, 0x89, 0x04],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0xa4],
-        [0xa5, 0x65, 0x45, 0x4a],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xa2, 0x6a, 0x99, 0x15],
-        [0xa9, 0x95, 0x65, 0x95],
-        [0xaa, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xa5, 0x95, 0xa5],
-        [0xaa, 0xa6, 0xa9, 0x95],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0x2a, 0xaa, 0x6a, 0x95],
-        [0xaa, 0xaa, 0x59, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa6, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x99, 0x55, 0x55],
-        [0x15, 0x55, 0x49, 0x14],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0x54],
-        [0xa5, 0x65, 0x45, 0x88],
-        [0xaa, 0xaa, 0xa9, 0xa1],
-        [0xaa, 0x66, 0x11, 0x15],
-        [0xa9, 0x55, 0x65, 0x55],
-        [0xaa, 0x96, 0xa9, 0x95],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa6, 0xa9, 0x15],
-        [0xaa, 0xaa, 0x95, 0x55],
-        [0x26, 0xa5, 0x65, 0x95],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x14],
-    ],
-    [
-        [0x40, 0x00, 0x00, 0x55],
-        [0xa5, 0x55, 0x46, 0x89],
-        [0xaa, 0xa6, 0xa5, 0x61],
-        [0xaa, 0x66, 0x51, 0x15],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x28, 0x56, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xa6, 0xa9, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x14],
-    ],
-    [
-        [0xa0, 0x00, 0x00, 0x55],
-        [0x95, 0x56, 0x8a, 0x99],
-        [0xaa, 0xaa, 0x9a, 0x51],
-        [0xaa, 0x66, 0x55, 0x95],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x55, 0xa5, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xa5, 0x45, 0x55],
-        [0xaa, 0xa9, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0x9a, 0xa9, 0x55, 0x55],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x45, 0x14],
-    ],
-    [
-        [0xa0, 0x00, 0x00, 0x55],
-        [0x9a, 0x9a, 0x8a, 0x65],
-        [0xaa, 0xa5, 0x56, 0x51],
-        [0x6a, 0x56, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x55, 0x55],
-        [0xaa, 0x59, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x55, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x96],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x05, 0x55, 0x45, 0x14],
-    ],
-    [
-        [0xa0, 0x00, 0x20, 0x51],
-        [0x5a, 0xaa, 0x8a, 0x65],
-        [0xa5, 0x65, 0x56, 0x59],
-        [0x69, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x85, 0x55, 0x45, 0x10],
-    ],
-    [
-        [0x60, 0x28, 0xa0, 0x51],
-        [0x6a, 0xaa, 0x8a, 0x65],
-        [0x95, 0x55, 0x56, 0x55],
-        [0x59, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x84, 0x55, 0x44, 0x10],
-    ],
-    [
-        [0x6a, 0xaa, 0xa8, 0x11],
-        [0x6a, 0xaa, 0x8a, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x45, 0x44, 0x10],
-    ],
-    [
-        [0x5a, 0xaa, 0x64, 0x01],
-        [0xa6, 0x99, 0x45, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa6, 0x44, 0x46, 0x10],
-    ],
-    [
-        [0x5a, 0x95, 0x56, 0x01],
-        [0x95, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x99, 0x01],
-        [0x95, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x56, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x95],
-        [0x95, 0x55, 0x55, 0x51],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x25, 0x6a, 0x99, 0x01],
-        [0x15, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x65, 0x65],
-        [0x56, 0x5a, 0x56, 0x56],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x95, 0x55, 0x41, 0x56],
-        [0x95, 0x55, 0x55, 0x41],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x5a, 0x66, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x2a, 0xaa, 0x56, 0x05],
-        [0x55, 0x55, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x95, 0x61],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x55, 0x45],
-        [0x55, 0x55, 0x55, 0x59],
-        [0x55, 0x55, 0x54, 0x55],
-        [0x55, 0x54, 0x00, 0x15],
-        [0x91, 0x55, 0x15, 0x42],
-        [0x55, 0x55, 0x24, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x2a, 0x95, 0x56, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x65, 0x49, 0x91],
-        [0x55, 0x55, 0x54, 0x44],
-        [0x55, 0x55, 0x55, 0x49],
-        [0x15, 0x55, 0x55, 0x54],
-        [0x55, 0x51, 0x54, 0x0a],
-        [0x55, 0x04, 0x00, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x54, 0x10, 0x55],
-        [0x65, 0x56, 0x9a, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x05],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0xa2, 0x1a, 0x06, 0x50],
-        [0x68, 0xa0, 0xa8, 0xaa],
-        [0x55, 0x45, 0x55, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x45, 0x04, 0x00, 0xaa],
-        [0x55, 0x55, 0x65, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x51, 0x05, 0x01, 0x02],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x6a],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x06],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x58],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x58],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x6a, 0xaa, 0xa9, 0xa4],
-        [0x55, 0x55, 0x55, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x54],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_9 = [ .phases = 38, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_9_data[0] ];
-const EPD_WP_ED047TC2_5_10_DATA: [[[u8; 4]; 16]; 44] = [
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x80, 0x80, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x80, 0x00, 0x08, 0x02],
-        [0x00, 0x80, 0x00, 0x08],
-        [0x10, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x02, 0x00, 0x00, 0x00],
-        [0xa2, 0x88, 0x20, 0x05],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x00, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x80, 0x20, 0x00],
-        [0x00, 0x80, 0x00, 0x00],
-        [0x00, 0x82, 0x00, 0x00],
-        [0xa8, 0x00, 0x08, 0x82],
-        [0x00, 0x80, 0x20, 0x0a],
-        [0x92, 0x00, 0x00, 0x8a],
-        [0x2a, 0x20, 0xa2, 0xa8],
-        [0x00, 0x00, 0x00, 0x08],
-    ],
-    [
-        [0x02, 0x00, 0x00, 0x00],
-        [0xa2, 0x8a, 0x20, 0x05],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x08, 0x05, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x00, 0x80, 0x20, 0x00],
-        [0x02, 0xa0, 0xa0, 0x00],
-        [0x00, 0x82, 0x00, 0x02],
-        [0xaa, 0x20, 0x08, 0x82],
-        [0x84, 0x80, 0xa0, 0x0a],
-        [0xa2, 0x84, 0x00, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x08],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xa2, 0xaa, 0xa0, 0x05],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x29, 0x15, 0x42, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x02, 0xa0, 0x20, 0x80],
-        [0x02, 0xaa, 0xa8, 0x00],
-        [0x00, 0x82, 0x00, 0x02],
-        [0xaa, 0xa8, 0x2a, 0x82],
-        [0x9a, 0x90, 0xa8, 0x0a],
-        [0xa6, 0xa8, 0x00, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x22, 0x00, 0x80, 0x28],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xa2, 0xaa, 0xa8, 0x09],
-        [0x00, 0x00, 0x00, 0x40],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x29, 0x1a, 0x46, 0x00],
-        [0x00, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x00],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x02, 0xaa, 0x28, 0x80],
-        [0x2a, 0xaa, 0xa8, 0x00],
-        [0x02, 0x82, 0x00, 0x02],
-        [0xaa, 0xa8, 0x2a, 0x8a],
-        [0xaa, 0xa8, 0xaa, 0x0a],
-        [0xaa, 0xaa, 0x08, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0x22, 0x8a, 0x88, 0x24],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x09],
-        [0x00, 0x00, 0x00, 0x90],
-        [0x02, 0xa0, 0x08, 0x02],
-        [0x2a, 0x2a, 0x86, 0x20],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x80],
-        [0x2a, 0xaa, 0xa8, 0x20],
-        [0x02, 0x82, 0x00, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xa8, 0xaa, 0x0a],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x1a, 0x8a, 0x8a, 0xa4],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xa8, 0x0a],
-        [0x80, 0x00, 0x00, 0x90],
-        [0x02, 0xaa, 0x2a, 0x02],
-        [0x2a, 0xaa, 0x8a, 0x20],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xa2, 0xa2, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x1a, 0xaa, 0x4a, 0x54],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x08],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x02, 0x00, 0x90],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa0],
-        [0x08, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x02, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xa8, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x16, 0xaa, 0x46, 0x54],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x08],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x02, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x82, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x2a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa4],
-        [0x15, 0x9a, 0x65, 0x54],
-    ],
-    [
-        [0x82, 0x00, 0x00, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0x80, 0x22, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x02],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x00, 0x02, 0x82, 0x20],
-        [0x00, 0x00, 0x00, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0x20],
-        [0x2a, 0xaa, 0xaa, 0x02],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x15, 0x95, 0x65, 0x54],
-    ],
-    [
-        [0x81, 0x00, 0x00, 0x28],
-        [0xaa, 0xaa, 0xaa, 0x0a],
-        [0xa2, 0x22, 0x00, 0xaa],
-        [0x02, 0xaa, 0xaa, 0x8a],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x0a, 0x02, 0x82, 0x20],
-        [0x00, 0x0a, 0x00, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x82],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa2, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0xa8],
-        [0xaa, 0xaa, 0x9a, 0x2a],
-        [0xa2, 0xa2, 0x02, 0xaa],
-        [0x02, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-        [0x28, 0x00, 0x00, 0x02],
-        [0x0a, 0x82, 0x82, 0xa0],
-        [0x00, 0x8a, 0x80, 0x09],
-        [0x2a, 0xaa, 0xaa, 0x22],
-        [0x2a, 0xaa, 0xaa, 0xa2],
-        [0xaa, 0xaa, 0xaa, 0x01],
-        [0xaa, 0xaa, 0xa2, 0x21],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0xa8],
-        [0xaa, 0x66, 0x9a, 0x2a],
-        [0xa2, 0xa2, 0x22, 0xaa],
-        [0x02, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xa9, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0x2a, 0xa2, 0xa0, 0x80],
-        [0x02, 0x8a, 0x80, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x20],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xa0, 0x61],
-        [0xaa, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x81, 0x00, 0x80, 0x94],
-        [0xaa, 0x65, 0x56, 0x26],
-        [0xa2, 0xaa, 0x22, 0xaa],
-        [0x82, 0xaa, 0xaa, 0xa9],
-        [0x2a, 0xaa, 0xa9, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x82],
-        [0x02, 0x8a, 0x82, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x21],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xa8, 0xaa, 0xa1],
-        [0xaa, 0xaa, 0xa0, 0x65],
-        [0xaa, 0xaa, 0x20, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0xaa, 0x95, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x41, 0x00, 0x40, 0x94],
-        [0x69, 0x65, 0x56, 0x26],
-        [0xa2, 0xaa, 0x22, 0xaa],
-        [0x82, 0xaa, 0xaa, 0xa1],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0x00, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xa2, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x21],
-        [0x2a, 0xaa, 0xaa, 0x9a],
-        [0xaa, 0x28, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0x80, 0x65],
-        [0xaa, 0x2a, 0x00, 0x85],
-        [0xaa, 0xaa, 0xaa, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x42, 0x00, 0x48, 0xa4],
-        [0x59, 0x55, 0x55, 0xa9],
-        [0xa6, 0xaa, 0x22, 0x69],
-        [0x82, 0xaa, 0xaa, 0x25],
-        [0x2a, 0xaa, 0xaa, 0x9a],
-        [0xa8, 0x80, 0x00, 0x01],
-        [0xaa, 0xaa, 0xa8, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0x2a, 0xaa, 0x8a, 0x29],
-        [0x2a, 0xaa, 0x56, 0x99],
-        [0xaa, 0x28, 0xa8, 0xa9],
-        [0xaa, 0x82, 0x80, 0x65],
-        [0xaa, 0x2a, 0x00, 0x85],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x42, 0x00, 0x48, 0x64],
-        [0x59, 0x59, 0x65, 0xa9],
-        [0xa6, 0xaa, 0x22, 0x69],
-        [0x82, 0xaa, 0xa0, 0x65],
-        [0x2a, 0xaa, 0xa9, 0x56],
-        [0xaa, 0x80, 0x00, 0x09],
-        [0xaa, 0xa9, 0xa9, 0x8a],
-        [0xaa, 0xaa, 0xaa, 0x05],
-        [0xaa, 0x2a, 0x86, 0x69],
-        [0x2a, 0x55, 0x55, 0x99],
-        [0xaa, 0x69, 0x89, 0xa9],
-        [0x96, 0x82, 0x00, 0x45],
-        [0xaa, 0x22, 0x10, 0x05],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0x62, 0x00, 0x4a, 0x54],
-        [0x59, 0x9a, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x22, 0x65],
-        [0x82, 0xaa, 0xa5, 0x45],
-        [0x26, 0xaa, 0xa9, 0x55],
-        [0xaa, 0x80, 0x00, 0x09],
-        [0xaa, 0xa9, 0x29, 0x89],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xaa, 0x55, 0x54, 0x69],
-        [0x21, 0x55, 0x55, 0x55],
-        [0xaa, 0x69, 0x45, 0xa9],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x22, 0x10, 0x05],
-        [0x89, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0xa2, 0x00, 0x8a, 0x54],
-        [0x66, 0xaa, 0xaa, 0x95],
-        [0xaa, 0xaa, 0x22, 0x55],
-        [0x82, 0x95, 0x55, 0x55],
-        [0x16, 0xa5, 0x55, 0x55],
-        [0xa2, 0xa0, 0x20, 0x09],
-        [0xaa, 0xa9, 0x29, 0x09],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0xa9, 0x55, 0x55, 0x59],
-        [0x11, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa1, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x25, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0xa2, 0x00, 0x86, 0x54],
-        [0xa6, 0xaa, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0x22, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x16, 0x5a, 0xa9, 0x55],
-        [0xa2, 0xaa, 0x28, 0x01],
-        [0xaa, 0xa9, 0x29, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x85],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x55, 0x55, 0x54],
-    ],
-    [
-        [0xa1, 0x00, 0x85, 0x54],
-        [0xaa, 0xa2, 0xaa, 0x65],
-        [0xaa, 0xaa, 0x21, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0xa5, 0x55, 0x55],
-        [0xa2, 0xaa, 0xa8, 0x01],
-        [0xaa, 0xa9, 0x69, 0x55],
-        [0xaa, 0xa5, 0x69, 0xa5],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x29, 0x45, 0x55, 0x54],
-    ],
-    [
-        [0xa1, 0x00, 0x85, 0x54],
-        [0x9a, 0x95, 0x55, 0x55],
-        [0xaa, 0x99, 0x21, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x16, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0xa2, 0xa9, 0x55, 0x55],
-        [0xaa, 0xa5, 0x69, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x2a, 0x65, 0x85, 0x54],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x54],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0x99, 0x61, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0xa5, 0x55, 0x55, 0x55],
-        [0xaa, 0x65, 0x55, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x2a, 0x65, 0x89, 0x94],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x50],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x9a, 0x99, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x85],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0x8a, 0xa8],
-    ],
-    [
-        [0x61, 0x00, 0x45, 0x10],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x95, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x96, 0xaa, 0xaa, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x51, 0x20, 0x41, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x99, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x16, 0x6a, 0x94, 0x65],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x50, 0x2a, 0x40, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x18, 0x2a, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x51, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x28, 0xa9, 0x00, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x28, 0x95, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x99, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x24, 0x95, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x99],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x99],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x9a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x14, 0x6a, 0x20, 0x01],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x95, 0x55, 0x55, 0x65],
-        [0x55, 0x14, 0x54, 0x56],
-        [0x41, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x5a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x14, 0x6a, 0x10, 0x01],
-        [0x55, 0x55, 0x55, 0x56],
-        [0x55, 0x55, 0x55, 0x95],
-        [0x55, 0x55, 0x55, 0x11],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x59, 0x55, 0x95, 0x55],
-        [0x55, 0x55, 0x55, 0x54],
-        [0x55, 0x55, 0x55, 0x15],
-        [0x6a, 0xaa, 0xaa, 0x99],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x18, 0x96, 0x10, 0x05],
-        [0x55, 0x55, 0x55, 0x19],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x55, 0x55, 0x55, 0x21],
-        [0xa9, 0x55, 0x56, 0xa5],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x66, 0xa9, 0x69, 0x54],
-        [0x55, 0x50, 0x14, 0x1a],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x46],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x18, 0x95, 0x10, 0x45],
-        [0x55, 0x55, 0x55, 0x15],
-        [0x55, 0x55, 0x55, 0x65],
-        [0x6a, 0x6a, 0x9a, 0x11],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x69, 0x55, 0x55, 0x59],
-        [0x51, 0x56, 0x16, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x18, 0x55, 0x10, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x65, 0x56, 0x54],
-        [0x55, 0x15, 0x45, 0x02],
-        [0x80, 0x00, 0x00, 0x0a],
-        [0x55, 0x55, 0x55, 0x16],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x15, 0x55, 0x15, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x59, 0x55, 0x55, 0x14],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x65, 0x45, 0x54, 0x0a],
-        [0x2a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0x15, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x80, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_10 = [ .phases = 44, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_10_data[0] ];
-const EPD_WP_ED047TC2_5_11_DATA: [[[u8; 4]; 16]; 57] = [
-    [
-        [0x20, 0x8a, 0x80, 0x00],
-        [0x15, 0x55, 0x5a, 0xa4],
-        [0x44, 0x10, 0x0a, 0x41],
-        [0x40, 0x00, 0x09, 0x54],
-        [0x10, 0x50, 0x00, 0x00],
-        [0x00, 0x00, 0x04, 0x28],
-        [0x40, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x20, 0x00],
-        [0x00, 0x00, 0x40, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x40, 0x00, 0x68],
-        [0x00, 0x00, 0x40, 0x00],
-        [0x00, 0x00, 0x0a, 0x54],
-        [0x00, 0x0a, 0x80, 0x00],
-        [0x10, 0x09, 0x60, 0x00],
-        [0x00, 0x00, 0x00, 0x50],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x0a, 0x60, 0x0a, 0xa8],
-        [0x54, 0x54, 0x8a, 0x41],
-        [0x41, 0x02, 0x09, 0x54],
-        [0x15, 0x50, 0x06, 0x24],
-        [0x00, 0x08, 0xa4, 0x28],
-        [0x44, 0x00, 0x09, 0xa8],
-        [0x05, 0x00, 0x2a, 0xa8],
-        [0x01, 0x10, 0x46, 0xa8],
-        [0x04, 0x00, 0x00, 0xa8],
-        [0x00, 0x40, 0x00, 0x54],
-        [0x04, 0x00, 0x44, 0xa8],
-        [0x00, 0x00, 0x8a, 0x54],
-        [0x00, 0x0a, 0x8a, 0x58],
-        [0x50, 0x19, 0x65, 0x54],
-        [0x00, 0x00, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x2a, 0x6a, 0xaa, 0xa8],
-        [0x55, 0x94, 0x89, 0x55],
-        [0x41, 0x02, 0x09, 0x54],
-        [0x15, 0x50, 0x66, 0x24],
-        [0x40, 0x04, 0xa4, 0x68],
-        [0x44, 0x0a, 0x09, 0x54],
-        [0x05, 0x08, 0x55, 0x54],
-        [0x11, 0x14, 0x56, 0x54],
-        [0x04, 0x40, 0x00, 0x58],
-        [0x01, 0x40, 0x0a, 0x54],
-        [0x04, 0x0a, 0x44, 0xa8],
-        [0x01, 0x00, 0x8a, 0x54],
-        [0x00, 0x0a, 0x6a, 0x54],
-        [0x50, 0x19, 0x65, 0x54],
-        [0x00, 0x00, 0x01, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x2a, 0x2a, 0xaa, 0xa8],
-        [0x96, 0x95, 0x55, 0x55],
-        [0x51, 0x0a, 0x05, 0x54],
-        [0x15, 0x50, 0x66, 0xa4],
-        [0x40, 0x04, 0x54, 0x54],
-        [0x44, 0x05, 0x09, 0x54],
-        [0x05, 0x18, 0x55, 0x54],
-        [0x51, 0x14, 0x56, 0x54],
-        [0x04, 0x40, 0x02, 0x54],
-        [0x01, 0x40, 0x8a, 0x54],
-        [0x04, 0x05, 0x44, 0x58],
-        [0x01, 0x0a, 0x85, 0x54],
-        [0x01, 0x05, 0x65, 0x54],
-        [0x54, 0x15, 0x55, 0x54],
-        [0x00, 0x00, 0x01, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x96, 0xa5, 0x55, 0x55],
-        [0x51, 0x09, 0x05, 0x55],
-        [0x15, 0x50, 0x65, 0x94],
-        [0x44, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x54],
-        [0x05, 0x56, 0x55, 0x54],
-        [0x51, 0x14, 0x55, 0x54],
-        [0x14, 0x40, 0x82, 0x54],
-        [0x01, 0x40, 0x8a, 0x54],
-        [0x04, 0x05, 0x44, 0x54],
-        [0x01, 0x0a, 0x45, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x54],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x9a, 0xa6, 0x55, 0x55],
-        [0x51, 0x49, 0x25, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x14, 0x55, 0x55],
-        [0x14, 0x40, 0x8a, 0x54],
-        [0x01, 0x40, 0x85, 0x54],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x0a, 0x65, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x6a, 0xaa, 0xaa, 0xa8],
-        [0x9a, 0xa6, 0x55, 0x55],
-        [0x51, 0x55, 0x25, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x95, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x14, 0x40, 0xa9, 0x54],
-        [0x01, 0x40, 0x45, 0x55],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x05, 0x65, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x50, 0x55, 0x54],
-        [0x54, 0x14, 0x54, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x14, 0x40, 0x69, 0x55],
-        [0x01, 0x40, 0x65, 0x55],
-        [0x04, 0x15, 0x44, 0x54],
-        [0x01, 0x05, 0x55, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x52, 0x55, 0x54],
-        [0x54, 0x14, 0x56, 0x54],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x40, 0x65, 0x55],
-        [0x01, 0x50, 0x65, 0x55],
-        [0x04, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x54, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x51, 0x55, 0x15, 0x55],
-        [0x15, 0x92, 0x55, 0x55],
-        [0x55, 0x14, 0x56, 0x54],
-        [0x54, 0x55, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x40, 0x55, 0x55],
-        [0x01, 0x50, 0x55, 0x55],
-        [0x05, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xa6, 0x65, 0x55],
-        [0x91, 0x55, 0x15, 0x55],
-        [0x16, 0x92, 0x55, 0x55],
-        [0x55, 0x14, 0x56, 0x55],
-        [0x54, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x48, 0x55, 0x55],
-        [0x01, 0x50, 0x55, 0x55],
-        [0x05, 0x15, 0x64, 0x54],
-        [0x01, 0x05, 0x55, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0x92, 0x55, 0x95, 0x55],
-        [0x6a, 0x91, 0x55, 0x55],
-        [0x55, 0x54, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x15, 0x55, 0x55],
-        [0x15, 0x48, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x05, 0x15, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa5, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x6a, 0x91, 0x55, 0x55],
-        [0x95, 0x54, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x4a, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa5, 0x15, 0x55, 0x55],
-        [0x00, 0x00, 0x05, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x4a, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x6a, 0xa1, 0x55, 0x55],
-        [0x95, 0x54, 0x55, 0x55],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x15, 0x46, 0x55, 0x55],
-        [0x01, 0x5a, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x11, 0x05, 0x55, 0x55],
-        [0x55, 0x05, 0x55, 0x55],
-        [0xa9, 0x15, 0x55, 0x55],
-        [0x00, 0x10, 0x45, 0x54],
-    ],
-    [
-        [0xaa, 0xa5, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0x65, 0x55],
-        [0xa6, 0x95, 0x56, 0x55],
-        [0x6a, 0xa5, 0x55, 0x55],
-        [0x99, 0x54, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x55],
-        [0x56, 0x55, 0x55, 0x55],
-        [0x15, 0x46, 0x55, 0x55],
-        [0x01, 0x55, 0x55, 0x55],
-        [0x15, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x56, 0x05, 0x55, 0x55],
-        [0xa9, 0x66, 0x55, 0x55],
-        [0x00, 0x10, 0x45, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xa6, 0x55],
-        [0xa6, 0xa5, 0x56, 0x55],
-        [0x6a, 0xa5, 0x55, 0x55],
-        [0xa9, 0x54, 0x55, 0x55],
-        [0x99, 0x55, 0x55, 0x55],
-        [0x5a, 0x55, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x15, 0x45, 0x55, 0x55],
-        [0x11, 0x55, 0x55, 0x55],
-        [0x19, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xa9, 0x66, 0x95, 0x55],
-        [0x00, 0x10, 0x55, 0x54],
-    ],
-    [
-        [0xaa, 0x95, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xa6, 0x96],
-        [0xa6, 0xa5, 0x56, 0x55],
-        [0x6a, 0xa5, 0x99, 0x55],
-        [0xaa, 0x68, 0x59, 0x55],
-        [0xa9, 0x55, 0x55, 0x55],
-        [0x5a, 0x95, 0x55, 0x55],
-        [0xa6, 0x55, 0x55, 0x55],
-        [0x19, 0x45, 0x55, 0x55],
-        [0x11, 0x95, 0x55, 0x55],
-        [0x19, 0x55, 0x54, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0x66, 0x15, 0x55, 0x55],
-        [0xaa, 0x66, 0x9a, 0x55],
-        [0x00, 0x14, 0x55, 0x54],
-    ],
-    [
-        [0x9a, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-        [0xaa, 0xaa, 0xaa, 0x96],
-        [0xaa, 0xa6, 0x56, 0x55],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0x68, 0x59, 0x55],
-        [0xa9, 0x65, 0x55, 0x55],
-        [0x5a, 0xa5, 0x55, 0x55],
-        [0xaa, 0x65, 0x55, 0x55],
-        [0x29, 0x85, 0x55, 0x55],
-        [0x12, 0x95, 0x55, 0x55],
-        [0x59, 0x55, 0x56, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xa6, 0x15, 0x55, 0x55],
-        [0xaa, 0x6a, 0x9a, 0x55],
-        [0x00, 0x14, 0x55, 0x54],
-    ],
-    [
-        [0x95, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x56, 0x55],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0xa8, 0x59, 0x55],
-        [0xa9, 0x65, 0x55, 0x55],
-        [0x9a, 0xa5, 0x55, 0x55],
-        [0xaa, 0x69, 0x95, 0x55],
-        [0x2a, 0x85, 0x55, 0x55],
-        [0x52, 0x95, 0x55, 0x55],
-        [0x5a, 0x55, 0x95, 0x55],
-        [0x56, 0x15, 0x55, 0x55],
-        [0xaa, 0x1a, 0x95, 0x55],
-        [0xaa, 0x6a, 0xaa, 0xa9],
-        [0x00, 0x15, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x56, 0xa9],
-        [0xaa, 0xa5, 0x99, 0x55],
-        [0xaa, 0xa8, 0xa9, 0x55],
-        [0xa9, 0xaa, 0x56, 0x55],
-        [0xaa, 0xa5, 0x95, 0x55],
-        [0xaa, 0x69, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x52, 0x95, 0x55, 0x55],
-        [0x5a, 0x6a, 0x99, 0x55],
-        [0x66, 0x15, 0x55, 0x55],
-        [0xaa, 0x1a, 0xaa, 0xa5],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x20, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x55, 0x8a, 0x00],
-        [0xaa, 0xaa, 0xa5, 0x5a],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xa5, 0x99, 0x59],
-        [0xaa, 0xa8, 0xa9, 0x55],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0x6a, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x5a, 0x6a, 0x99, 0x55],
-        [0xa6, 0x15, 0x55, 0xa9],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x5a, 0x8a, 0x00],
-        [0xaa, 0xaa, 0x55, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xa5, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0x95, 0x55, 0x55],
-        [0x6a, 0xaa, 0x99, 0x55],
-        [0xaa, 0x1a, 0x9a, 0xa9],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0x6a, 0x8a, 0x00],
-        [0xaa, 0x55, 0x5a, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xaa],
-        [0xaa, 0xa9, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x66, 0x55],
-        [0xaa, 0xaa, 0xaa, 0x55],
-        [0xaa, 0xaa, 0xa9, 0x55],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x56, 0xa5, 0x55, 0x95],
-        [0x6a, 0xaa, 0x99, 0x55],
-        [0xaa, 0x1a, 0xaa, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x26, 0x95, 0x55, 0x54],
-    ],
-    [
-        [0x55, 0xaa, 0x8a, 0x00],
-        [0xa9, 0x55, 0x5a, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x6a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0x95],
-        [0xaa, 0xaa, 0x6a, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x66, 0xa5, 0x55, 0xa9],
-        [0xaa, 0xaa, 0x99, 0x55],
-        [0xaa, 0x2a, 0xaa, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x12, 0x45, 0x55, 0x54],
-    ],
-    [
-        [0x6a, 0xaa, 0x8a, 0x00],
-        [0xa5, 0x55, 0xa5, 0x56],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0x59],
-        [0xaa, 0xa9, 0xa9, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x6a, 0x95, 0x55, 0xa5],
-        [0xa6, 0xa5, 0x9a, 0xa9],
-        [0xaa, 0xaa, 0x99, 0xa5],
-        [0xaa, 0x2a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x41, 0x55, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x8a, 0x00],
-        [0x95, 0x6a, 0xa5, 0x56],
-        [0xaa, 0x69, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa9],
-        [0xaa, 0xa9, 0xa9, 0xa9],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x95, 0x96, 0xa9],
-        [0xa6, 0xa5, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0x99, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x40, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x85, 0x00],
-        [0x55, 0x6a, 0xa5, 0x56],
-        [0xa9, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xa9, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x95, 0xaa, 0xaa],
-        [0xaa, 0xa5, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xa9],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x11, 0x40, 0x00, 0x54],
-    ],
-    [
-        [0xaa, 0xaa, 0x45, 0x00],
-        [0x5a, 0x6a, 0x55, 0x56],
-        [0xa9, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x99, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x01, 0x40, 0x02, 0xa0],
-    ],
-    [
-        [0xaa, 0xa5, 0x45, 0x00],
-        [0x6a, 0x55, 0x55, 0x56],
-        [0x69, 0x59, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xa9, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x40, 0x02, 0xa8],
-    ],
-    [
-        [0xaa, 0x55, 0x45, 0x00],
-        [0x6a, 0x95, 0x55, 0x55],
-        [0x65, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0xa5, 0x55, 0x45, 0x00],
-        [0x66, 0x95, 0x55, 0x55],
-        [0x65, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x55, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x5a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x99, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x59, 0x55, 0x69],
-        [0x69, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x5a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x95, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x6a, 0xaa, 0xaa],
-        [0xa9, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x45, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x6a, 0x5a, 0xa9, 0xaa],
-        [0xa5, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x0a, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x40, 0x00],
-        [0x95, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0xa9, 0xaa],
-        [0x95, 0x6a, 0x66, 0xaa],
-        [0xaa, 0x6a, 0xaa, 0xaa],
-        [0xaa, 0x9a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x8a, 0xa8],
-    ],
-    [
-        [0x55, 0x50, 0x00, 0x80],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0x99, 0xaa],
-        [0x55, 0x46, 0x66, 0xaa],
-        [0xa8, 0x6a, 0x9a, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x01, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x20, 0x8a, 0xa8],
-    ],
-    [
-        [0x50, 0x00, 0x00, 0x80],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x94, 0x95, 0x95, 0x56],
-        [0x55, 0x45, 0x55, 0xa6],
-        [0x65, 0x96, 0x96, 0xaa],
-        [0x26, 0x8a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x02, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x08, 0x28, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x60],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x45, 0x55, 0xa6],
-        [0x55, 0x96, 0x66, 0xaa],
-        [0x2a, 0x4a, 0xaa, 0xaa],
-        [0xaa, 0x8a, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x88, 0x2a, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x20, 0x50],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x95, 0x66, 0x6a],
-        [0x12, 0x86, 0xaa, 0xaa],
-        [0x85, 0x4a, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xa9, 0xaa, 0xaa, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xa8, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x61, 0x09, 0x55, 0x55],
-        [0x45, 0x55, 0x55, 0xaa],
-        [0xa8, 0xaa, 0xaa, 0xaa],
-        [0x98, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x10, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x45, 0x99, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x56, 0xaa],
-        [0x94, 0x6a, 0xaa, 0xaa],
-        [0x56, 0x65, 0x55, 0x55],
-        [0xa6, 0x55, 0x66, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x00, 0x25, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x68, 0x95, 0x55, 0x55],
-        [0xa9, 0x8a, 0xaa, 0xaa],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x00, 0x05, 0x65, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xa4, 0x6a, 0xaa, 0xaa],
-        [0x02, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x05, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x01, 0x50, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xa8],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x55, 0x55, 0x55, 0x55],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0xaa, 0xaa, 0xaa, 0xaa],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-    [
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-        [0x00, 0x00, 0x00, 0x00],
-    ],
-];
-//const EpdWaveformPhases epd_wp_ED047TC2_5_11 = [ .phases = 57, .phase_times = NULL, .luts = (const uint8_t*)&epd_wp_ED047TC2_5_11_data[0] ];
-//const EpdWaveformPhases* epd_wm_ed047tc2_5_ranges[7] = [ &epd_wp_ED047TC2_5_5,&epd_wp_ED047TC2_5_6,&epd_wp_ED047TC2_5_7,&epd_wp_ED047TC2_5_8,&epd_wp_ED047TC2_5_9,&epd_wp_ED047TC2_5_10,&epd_wp_ED047TC2_5_11 ];
-//const EpdWaveformMode epd_wm_ed047tc2_5 = [ .type = 5, .temp_ranges = 7, .range_data = &epd_wm_ed047tc2_5_ranges[0] ];
-//const EpdWaveformTempInterval ed047tc2_intervals[14] = [ [ .min = 0, .max = 3 ],[ .min = 3, .max = 6 ],[ .min = 6, .max = 9 ],[ .min = 9, .max = 12 ],[ .min = 12, .max = 15 ],[ .min = 15, .max = 18 ],[ .min = 18, .max = 21 ],[ .min = 21, .max = 24 ],[ .min = 24, .max = 27 ],[ .min = 27, .max = 30 ],[ .min = 30, .max = 33 ],[ .min = 33, .max = 38 ],[ .min = 38, .max = 43 ],[ .min = 43, .max = 48 ] ];
-//const EpdWaveformMode* ed047tc2_modes[3] = [ &epd_wm_ed047tc2_1,&epd_wm_ed047tc2_2,&epd_wm_ed047tc2_5 ];
-//const EpdWaveform epdiy_ED047TC2 = [ .num_modes = 3, .num_temp_ranges = 7, .mode_data = &ed047tc2_modes[0], .temp_intervals = &ed047tc2_intervals[0] ];
+use crate::{config::MAX_PULSE, Error, Result};
+
+#[cfg(any(
+    feature = "waveform-du",
+    feature = "waveform-gc16",
+    feature = "waveform-gl16"
+))]
+mod ed047tc2;
+
+const MAGIC: &[u8; 4] = b"EPW4";
+const VERSION: u8 = 1;
+const HEADER_SIZE: usize = 8;
+const RANGE_HEADER_SIZE: usize = 4;
+/// Size of the lookup table of a single phase.
+pub const PHASE_SIZE: usize = 64;
+/// Largest number of phases of a temperature range, the panel would be
+/// driven for seconds by longer waveforms.
+pub const MAX_PHASES: usize = 128;
+/// Flag of a temperature range with phase times.
+const FLAG_PHASE_TIMES: u8 = 1 << 0;
+
+/// Output time of a phase without a phase time (in ticks of 0.1µs).
+pub const DEFAULT_PHASE_TIME: u16 = 50;
+/// Temperature assumed if the board has no temperature sensor (°C).
+pub const DEFAULT_TEMPERATURE: i8 = 20;
+
+/// What happens to a pixel during a phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// The pixel is left alone.
+    None,
+    /// The pixel is darkened.
+    Darken,
+    /// The pixel is lightened.
+    Lighten,
+}
+
+/// A temperature range in °C, the upper bound is exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TemperatureRange {
+    pub min: i8,
+    pub max: i8,
+}
+
+impl TemperatureRange {
+    /// Whether the temperature is within the range.
+    pub fn contains(&self, temperature: i8) -> bool {
+        (self.min..self.max).contains(&temperature)
+    }
+
+    /// Distance of the temperature to the range.
+    fn distance(&self, temperature: i8) -> i16 {
+        if temperature < self.min {
+            self.min as i16 - temperature as i16
+        } else if temperature >= self.max {
+            temperature as i16 - self.max as i16 + 1
+        } else {
+            0
+        }
+    }
+}
+
+/// The phases of a waveform for a single temperature range.
+#[derive(Clone, Copy, Debug)]
+pub struct Phases<'a> {
+    luts: &'a [u8],
+    /// Phase times (`u16`), empty if the phases have no times.
+    times: &'a [u8],
+}
+
+impl<'a> Phases<'a> {
+    /// Number of phases.
+    pub fn len(&self) -> usize {
+        self.luts.len() / PHASE_SIZE
+    }
+
+    /// Whether there are no phases, never true for a valid waveform.
+    pub fn is_empty(&self) -> bool {
+        self.luts.is_empty()
+    }
+
+    /// What happens to a pixel at level `from` with the target level `to`
+    /// during the phase.
+    pub fn action(&self, phase: usize, from: u8, to: u8) -> Action {
+        match self.code(phase, from, to) {
+            1 => Action::Darken,
+            2 => Action::Lighten,
+            _ => Action::None,
+        }
+    }
+
+    /// Output time of the phase (in ticks of 0.1µs), `None` if the phases
+    /// have no times.
+    pub fn time(&self, phase: usize) -> Option<u16> {
+        self.times
+            .get(phase * 2..phase * 2 + 2)
+            .map(|time| u16::from_le_bytes([time[0], time[1]]))
+    }
+
+    /// The 2 bit output code of a pixel.
+    fn code(&self, phase: usize, from: u8, to: u8) -> u8 {
+        let (from, to) = ((from & 0x0F) as usize, (to & 0x0F) as usize);
+        let byte = self.luts[phase * PHASE_SIZE + to * 4 + from / 4];
+        (byte >> (6 - 2 * (from % 4))) & 0b11
+    }
+
+    /// Output codes of the phase indexed by `to << 4 | from`.
+    pub(crate) fn lut(&self, phase: usize) -> [u8; 256] {
+        let mut lut = [0u8; 256];
+        for (index, code) in lut.iter_mut().enumerate() {
+            *code = self.code(phase, index as u8 & 0x0F, (index >> 4) as u8);
+        }
+        lut
+    }
+}
+
+/// Lookup tables of a built-in mode.
+#[cfg(any(
+    feature = "waveform-du",
+    feature = "waveform-gc16",
+    feature = "waveform-gl16"
+))]
+struct Table {
+    /// Number of phases of each temperature range.
+    phases: &'static [u8],
+    /// Lookup tables of all temperature ranges back to back, in the layout of
+    /// the binary format.
+    luts: &'static [u8],
+}
+
+#[cfg(any(
+    feature = "waveform-du",
+    feature = "waveform-gc16",
+    feature = "waveform-gl16"
+))]
+impl Table {
+    /// The phases of each temperature range.
+    fn ranges(&self) -> impl Iterator<Item = Phases<'static>> + '_ {
+        let luts = self.luts;
+        self.phases.iter().scan(0, move |start, &count| {
+            let end = *start + count as usize * PHASE_SIZE;
+            let phases = Phases {
+                luts: &luts[*start..end],
+                times: &[],
+            };
+            *start = end;
+            Some(phases)
+        })
+    }
+}
+
+/// Modes of the built-in ED047TC2 waveform, each one is gated by a cargo
+/// feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Fast black and white updates (epdiy mode 1).
+    #[cfg(feature = "waveform-du")]
+    Du,
+    /// Flashing 16 level grayscale updates (epdiy mode 2).
+    #[cfg(feature = "waveform-gc16")]
+    Gc16,
+    /// Non-flashing 16 level grayscale updates for text on white
+    /// (epdiy mode 5).
+    #[cfg(feature = "waveform-gl16")]
+    Gl16,
+}
+
+/// A waveform, one set of phases per temperature range.
+#[derive(Clone, Debug)]
+pub struct Waveform<'a> {
+    ranges: Vec<(TemperatureRange, Phases<'a>)>,
+}
+
+#[cfg(any(
+    feature = "waveform-du",
+    feature = "waveform-gc16",
+    feature = "waveform-gl16"
+))]
+impl Waveform<'static> {
+    /// The built-in ED047TC2 waveform of the mode.
+    pub fn ed047tc2(mode: Mode) -> Self {
+        let table = match mode {
+            #[cfg(feature = "waveform-du")]
+            Mode::Du => &ed047tc2::DU,
+            #[cfg(feature = "waveform-gc16")]
+            Mode::Gc16 => &ed047tc2::GC16,
+            #[cfg(feature = "waveform-gl16")]
+            Mode::Gl16 => &ed047tc2::GL16,
+        };
+        Waveform {
+            ranges: ed047tc2::TEMPERATURE_RANGES
+                .iter()
+                .copied()
+                .zip(table.ranges())
+                .collect(),
+        }
+    }
+}
+
+impl<'a> Waveform<'a> {
+    /// Load a waveform from its binary representation.
+    ///
+    /// Returns [`Error::InvalidFormat`] if the data is malformed, e.g. it is
+    /// truncated, the temperature ranges overlap or a lookup table contains
+    /// an invalid code.
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE || &data[0..4] != MAGIC || data[4] != VERSION {
+            return Err(Error::InvalidFormat);
+        }
+        let range_count = data[5] as usize;
+        if range_count == 0 {
+            return Err(Error::InvalidFormat);
+        }
+        let mut ranges = Vec::with_capacity(range_count);
+        let mut offset = HEADER_SIZE;
+        let mut previous: Option<TemperatureRange> = None;
+        for _ in 0..range_count {
+            let header = data
+                .get(offset..offset + RANGE_HEADER_SIZE)
+                .ok_or(Error::InvalidFormat)?;
+            let range = TemperatureRange {
+                min: header[0] as i8,
+                max: header[1] as i8,
+            };
+            let phase_count = header[2] as usize;
+            let flags = header[3];
+            if range.min >= range.max
+                || previous.is_some_and(|previous| range.min < previous.max)
+                || !(1..=MAX_PHASES).contains(&phase_count)
+                || flags & !FLAG_PHASE_TIMES != 0
+            {
+                return Err(Error::InvalidFormat);
+            }
+            offset += RANGE_HEADER_SIZE;
+
+            let luts = data
+                .get(offset..offset + phase_count * PHASE_SIZE)
+                .ok_or(Error::InvalidFormat)?;
+            // 3 is not a valid code
+            if luts
+                .iter()
+                .any(|&byte| (0..4).any(|i| (byte >> (2 * i)) & 0b11 == 0b11))
+            {
+                return Err(Error::InvalidFormat);
+            }
+            offset += luts.len();
+
+            let times: &[u8] = if flags & FLAG_PHASE_TIMES != 0 {
+                let times = data
+                    .get(offset..offset + phase_count * 2)
+                    .ok_or(Error::InvalidFormat)?;
+                offset += times.len();
+                times
+            } else {
+                &[]
+            };
+            let phases = Phases { luts, times };
+            if (0..phase_count)
+                .filter_map(|phase| phases.time(phase))
+                .any(|time| time == 0 || time > MAX_PULSE)
+            {
+                return Err(Error::InvalidFormat);
+            }
+            ranges.push((range, phases));
+            previous = Some(range);
+        }
+        if offset != data.len() {
+            return Err(Error::InvalidFormat);
+        }
+        Ok(Waveform { ranges })
+    }
+
+    /// The binary representation of the waveform, see the
+    /// [module documentation](self).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(MAGIC);
+        data.extend_from_slice(&[VERSION, self.ranges.len() as u8, 0, 0]);
+        for (range, phases) in &self.ranges {
+            let flags = match phases.times.is_empty() {
+                true => 0,
+                false => FLAG_PHASE_TIMES,
+            };
+            data.extend_from_slice(&[range.min as u8, range.max as u8, phases.len() as u8, flags]);
+            data.extend_from_slice(phases.luts);
+            data.extend_from_slice(phases.times);
+        }
+        data
+    }
+
+    /// The temperature ranges and their phases.
+    pub fn ranges(&self) -> impl Iterator<Item = (TemperatureRange, Phases<'a>)> + '_ {
+        self.ranges.iter().copied()
+    }
+
+    /// The phases for the temperature. Outside of all ranges, the closest
+    /// range is used.
+    pub fn phases(&self, temperature: i8) -> Phases<'a> {
+        self.ranges
+            .iter()
+            .min_by_key(|(range, _)| range.distance(temperature))
+            .map(|&(_, phases)| phases)
+            .unwrap_or(Phases {
+                luts: &[],
+                times: &[],
+            })
+    }
+}
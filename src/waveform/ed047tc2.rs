@@ -0,0 +1,3064 @@
+//! Waveforms of the ED047TC2 panel, taken from [epdiy].
+//!
+//! Generated from `waveforms/ed047tc2.json` by `tools/waveformgen`.
+//! Don't edit by hand.
+//!
+//! [epdiy]: https://github.com/vroland/epdiy
+
+use super::{Table, TemperatureRange};
+
+/// Temperature ranges of the phases of each mode.
+pub(super) const TEMPERATURE_RANGES: [TemperatureRange; 7] = [
+    TemperatureRange { min: 15, max: 18 },
+    TemperatureRange { min: 18, max: 21 },
+    TemperatureRange { min: 21, max: 24 },
+    TemperatureRange { min: 24, max: 27 },
+    TemperatureRange { min: 27, max: 30 },
+    TemperatureRange { min: 30, max: 33 },
+    TemperatureRange { min: 33, max: 38 },
+];
+
+/// Phases of the DU (epdiy mode 1) mode for each temperature range.
+#[cfg(feature = "waveform-du")]
+pub(super) static DU: Table = Table {
+    phases: &[25, 22, 22, 22, 18, 17, 15],
+    luts: &DU_LUTS,
+};
+
+#[cfg(feature = "waveform-du")]
+static DU_LUTS: [u8; 9024] = [
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa8, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa0, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x00, 0x01, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x80,
+    0x00, 0x00, 0x15, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa0,
+    0x00, 0x01, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x84, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x84, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa8, 0x00, 0x04,
+    0x00, 0x00, 0x01, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa0, 0x04,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa0, 0x04,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x18,
+    0x00, 0x01, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x18,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x98,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa0, 0x00,
+    0x00, 0x00, 0x01, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x00,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x00,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x80,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x80,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x80,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x90,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa0,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa8, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x04,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x04,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x04,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x88,
+    0x00, 0x01, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x88,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x88,
+    0x00, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x88,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x01, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa8, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x00, 0x00,
+    0x00, 0x00, 0x15, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x80, 0x00,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x01, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x00,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x04,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x84,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa4, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa8, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x00,
+    0x00, 0x00, 0x01, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x00,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x00, 0x00,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa0, 0x40,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x40,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0x90,
+    0x00, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa0,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa4,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x54,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xa0, 0x00, 0x00, 0x54,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x00, 0x00, 0x54,
+    0x00, 0x00, 0x00, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x80, 0x00, 0x54,
+    0x00, 0x00, 0x05, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x90, 0x00, 0x54,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x54,
+    0x00, 0x00, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xa0, 0x00, 0x54,
+    0x00, 0x05, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x40, 0xa8,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x85, 0xa8,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x15, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x05, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// Phases of the GC16 (epdiy mode 2) mode for each temperature range.
+#[cfg(feature = "waveform-gc16")]
+pub(super) static GC16: Table = Table {
+    phases: &[46, 43, 40, 38, 38, 44, 57],
+    luts: &GC16_LUTS,
+};
+
+#[cfg(feature = "waveform-gc16")]
+static GC16_LUTS: [u8; 19584] = [
+    0x00, 0x00, 0x00, 0x00, 0x20, 0x88, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x00, 0x02, 0x00, 0x80,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa2, 0x88, 0x20, 0x88, 0x22, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xa2, 0x08,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x44, 0x08, 0x00, 0xa0, 0x02, 0x20, 0xa8,
+    0x08, 0x62, 0x88, 0x80, 0x00, 0x00, 0x00, 0x08, 0x08, 0x22, 0x88, 0x80, 0x00, 0x80, 0x00, 0x00,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x80, 0x82, 0xa2, 0x08,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x02, 0x84, 0x84, 0x28, 0x80, 0xa0, 0x02, 0x20, 0xaa,
+    0xa8, 0xaa, 0x8a, 0x80, 0x22, 0x84, 0x00, 0x28, 0x2a, 0xaa, 0xaa, 0xa0, 0x00, 0x80, 0x28, 0x00,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x08, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x40, 0x20, 0x00, 0x80, 0xa2, 0xa2, 0x88,
+    0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x00, 0x02, 0x84, 0x88, 0x2a, 0xa2, 0xa0, 0xaa, 0xa8, 0xa8,
+    0xa8, 0xaa, 0x8a, 0x80, 0x2a, 0xaa, 0x08, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x80, 0x28, 0x08,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x28, 0x24, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x29, 0x80, 0x00, 0x00, 0x00, 0x40, 0x20, 0x00, 0x80, 0xa2, 0xa2, 0x88,
+    0x00, 0x02, 0xa0, 0x00, 0x02, 0x00, 0x00, 0x81, 0x88, 0xa8, 0xaa, 0xa9, 0xa2, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0x8a, 0x81, 0x2a, 0xaa, 0x2a, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x80, 0x28, 0x04,
+    0xaa, 0xaa, 0xa8, 0xa4, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x28, 0xa8, 0x80, 0x00,
+    0x00, 0x20, 0x00, 0x00, 0x2a, 0x84, 0xa0, 0x08, 0x2a, 0x68, 0x20, 0x80, 0x80, 0xa2, 0xa2, 0xa8,
+    0x80, 0x02, 0xa0, 0x01, 0x02, 0x80, 0x00, 0xa9, 0x9a, 0xa8, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0x89, 0x2a, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x8a, 0x28, 0x04,
+    0xaa, 0xaa, 0xa8, 0x64, 0x2a, 0xaa, 0xaa, 0x01, 0x00, 0x88, 0x02, 0x00, 0x29, 0xa8, 0x80, 0x00,
+    0x00, 0x28, 0x08, 0x02, 0x2a, 0x88, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa0, 0xaa, 0xa2, 0xa2, 0xa9,
+    0x80, 0x02, 0xa0, 0x01, 0x02, 0xa0, 0x00, 0xa9, 0x9a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0x2a, 0x8a, 0xa8, 0x84,
+    0xaa, 0xaa, 0xa8, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0x00, 0x8a, 0x02, 0x08, 0x2a, 0xa8, 0x88, 0x00,
+    0x00, 0x28, 0x8a, 0x01, 0x2a, 0x88, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa0, 0xaa, 0xaa, 0xaa, 0xa9,
+    0x80, 0x02, 0xa0, 0x01, 0x0a, 0xaa, 0x80, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x21,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0x6a, 0x8a, 0xaa, 0x56,
+    0xaa, 0xaa, 0xa8, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0x20, 0x8a, 0x0a, 0x08, 0x2a, 0xa8, 0xaa, 0x00,
+    0x00, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x80, 0x02, 0xa0, 0x81, 0x2a, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x59, 0x69, 0x8a, 0xaa, 0x56,
+    0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0xa0, 0x8a, 0x0a, 0x0a, 0xaa, 0xaa, 0xaa, 0x02,
+    0x08, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xaa, 0x09, 0x2a, 0xaa, 0xa0, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x82, 0x82, 0xa0, 0xa1, 0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0x8a, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0xa2, 0x8a, 0x2a, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x28, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xaa, 0x89, 0x2a, 0xaa, 0xa8, 0xa1, 0xaa, 0xaa, 0xaa, 0x25,
+    0xaa, 0xaa, 0xa0, 0xa9, 0x2a, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x58, 0x2a, 0xaa, 0xa5, 0x05, 0xaa, 0x8a, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0x01, 0x2a, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x01, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0x96, 0xa8, 0xaa, 0xaa, 0x55, 0x09, 0xaa, 0x8a, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0x21, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x51, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0x98, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa4, 0x55, 0xaa, 0xa8, 0x8a, 0x55,
+    0xaa, 0xaa, 0xa5, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x54, 0xaa, 0xaa, 0x55,
+    0xaa, 0xa5, 0x96, 0xa8, 0xaa, 0xa5, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0xaa, 0xa9, 0x90, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa6, 0x99, 0x55, 0x55, 0x94, 0xaa, 0xa6, 0x51,
+    0xaa, 0xa5, 0x56, 0xa8, 0xaa, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x05, 0xaa, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x98, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0xaa, 0xa9, 0x15, 0x55,
+    0x92, 0x95, 0x65, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x95, 0x95, 0x55, 0x55, 0x94, 0xaa, 0x96, 0x01,
+    0xa9, 0x55, 0x56, 0xa8, 0xa9, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x05, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0x98, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x55, 0x55, 0x9a, 0x85, 0x55, 0x55,
+    0x94, 0x95, 0x55, 0x55, 0x99, 0xa9, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x94, 0xaa, 0x96, 0x01,
+    0x95, 0x55, 0x56, 0xa8, 0xa5, 0x55, 0x5a, 0xa5, 0xaa, 0xa6, 0xaa, 0x05, 0xaa, 0xaa, 0x6a, 0xa5,
+    0x2a, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x96, 0x65, 0xaa, 0xaa, 0x1a, 0x59, 0xaa, 0x59, 0x51, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa8, 0x55, 0x55, 0x55, 0x98, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x94, 0x6a, 0x96, 0x01,
+    0x95, 0x55, 0x55, 0x94, 0x95, 0x55, 0xaa, 0x95, 0xaa, 0xa6, 0xaa, 0x05, 0xaa, 0xaa, 0x6a, 0x95,
+    0x2a, 0xaa, 0xa5, 0x55, 0x9a, 0x6a, 0x55, 0x55, 0xaa, 0x85, 0x5a, 0x55, 0xaa, 0x59, 0x55, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xa8, 0xaa, 0x00, 0x55, 0xa8, 0x55, 0x55, 0x55, 0x91, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x90, 0x69, 0x95, 0x01,
+    0x55, 0x55, 0x55, 0x54, 0x96, 0x5a, 0xaa, 0x15, 0xaa, 0xa6, 0xa9, 0x05, 0xaa, 0x9a, 0x65, 0x55,
+    0x2a, 0x96, 0x65, 0x55, 0x96, 0x65, 0x55, 0x55, 0xa1, 0x95, 0x56, 0x55, 0x28, 0x55, 0x55, 0x55,
+    0xaa, 0xa9, 0x52, 0x55, 0xa9, 0x01, 0x05, 0x55, 0x25, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x01,
+    0x55, 0x55, 0x69, 0x55, 0x9a, 0xaa, 0xaa, 0x55, 0xaa, 0xa6, 0xa5, 0x85, 0x9a, 0x56, 0x55, 0x55,
+    0xaa, 0x95, 0x65, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x56, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0xa8, 0xa1, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x01,
+    0x55, 0x5a, 0xa9, 0x55, 0x9a, 0xaa, 0xaa, 0x55, 0xaa, 0xa5, 0xa5, 0x85, 0x92, 0x55, 0x55, 0x55,
+    0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x15, 0x56, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0xa8, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x01,
+    0x56, 0xaa, 0xa9, 0x55, 0x99, 0xaa, 0xa1, 0x55, 0xaa, 0x65, 0x95, 0x45, 0x95, 0x55, 0x55, 0x55,
+    0xa6, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x05, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa0, 0x55, 0x55, 0x01,
+    0x6a, 0xaa, 0xaa, 0x55, 0x95, 0xaa, 0x15, 0x55, 0x9a, 0x65, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa0, 0x55, 0x55, 0x01,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0xa5, 0x55, 0x55, 0x99, 0x65, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa8, 0x55, 0x55, 0x01,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x01,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x41, 0x01,
+    0xaa, 0xaa, 0x56, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x65, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x41, 0x01,
+    0xa9, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x68, 0x55, 0x55, 0x96, 0x65, 0x55, 0x55, 0x56, 0x6a, 0x65, 0x55, 0xa6, 0xaa, 0x94, 0x49, 0x02,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x6a, 0x2a, 0x8a, 0xaa, 0x6a, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x15, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x56, 0x65, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x65, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0xaa, 0x55, 0x54, 0x45, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0xa6, 0x50,
+    0x55, 0x56, 0x95, 0x56, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x95, 0x04, 0x45, 0x05, 0x55, 0xaa,
+    0x55, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x55, 0x56, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x54, 0x45, 0x48, 0x58, 0x50, 0x55, 0x55, 0x41, 0x06, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x54, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65,
+    0x55, 0x55, 0x55, 0x54, 0x41, 0x04, 0x44, 0x12, 0x6a, 0x2a, 0x21, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x54, 0x45, 0x05, 0x15, 0x16,
+    0x41, 0x41, 0x15, 0x02, 0x6a, 0x00, 0xa0, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x66, 0x55, 0x59, 0x59, 0xa6, 0x60, 0x00, 0x00, 0x0a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x6a, 0x59, 0x51, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x52, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0x55, 0x55, 0x02, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xa5, 0x96, 0xa6, 0x5a, 0x80, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xaa, 0x00, 0x00, 0x20, 0x22, 0xa8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x22, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x22, 0xa8, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x02, 0x08, 0x80, 0x00, 0x80, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2a, 0xaa, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+    0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x22, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x22, 0x02, 0x08, 0x80, 0x00, 0xa0, 0x20, 0x2a, 0x28, 0x22, 0xa8, 0xa8, 0x28, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x28,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0xa0, 0x00, 0x00, 0x00, 0x00,
+    0x22, 0x02, 0x08, 0x80, 0x00, 0xa8, 0x22, 0x2a, 0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x80,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0xaa, 0x80,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0xa0, 0x00, 0x00, 0x00, 0x00,
+    0xa2, 0x82, 0x08, 0x80, 0x08, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x42,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x02, 0x00, 0x88, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x00, 0x00, 0x02, 0xaa, 0x2a, 0xaa, 0x80,
+    0x80, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0xaa, 0xa8, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0x82, 0x88, 0xa0, 0x8a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0x94, 0x24, 0x00, 0x28, 0x42,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x02, 0x20, 0x88, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x20, 0x80, 0x00, 0x02, 0xaa, 0x2a, 0xaa, 0x80,
+    0x80, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x2a, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x00,
+    0xaa, 0x8a, 0x8a, 0xa0, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0x24, 0x00, 0x28, 0x42,
+    0xaa, 0xaa, 0xa0, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x22, 0x20, 0x88, 0x00, 0x2a,
+    0x22, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0xa0, 0xa0, 0xa8, 0x02, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x2a, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa5, 0xa6, 0xaa, 0xaa, 0x95, 0x24, 0x00, 0x26, 0x41,
+    0xaa, 0xaa, 0xa0, 0x00, 0x20, 0xaa, 0xaa, 0x42, 0x80, 0x00, 0x00, 0x22, 0x20, 0x88, 0x00, 0x2a,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0x80, 0x20, 0x28, 0xa2, 0xa8, 0xaa, 0x02, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x01, 0x20, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x20,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0xa6, 0xaa, 0xa6, 0x55, 0x56, 0x02, 0x95, 0x55,
+    0xaa, 0xaa, 0xa0, 0x04, 0x20, 0xaa, 0xa5, 0x4a, 0x80, 0x00, 0x00, 0x29, 0xa0, 0x88, 0x00, 0x26,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0x80, 0x20, 0x28, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x01, 0x20, 0x80, 0x00, 0xa2, 0xaa, 0xaa, 0xaa, 0x98, 0x88, 0x80, 0x00, 0xa2,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xa5, 0xa9, 0x65, 0x55, 0x56, 0x0a, 0x95, 0x55,
+    0xaa, 0xa9, 0xa0, 0x24, 0x20, 0xa9, 0x55, 0x4a, 0x80, 0x00, 0x00, 0x29, 0xa0, 0x88, 0x00, 0x25,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0xa0, 0x28, 0x28, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0x2a, 0xaa, 0x80,
+    0xa0, 0x00, 0x00, 0x01, 0x20, 0xa2, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0x94, 0x88, 0xa2, 0xa8, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xa5, 0x95, 0x55, 0x55, 0x56, 0x0a, 0x95, 0x55,
+    0xaa, 0x95, 0xa8, 0xa4, 0x20, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x29, 0xa2, 0x88, 0x00, 0x15,
+    0x2a, 0x80, 0x00, 0x01, 0x20, 0xa8, 0x28, 0x14, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x81,
+    0xa0, 0x00, 0x00, 0x01, 0xa2, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x94, 0x88, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0x95, 0x55, 0x55, 0x55, 0x56, 0x89, 0x55, 0x55,
+    0xaa, 0x95, 0x98, 0x94, 0xa0, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x25, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x00, 0x21, 0x20, 0xaa, 0xa8, 0x94, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0xa1,
+    0xa0, 0x22, 0xa0, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x94, 0xa8, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xa9, 0xa6, 0x55, 0xaa, 0xaa, 0xa9, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x15,
+    0xa9, 0x55, 0x9a, 0x98, 0xa8, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x00, 0x21, 0xa2, 0xaa, 0xaa, 0x94, 0xaa, 0xaa, 0xaa, 0x89, 0xaa, 0xaa, 0xa9, 0xa1,
+    0xa2, 0x2a, 0xa0, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x56, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xa9, 0xa6, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x15,
+    0xa5, 0x55, 0x5a, 0xa8, 0xa8, 0x95, 0x55, 0x45, 0xa0, 0x82, 0x20, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x20, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x85, 0x69,
+    0xaa, 0x2a, 0xa8, 0x29, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x95,
+    0xa9, 0xa9, 0xa5, 0x55, 0xa6, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0xa5, 0x41, 0x15,
+    0x95, 0x55, 0x6a, 0x68, 0xa8, 0x55, 0x55, 0x85, 0xa8, 0xaa, 0xa0, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x20, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xa9, 0x55, 0x69,
+    0xaa, 0x2a, 0xaa, 0x15, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x95,
+    0x99, 0xa9, 0x65, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x65, 0x40, 0x15,
+    0x95, 0x55, 0x66, 0x58, 0xa8, 0x55, 0x5a, 0x85, 0xa8, 0xaa, 0xa0, 0x15, 0xaa, 0xa8, 0xa0, 0x15,
+    0xaa, 0x62, 0xa8, 0x19, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x95, 0xaa, 0x95, 0x55, 0x55,
+    0xaa, 0x2a, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0xa9, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0x95, 0x65, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x91, 0x55, 0x40, 0x15,
+    0x95, 0x56, 0xa5, 0x94, 0xa8, 0x56, 0xaa, 0x85, 0xa8, 0xaa, 0xa0, 0x95, 0xaa, 0xa8, 0xa0, 0x15,
+    0xaa, 0x6a, 0xaa, 0x15, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x55, 0x55, 0x89, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x55, 0x99, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x50, 0x00, 0x15,
+    0x55, 0x6a, 0x89, 0x94, 0x98, 0x6a, 0xaa, 0x85, 0xaa, 0xaa, 0xa0, 0x95, 0xaa, 0xa6, 0xa0, 0x15,
+    0x8a, 0x6a, 0xaa, 0x95, 0xaa, 0xaa, 0x96, 0x55, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0xa6, 0xaa, 0xa5, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x89, 0x50, 0x00, 0x15,
+    0x56, 0xaa, 0x9a, 0x14, 0x98, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x66, 0xa2, 0x15,
+    0x99, 0x6a, 0xaa, 0x95, 0xaa, 0x6a, 0x95, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0xa6, 0xa9, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0xa9, 0x10, 0x00, 0x05,
+    0x5a, 0xaa, 0x12, 0x54, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x66, 0xaa, 0x15,
+    0x95, 0x6a, 0xaa, 0x95, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55, 0xa9, 0x10, 0x00, 0x05,
+    0x6a, 0xaa, 0x55, 0x54, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0x9a, 0x66, 0xaa, 0x15,
+    0x95, 0x6a, 0xaa, 0x95, 0x9a, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x8a, 0x55, 0x55, 0x55, 0xaa, 0x00, 0x00, 0x01,
+    0x6a, 0xaa, 0x55, 0x55, 0x9a, 0x96, 0xaa, 0x41, 0x6a, 0xaa, 0xaa, 0x55, 0x99, 0x66, 0xaa, 0x95,
+    0x95, 0x6a, 0x95, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x59, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0x99, 0x65, 0xaa, 0x00, 0x00, 0x01,
+    0xaa, 0xaa, 0x55, 0x55, 0x96, 0x56, 0xa5, 0x51, 0x6a, 0x69, 0x8a, 0x55, 0x95, 0x66, 0xaa, 0x95,
+    0x95, 0x69, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x01,
+    0xaa, 0xa8, 0x55, 0x55, 0x96, 0x55, 0x55, 0x55, 0x6a, 0x65, 0x58, 0x55, 0x95, 0x66, 0x59, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x01,
+    0xa9, 0x45, 0x55, 0x45, 0x96, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00,
+    0xa5, 0x55, 0x55, 0x41, 0x96, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x41, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x45, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0x56, 0x59, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x2a, 0x55, 0x55, 0x55, 0x61, 0x55, 0x55, 0x55, 0x54,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x42, 0x55, 0x55, 0x55, 0x56,
+    0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x51, 0x59, 0x55, 0x55, 0x2a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x51,
+    0x55, 0x55, 0x55, 0x56, 0x55, 0x15, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0x6a, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0x6a, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x00, 0x15, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x41,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0x6a, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x10, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x40, 0x55, 0x55, 0x55, 0x42,
+    0x6a, 0x95, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x59, 0x15, 0x55, 0x55, 0x82, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x99, 0x50, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x6a, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x99, 0x55, 0x6a,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0a, 0x08, 0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x80, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa0, 0x80, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x00, 0x12, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1a, 0x20, 0x00, 0x00, 0x01, 0x80, 0x00, 0x00, 0x88, 0x00, 0x00, 0x00, 0x00, 0x10, 0x80, 0x00,
+    0x20, 0x0a, 0x88, 0x82, 0x80, 0x00, 0x05, 0x08, 0x0a, 0xa0, 0x00, 0x20, 0x20, 0x00, 0x00, 0x20,
+    0x00, 0x80, 0x00, 0x08, 0x80, 0x28, 0x08, 0x80, 0x2a, 0x80, 0xa0, 0x28, 0x20, 0x00, 0x80, 0x04,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x11, 0x22, 0xa2, 0x00, 0x04, 0x04, 0x00, 0x00,
+    0x2a, 0xa0, 0x20, 0x00, 0x22, 0xa0, 0x00, 0x00, 0x98, 0x12, 0x00, 0x0a, 0x18, 0xa0, 0x80, 0x00,
+    0x28, 0x0a, 0x8a, 0x82, 0x88, 0x88, 0x09, 0x28, 0x0a, 0xa2, 0x20, 0x20, 0x20, 0x00, 0x02, 0xa0,
+    0x00, 0xa4, 0x00, 0x08, 0xa8, 0xa8, 0xa8, 0xa0, 0xaa, 0xaa, 0xaa, 0xa8, 0x20, 0x02, 0x88, 0x84,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x22, 0x22, 0xa2, 0x28, 0x04, 0x08, 0x00, 0x00,
+    0x2a, 0xa0, 0x28, 0x00, 0x2a, 0xa0, 0x20, 0x0a, 0x98, 0x56, 0x02, 0x8a, 0xa8, 0xa4, 0x80, 0x00,
+    0x28, 0x0a, 0xaa, 0x82, 0x88, 0xaa, 0x0a, 0x28, 0x2a, 0xaa, 0xa8, 0x22, 0x20, 0x40, 0xa2, 0xa2,
+    0x12, 0xa8, 0xa0, 0x28, 0xa8, 0xaa, 0xaa, 0xa0, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0x82, 0xa8, 0x85,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0xa0, 0x20, 0x00, 0x26, 0x22, 0xa2, 0x28, 0x05, 0x08, 0x00, 0x00,
+    0x2a, 0xa0, 0x28, 0x00, 0x2a, 0xa0, 0x28, 0x0a, 0x99, 0xaa, 0x82, 0x8a, 0xaa, 0xa8, 0xa2, 0x00,
+    0x28, 0x8a, 0xaa, 0x82, 0xaa, 0xaa, 0xaa, 0x28, 0x2a, 0xaa, 0xa8, 0xa2, 0x20, 0xa6, 0xa2, 0xa2,
+    0x92, 0xaa, 0xa8, 0x28, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x98, 0x58, 0x8a, 0xa8, 0x95,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x20, 0x00, 0x2a, 0x66, 0xa2, 0x28, 0x09, 0x0a, 0x00, 0x00,
+    0x2a, 0xa2, 0x28, 0x00, 0x2a, 0xa0, 0xa8, 0x0a, 0xa9, 0xaa, 0x82, 0x8a, 0xaa, 0xaa, 0xaa, 0x00,
+    0x28, 0x8a, 0xaa, 0x82, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xa2, 0x25, 0xa6, 0xaa, 0xa2,
+    0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0x8a, 0xaa, 0x95,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0x20, 0x80, 0x2a, 0xa6, 0xa2, 0x28, 0x19, 0x0a, 0x00, 0x00,
+    0x2a, 0xa2, 0xa8, 0x00, 0x2a, 0xaa, 0xa8, 0x89, 0xaa, 0xaa, 0x8a, 0x89, 0xaa, 0xaa, 0xaa, 0x02,
+    0x2a, 0x8a, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0x81, 0x2a, 0xaa, 0xaa, 0xa1,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0xaa, 0xa9, 0x95,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0xa0, 0x82, 0xaa, 0xaa, 0xa2, 0x28, 0x9a, 0x2a, 0x00, 0x00,
+    0x2a, 0xaa, 0xa8, 0x04, 0x2a, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0x8a, 0x89, 0xaa, 0xaa, 0xaa, 0x82,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa4, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0xaa, 0xa9, 0x95,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0xa8, 0x82, 0xaa, 0xaa, 0xa2, 0x28, 0x9a, 0x2a, 0x00, 0x00,
+    0xaa, 0xaa, 0xa8, 0x88, 0x2a, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x8a, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x96, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa9, 0x95, 0x5a, 0xaa, 0xa9, 0x55,
+    0xa8, 0xa0, 0x00, 0x14, 0x0a, 0xaa, 0xa8, 0x82, 0xaa, 0xaa, 0xaa, 0x28, 0xaa, 0x2a, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0x99, 0x2a, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x55, 0x55, 0x5a, 0xaa, 0x69, 0x55,
+    0xa8, 0x50, 0x00, 0x14, 0x8a, 0xaa, 0x9a, 0x81, 0xaa, 0xaa, 0xaa, 0x16, 0xaa, 0x2a, 0x08, 0x00,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x55, 0xa9, 0xa5, 0x55, 0x55, 0x9a, 0xaa, 0x65, 0x51,
+    0x98, 0x50, 0x00, 0x14, 0x8a, 0xaa, 0x9a, 0x81, 0xaa, 0xaa, 0xaa, 0x15, 0xaa, 0x2a, 0x08, 0x00,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa6, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0xa9, 0x64, 0x51,
+    0x98, 0x50, 0x00, 0x28, 0x8a, 0x6a, 0x9a, 0x41, 0xaa, 0xaa, 0xa9, 0x15, 0xaa, 0xaa, 0x18, 0x80,
+    0xaa, 0xaa, 0x96, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa9, 0x65, 0xaa, 0xaa, 0x69, 0x95,
+    0xaa, 0xaa, 0xa5, 0x59, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x80, 0x55, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0x55, 0x55, 0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0xa9, 0x54, 0x51,
+    0x94, 0x50, 0x00, 0x28, 0xa6, 0x55, 0x55, 0x61, 0xaa, 0xaa, 0xa9, 0x15, 0xaa, 0xaa, 0x28, 0x80,
+    0xa9, 0x9a, 0x96, 0x95, 0xaa, 0xaa, 0x96, 0x55, 0xaa, 0xa9, 0x69, 0x65, 0xaa, 0xaa, 0x69, 0x95,
+    0xaa, 0xa5, 0x65, 0x55, 0xaa, 0xa6, 0x25, 0x55, 0xaa, 0x01, 0x55, 0x55, 0xaa, 0xaa, 0x59, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x9a, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa9, 0xa5, 0x54, 0x41,
+    0x94, 0xa0, 0x00, 0x28, 0xa5, 0x55, 0x55, 0x51, 0xaa, 0xa9, 0x59, 0x15, 0xaa, 0xaa, 0x68, 0xa1,
+    0xa5, 0x59, 0x55, 0x55, 0xaa, 0x5a, 0x55, 0x55, 0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xaa, 0x55, 0x55,
+    0xaa, 0xa5, 0x55, 0x55, 0xaa, 0xa5, 0x15, 0x55, 0xa9, 0x55, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa9, 0x65, 0x54, 0x41,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x55, 0x59, 0xaa, 0xa9, 0x59, 0x15, 0xaa, 0xaa, 0x68, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x65, 0x55, 0xaa, 0x55, 0x55, 0x55,
+    0xaa, 0xa5, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55,
+    0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa8, 0x65, 0x54, 0x41,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x65, 0x55, 0x8a, 0x99, 0x59, 0x15, 0xaa, 0xa5, 0xaa, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0xa5, 0x65, 0x55, 0xa5, 0x55, 0x55, 0x55,
+    0x92, 0x25, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x54, 0x40,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x65, 0x95, 0x89, 0x99, 0x55, 0x15, 0xaa, 0xa5, 0xaa, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x26, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x96, 0x25, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x54, 0x40,
+    0x64, 0x50, 0x80, 0x15, 0xa5, 0x95, 0x65, 0x95, 0x99, 0x95, 0x55, 0x15, 0xaa, 0x95, 0xa6, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x26, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x14, 0x00,
+    0x58, 0x5a, 0x80, 0x15, 0xa9, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0xaa, 0x95, 0xa6, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x14, 0x00,
+    0x98, 0x5a, 0x80, 0x15, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0x2a, 0x95, 0xa5, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa6, 0x54, 0x14, 0x00,
+    0x98, 0x5a, 0x48, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0x25, 0x95, 0x95, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x2a, 0x55, 0x55, 0x65, 0xa6, 0x54, 0x10, 0x00,
+    0x9a, 0x55, 0x68, 0x55, 0x6a, 0xaa, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x15, 0x25, 0x55, 0x95, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x66, 0x2a, 0xaa, 0xaa, 0x6a, 0xa6, 0x10, 0x00, 0x00,
+    0x9a, 0x55, 0x64, 0x41, 0x5a, 0xaa, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x15, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xa6, 0x10, 0x08, 0x00,
+    0x9a, 0x55, 0x94, 0x81, 0x5a, 0x6a, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x46, 0x0a, 0x9a, 0x81, 0xa6, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65,
+    0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x45, 0x0a, 0xa9, 0x41, 0xa5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x44, 0x85, 0x56,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x45, 0x0a, 0x65, 0x41, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x6a, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x69, 0x55, 0x56, 0x11, 0x59, 0x55, 0x55, 0x55, 0x61,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x66, 0x65, 0x44, 0x42, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x46, 0x05, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x51,
+    0x55, 0xaa, 0xaa, 0x54, 0x45, 0x55, 0x55, 0x56, 0x59, 0x59, 0x11, 0x56, 0x55, 0x55, 0x14, 0x52,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x46, 0x05, 0x55, 0x41, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x61,
+    0x40, 0x55, 0x55, 0x80, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x04, 0x02, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x59, 0x55, 0x65, 0x46, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x45, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51,
+    0x40, 0x00, 0x00, 0x42, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x64, 0x0a, 0x9a, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x45, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x65, 0x55, 0x55, 0x55, 0x55, 0x64, 0x55, 0x41,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x6a, 0x2a, 0x82, 0x2a, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x44, 0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x55, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x42, 0x95, 0x55, 0x51, 0x42, 0x55, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x50, 0x50, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x8a, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x80, 0x08, 0x82, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0x00, 0x8a, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x82, 0x20, 0x00, 0x00, 0x08, 0x20, 0x00, 0x00, 0x00,
+    0x02, 0x82, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00, 0xaa, 0x08, 0xaa, 0x08, 0x00, 0x00, 0x08, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0x20, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x82, 0xa0, 0x00, 0x00, 0x0a, 0x20, 0x00, 0x00, 0x00,
+    0x02, 0x82, 0x00, 0x2a, 0x80, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xa8, 0x00, 0x20, 0x08, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0xa0, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x81, 0xa0, 0x00, 0x00, 0x05, 0x20, 0x00, 0x00, 0x01,
+    0x02, 0x82, 0x00, 0xa9, 0x80, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xa4, 0x12, 0x28, 0xaa, 0x01,
+    0xa8, 0x00, 0x0a, 0x00, 0x88, 0xa0, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x82, 0xa8, 0x08, 0x81, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x02, 0x0a, 0x80, 0x88, 0x00, 0x02, 0x81, 0xa0, 0x00, 0x00, 0x25, 0x28, 0x00, 0x00, 0x21,
+    0x22, 0x82, 0x00, 0xa5, 0x80, 0x00, 0x00, 0x21, 0xaa, 0xaa, 0xaa, 0xa4, 0x19, 0x2a, 0xaa, 0x81,
+    0xa8, 0x00, 0x0a, 0x00, 0x8a, 0xa8, 0x8a, 0x0a, 0x00, 0x20, 0x00, 0x28, 0x80, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x0a, 0x82, 0xaa, 0x08, 0x81, 0x08, 0x20, 0x02, 0x02, 0x08, 0x00, 0x00, 0x08,
+    0x28, 0x02, 0x0a, 0x80, 0x88, 0x08, 0x0a, 0x81, 0xa0, 0x00, 0x08, 0x25, 0x2a, 0x00, 0x0a, 0x21,
+    0x22, 0xa2, 0x00, 0x95, 0x80, 0x02, 0x00, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5, 0x19, 0xaa, 0xaa, 0x81,
+    0xa8, 0x00, 0x05, 0x00, 0x8a, 0xaa, 0x8a, 0x0a, 0x02, 0x20, 0x00, 0x28, 0x88, 0x00, 0x00, 0x02,
+    0x20, 0x00, 0x00, 0x09, 0xa2, 0xaa, 0xa8, 0x85, 0x8a, 0x28, 0x0a, 0x09, 0x88, 0x00, 0x00, 0x08,
+    0x28, 0x02, 0x8a, 0x80, 0x88, 0x0a, 0x0a, 0x81, 0xa8, 0x02, 0x08, 0x25, 0xaa, 0xaa, 0x0a, 0x21,
+    0x2a, 0xaa, 0x2a, 0x95, 0xa0, 0x02, 0x82, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0x19, 0xaa, 0xaa, 0x81,
+    0xa8, 0x00, 0x05, 0x00, 0x8a, 0xaa, 0xaa, 0x09, 0x02, 0x20, 0x08, 0x04, 0x88, 0x00, 0x00, 0x21,
+    0x28, 0x00, 0x00, 0x09, 0xaa, 0xaa, 0xa8, 0x85, 0x8a, 0xaa, 0x0a, 0x09, 0x88, 0x08, 0x00, 0x04,
+    0xaa, 0x82, 0x8a, 0x82, 0xaa, 0xaa, 0xaa, 0x81, 0xa8, 0x22, 0xa8, 0x15, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0x2a, 0x95, 0xa0, 0x22, 0x82, 0x91, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x6a, 0xaa, 0x85,
+    0xa8, 0x00, 0x05, 0x10, 0x8a, 0xaa, 0xaa, 0x09, 0x22, 0x20, 0x28, 0x14, 0x88, 0x00, 0x00, 0x25,
+    0x28, 0x00, 0x00, 0x05, 0xaa, 0xaa, 0xa8, 0x45, 0xaa, 0xaa, 0xaa, 0x05, 0x88, 0x0a, 0x00, 0x85,
+    0xaa, 0xa2, 0x8a, 0xa9, 0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0x2a, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x95, 0xa2, 0x2a, 0x8a, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x6a, 0xa6, 0x85,
+    0xa8, 0x00, 0x05, 0x90, 0x8a, 0xaa, 0x65, 0x09, 0xaa, 0x22, 0xaa, 0x15, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x00, 0x00, 0x25, 0xaa, 0xaa, 0xa8, 0x65, 0xaa, 0xaa, 0xaa, 0x25, 0xa8, 0x2a, 0x80, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x55, 0xa2, 0xaa, 0x8a, 0x95, 0xa9, 0xaa, 0x66, 0x55, 0x15, 0x6a, 0x56, 0x45,
+    0x94, 0x00, 0x05, 0x90, 0x8a, 0xaa, 0x65, 0x29, 0xaa, 0x22, 0xaa, 0x95, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x08, 0x00, 0x25, 0xaa, 0xaa, 0xa6, 0x65, 0xaa, 0xaa, 0xaa, 0x25, 0xaa, 0x2a, 0x8a, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x45, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0x29, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa9, 0xa9, 0x55, 0x55, 0x25, 0x6a, 0x55, 0x55,
+    0x94, 0x00, 0x0a, 0x64, 0x8a, 0xaa, 0x65, 0x19, 0xaa, 0x22, 0xaa, 0x95, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x0a, 0x0a, 0x15, 0xaa, 0x6a, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x8a, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xa9, 0x69, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x99, 0xa5, 0x55, 0x55, 0x21, 0x15, 0x55, 0x55,
+    0x94, 0x00, 0x0a, 0x64, 0xaa, 0x9a, 0x65, 0x19, 0xaa, 0x22, 0xaa, 0x95, 0x8a, 0x00, 0x00, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xaa, 0x5a, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa8, 0x55, 0x9a, 0xaa, 0xa6, 0x55, 0x9a, 0xaa, 0xa5, 0x55,
+    0xa9, 0x69, 0xaa, 0x55, 0xaa, 0xa9, 0x6a, 0x55, 0x95, 0x55, 0x55, 0x55, 0x22, 0x15, 0x55, 0x55,
+    0x94, 0x00, 0x0a, 0xa5, 0xaa, 0x5a, 0x65, 0x15, 0xaa, 0x22, 0xaa, 0x95, 0xaa, 0xa0, 0x08, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xaa, 0x55, 0x56, 0x55, 0xaa, 0xaa, 0xa9, 0x95, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x9a, 0xa9, 0x56, 0x55, 0x9a, 0xa1, 0x55, 0x55,
+    0xa9, 0x69, 0x95, 0x55, 0xaa, 0xa9, 0x69, 0x55, 0x95, 0x55, 0x55, 0x55, 0x22, 0x15, 0x55, 0x55,
+    0x68, 0x00, 0x0a, 0x95, 0xa6, 0x55, 0x55, 0x15, 0xaa, 0x12, 0x8a, 0x15, 0xaa, 0xa0, 0x28, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xa9, 0x55, 0x56, 0x55, 0xaa, 0xaa, 0xa5, 0x95, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xa9, 0xa5, 0x55, 0xaa, 0xa5, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55, 0x91, 0x55, 0x55, 0x55,
+    0x99, 0x55, 0x55, 0x55, 0x9a, 0xa9, 0x65, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa2, 0x15, 0x55, 0x55,
+    0x68, 0x00, 0x0a, 0x55, 0xa6, 0x55, 0x9a, 0x15, 0xaa, 0x12, 0x06, 0x55, 0xaa, 0xaa, 0x28, 0x15,
+    0xa8, 0x2a, 0xaa, 0x15, 0xa9, 0x55, 0x56, 0x55, 0xaa, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xa5, 0x55, 0x55, 0x9a, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x99, 0x55, 0x55, 0x55, 0x99, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa2, 0x15, 0x55, 0x55,
+    0x68, 0x00, 0x05, 0x55, 0xa5, 0x55, 0x9a, 0x25, 0xa9, 0x91, 0x55, 0x55, 0xaa, 0xaa, 0x28, 0x15,
+    0xa8, 0x2a, 0xaa, 0x95, 0x59, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x6a, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xa4, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x15, 0x55, 0x55,
+    0x68, 0x00, 0x05, 0x55, 0xa5, 0x65, 0x9a, 0x25, 0x99, 0x91, 0x55, 0x55, 0xaa, 0xaa, 0xa8, 0x95,
+    0x9a, 0x25, 0x65, 0x95, 0x51, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xa6, 0x85, 0x65, 0x55,
+    0x9a, 0x29, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x15, 0x55, 0x55,
+    0x54, 0x00, 0x05, 0x55, 0xa9, 0xa5, 0x9a, 0x25, 0x99, 0x91, 0x55, 0x55, 0xa6, 0xaa, 0xaa, 0x95,
+    0x96, 0xa5, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x85, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x96, 0x55, 0x55, 0x55, 0xaa, 0x95, 0x11, 0x56,
+    0x94, 0x00, 0x05, 0x55, 0x69, 0xaa, 0xaa, 0x25, 0x95, 0x99, 0x55, 0x55, 0xa6, 0xaa, 0x96, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x56, 0xa6, 0x55, 0x55, 0x59, 0xaa, 0x95, 0x21, 0x56,
+    0x94, 0x00, 0x85, 0x51, 0x6a, 0xaa, 0xaa, 0xa5, 0x95, 0x99, 0x55, 0x55, 0x65, 0x59, 0x96, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x66, 0xaa, 0x56, 0x99, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x94, 0x22, 0x85, 0x51, 0x6a, 0xaa, 0x20, 0x95, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x66, 0x56, 0x95, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x96, 0x2a, 0x65, 0x01, 0xa2, 0x8a, 0x65, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x15, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x96, 0xa9, 0x50, 0x01, 0xa6, 0x0a, 0x65, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x4a, 0x55, 0x55, 0x55, 0x55,
+    0x66, 0x96, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x42, 0x95, 0x90, 0x01, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x52, 0x65, 0x54, 0x01, 0x2a, 0x65, 0x55, 0x55, 0x6a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x41, 0x56, 0xa0, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x64, 0x66, 0x6a, 0xa9, 0x50, 0x55, 0x55, 0x55, 0x64, 0x55, 0x55, 0x55, 0x5a,
+    0x55, 0x55, 0x55, 0x55, 0x45, 0x50, 0x00, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x41, 0x6a, 0x60, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x56,
+    0x55, 0x5a, 0x95, 0x5a, 0x55, 0x15, 0x55, 0x20, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x95,
+    0x55, 0x55, 0x55, 0x56, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x42, 0xa9, 0x50, 0x05, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55,
+    0x59, 0x65, 0x6a, 0xa6, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x41, 0x50, 0x15, 0x6a,
+    0x45, 0x54, 0x55, 0x91, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x42, 0x95, 0x50, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x65, 0x55, 0x55, 0x54,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x45, 0x54, 0x10, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x41, 0x55, 0x50, 0x15, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xa2, 0xaa, 0xa2, 0x52, 0xa6, 0x65, 0x92,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xa2,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x40, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x84, 0x50, 0x05, 0x0a, 0x40, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0xa5, 0x50, 0x15, 0x48, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x45, 0x00, 0x00, 0x00, 0x00, 0x81, 0x51, 0x11, 0x68,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x54, 0x45, 0x55, 0x55, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0a, 0x82, 0x02, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x80, 0x02, 0x08,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0a, 0x8a, 0x82, 0x00, 0x40, 0x08, 0x00, 0x40, 0x00, 0x80, 0x22, 0x08,
+    0x00, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x04, 0x00, 0x00, 0x11, 0x40, 0x20, 0x00,
+    0x81, 0x00, 0x00, 0x20, 0x28, 0x08, 0x00, 0x82, 0x00, 0x20, 0x80, 0x80, 0x00, 0x00, 0x02, 0x80,
+    0x80, 0x00, 0x00, 0x00, 0x6a, 0x8a, 0x8a, 0x00, 0x90, 0x08, 0x01, 0x40, 0x00, 0x80, 0x22, 0x08,
+    0x00, 0x28, 0x88, 0x0a, 0x80, 0x08, 0x00, 0x00, 0x00, 0x08, 0x02, 0x00, 0x00, 0x08, 0x02, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x14, 0x14, 0x00, 0x00, 0x21, 0x50, 0x20, 0x80,
+    0x81, 0x18, 0x00, 0xa0, 0x29, 0x0a, 0x02, 0x81, 0x2a, 0xa2, 0xaa, 0xa0, 0x00, 0x2a, 0x02, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x00, 0xa0, 0x08, 0x02, 0x80, 0x80, 0x88, 0x22, 0x08,
+    0x20, 0xa8, 0x89, 0x0a, 0x80, 0xa8, 0x00, 0x02, 0xaa, 0x8a, 0x0a, 0x08, 0x00, 0x08, 0x02, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x08, 0x08, 0x80, 0x00, 0x24, 0x54, 0x22, 0x80, 0x25, 0x52, 0x2a, 0xa0,
+    0x86, 0xa8, 0x20, 0xa8, 0xaa, 0x2a, 0x82, 0x81, 0x2a, 0xaa, 0xaa, 0xa0, 0x00, 0xaa, 0x22, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0x8a, 0x2a, 0x80, 0x80, 0x88, 0x22, 0x08,
+    0x22, 0xaa, 0x8a, 0x0a, 0x82, 0xa8, 0x00, 0x02, 0xaa, 0xaa, 0x0a, 0x0a, 0x00, 0x08, 0x02, 0x28,
+    0x00, 0x00, 0x00, 0x02, 0x2a, 0x8a, 0x80, 0x00, 0xa5, 0xaa, 0xa2, 0xa0, 0x26, 0x96, 0xaa, 0xa0,
+    0x8a, 0xa8, 0xa0, 0xa8, 0xaa, 0x2a, 0x8a, 0x81, 0x2a, 0xaa, 0xaa, 0xa8, 0x00, 0xaa, 0x22, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0x8a, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x0a, 0xa2, 0xa8, 0x02, 0x02, 0xaa, 0xaa, 0x2a, 0x0a, 0x00, 0x08, 0x02, 0x2a,
+    0x00, 0x00, 0x00, 0x02, 0x2a, 0xaa, 0x80, 0x00, 0xa9, 0xaa, 0xa2, 0xa0, 0x26, 0xa6, 0xaa, 0xa0,
+    0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x2a, 0x8a, 0x41, 0xaa, 0xaa, 0xaa, 0xa8, 0x08, 0xaa, 0x12, 0x41,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0x8a, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x29, 0xa2, 0xa8, 0x02, 0x02, 0xaa, 0xaa, 0xaa, 0x0a, 0x00, 0x08, 0x02, 0x2a,
+    0x00, 0x00, 0x08, 0x80, 0x2a, 0xaa, 0x8a, 0x22, 0xaa, 0xaa, 0xaa, 0xa0, 0x2a, 0xaa, 0xaa, 0xa0,
+    0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0xaa, 0x12, 0x41,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x25, 0xaa, 0xaa, 0x0a, 0x02, 0xaa, 0xaa, 0xaa, 0x09, 0x20, 0x08, 0x02, 0x28,
+    0x00, 0x02, 0x0a, 0xa0, 0x2a, 0xaa, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0xa0, 0x2a, 0xaa, 0xaa, 0xa8,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0xaa, 0x11, 0x45,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x81, 0x88, 0xa2, 0x24,
+    0xaa, 0xaa, 0x8a, 0x25, 0xaa, 0xaa, 0x0a, 0x21, 0xaa, 0xaa, 0xaa, 0x05, 0x2a, 0x8a, 0x02, 0x28,
+    0x00, 0x82, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xa8,
+    0xaa, 0xaa, 0xaa, 0x95, 0xa6, 0xaa, 0xa9, 0x55, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0x91, 0x45,
+    0x80, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0x89, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x82, 0x88, 0xaa, 0x24,
+    0xaa, 0xaa, 0xaa, 0x25, 0xaa, 0xaa, 0x0a, 0x21, 0xaa, 0xaa, 0xaa, 0x05, 0x2a, 0xaa, 0x82, 0x04,
+    0x20, 0xa2, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x29, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x55, 0x96, 0xa5, 0x65, 0x55, 0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0x91, 0x45,
+    0x80, 0x00, 0x00, 0x10, 0xaa, 0xaa, 0x89, 0x80, 0xaa, 0xaa, 0xaa, 0xa0, 0xa2, 0xa8, 0x9a, 0x14,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x8a, 0x21, 0xaa, 0xaa, 0xaa, 0x85, 0x2a, 0xaa, 0x8a, 0x15,
+    0x20, 0xaa, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x25, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x5a,
+    0xaa, 0xaa, 0xaa, 0x55, 0x96, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x56, 0x2a, 0x99, 0x91, 0x45,
+    0x80, 0x00, 0x00, 0x94, 0xaa, 0xa9, 0x45, 0x44, 0xaa, 0xaa, 0xaa, 0xa0, 0xa2, 0xa8, 0x99, 0x16,
+    0xaa, 0xa6, 0xa6, 0x95, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xa9, 0xa5, 0xaa, 0xaa, 0xa9, 0x95,
+    0x2a, 0xaa, 0xaa, 0x69, 0x2a, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x5a, 0xaa, 0xaa, 0x9a, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0x95, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0x55, 0x56, 0x1a, 0x95, 0x99, 0x05,
+    0x80, 0x00, 0x00, 0x64, 0xa9, 0x65, 0x45, 0x48, 0xaa, 0xaa, 0xa9, 0xa2, 0xa2, 0x6a, 0x99, 0x15,
+    0xaa, 0xa5, 0x65, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xa5, 0xa5, 0xaa, 0xaa, 0xa9, 0x95,
+    0xaa, 0xaa, 0xaa, 0x59, 0x2a, 0xaa, 0x6a, 0x95, 0xaa, 0xaa, 0x99, 0x55, 0xaa, 0xaa, 0x95, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0x95, 0x95, 0x55, 0x55, 0xaa, 0x99, 0x55, 0x55, 0x19, 0x55, 0x89, 0x05,
+    0x40, 0x00, 0x00, 0xa4, 0xa5, 0x65, 0x45, 0x4a, 0xaa, 0xaa, 0xa9, 0xa1, 0xa2, 0x6a, 0x99, 0x15,
+    0xa9, 0x95, 0x65, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa5, 0x95, 0xa5, 0xaa, 0xa6, 0xa9, 0x95,
+    0xaa, 0xaa, 0xa6, 0x55, 0x2a, 0xaa, 0x6a, 0x95, 0xaa, 0xaa, 0x59, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xaa, 0xa6, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x99, 0x55, 0x55, 0x15, 0x55, 0x49, 0x15,
+    0x40, 0x00, 0x00, 0x54, 0xa5, 0x65, 0x45, 0x88, 0xaa, 0xaa, 0xa9, 0xa1, 0xaa, 0x66, 0x11, 0x15,
+    0xa9, 0x55, 0x65, 0x55, 0xaa, 0x96, 0xa9, 0x95, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa6, 0xa9, 0x15,
+    0xaa, 0xaa, 0x95, 0x55, 0x26, 0xa5, 0x65, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x15,
+    0x40, 0x00, 0x00, 0x55, 0xa5, 0x55, 0x46, 0x89, 0xaa, 0xa6, 0xa5, 0x61, 0xaa, 0x66, 0x51, 0x15,
+    0x95, 0x55, 0x55, 0x55, 0x28, 0x56, 0xa5, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xa6, 0xa9, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x15,
+    0xa0, 0x00, 0x00, 0x55, 0x95, 0x56, 0x8a, 0x99, 0xaa, 0xaa, 0x9a, 0x51, 0xaa, 0x66, 0x55, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x29, 0x55, 0xa5, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x45, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x59, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x16,
+    0xa0, 0x00, 0x00, 0x55, 0x9a, 0x9a, 0x8a, 0x65, 0xaa, 0xa5, 0x56, 0x51, 0x6a, 0x56, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x55,
+    0xaa, 0x59, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x96, 0x95, 0x55, 0x55, 0x55, 0x05, 0x55, 0x45, 0x16,
+    0xa0, 0x00, 0x20, 0x51, 0x5a, 0xaa, 0x8a, 0x65, 0xa5, 0x65, 0x56, 0x59, 0x69, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x45, 0x12,
+    0x60, 0x28, 0xa0, 0x51, 0x6a, 0xaa, 0x8a, 0x65, 0x95, 0x55, 0x56, 0x55, 0x59, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x84, 0x55, 0x44, 0x12,
+    0x6a, 0xaa, 0xa8, 0x11, 0x6a, 0xaa, 0x8a, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x45, 0x44, 0x12,
+    0x5a, 0xaa, 0x64, 0x01, 0xa6, 0x99, 0x45, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x44, 0x46, 0x12,
+    0x5a, 0x95, 0x56, 0x01, 0x95, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x99, 0x01, 0x95, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x56, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x65, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x25, 0x6a, 0x99, 0x01, 0x15, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x65,
+    0x56, 0x5a, 0x56, 0x56, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x65,
+    0x95, 0x55, 0x41, 0x56, 0x95, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x5a, 0x66, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x2a, 0xaa, 0x56, 0x05, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x95, 0x61,
+    0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x54, 0x55,
+    0x55, 0x54, 0x00, 0x15, 0x91, 0x55, 0x15, 0x42, 0x55, 0x55, 0x24, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x2a, 0x95, 0x56, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x49, 0x91,
+    0x55, 0x55, 0x54, 0x44, 0x55, 0x55, 0x55, 0x49, 0x15, 0x55, 0x55, 0x54, 0x55, 0x51, 0x54, 0x0a,
+    0x55, 0x04, 0x00, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x54, 0x10, 0x55, 0x65, 0x56, 0x9a, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0xa2, 0x1a, 0x06, 0x50,
+    0x68, 0xa0, 0xa8, 0xaa, 0x55, 0x45, 0x55, 0x06, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x45, 0x04, 0x00, 0xaa, 0x55, 0x55, 0x65, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x51, 0x05, 0x01, 0x02,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x6a, 0x00, 0x00, 0x00, 0x00,
+    0x80, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x06, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x15, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x58, 0x55, 0x55, 0x55, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x58, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xa9, 0xa4, 0x55, 0x55, 0x55, 0x00, 0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x80, 0x00, 0x08, 0x02,
+    0x00, 0x80, 0x00, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x00, 0x00, 0xa2, 0x88, 0x20, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x80, 0x20, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x82, 0x00, 0x00, 0xa8, 0x00, 0x08, 0x82,
+    0x00, 0x80, 0x20, 0x0a, 0x92, 0x00, 0x00, 0x8a, 0x2a, 0x20, 0xa2, 0xa8, 0x00, 0x00, 0x00, 0x08,
+    0x02, 0x00, 0x00, 0x00, 0xa2, 0x8a, 0x20, 0x05, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x08, 0x05, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x00, 0x80, 0x20, 0x00, 0x02, 0xa0, 0xa0, 0x00, 0x00, 0x82, 0x00, 0x02, 0xaa, 0x20, 0x08, 0x82,
+    0x84, 0x80, 0xa0, 0x0a, 0xa2, 0x84, 0x00, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x08,
+    0x82, 0x00, 0x00, 0x00, 0xa2, 0xaa, 0xa0, 0x05, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x29, 0x15, 0x42, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x02, 0xa0, 0x20, 0x80, 0x02, 0xaa, 0xa8, 0x00, 0x00, 0x82, 0x00, 0x02, 0xaa, 0xa8, 0x2a, 0x82,
+    0x9a, 0x90, 0xa8, 0x0a, 0xa6, 0xa8, 0x00, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x22, 0x00, 0x80, 0x2a,
+    0x82, 0x00, 0x00, 0x00, 0xa2, 0xaa, 0xa8, 0x09, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x29, 0x1a, 0x46, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x02, 0xaa, 0x28, 0x80, 0x2a, 0xaa, 0xa8, 0x00, 0x02, 0x82, 0x00, 0x02, 0xaa, 0xa8, 0x2a, 0x8a,
+    0xaa, 0xa8, 0xaa, 0x0a, 0xaa, 0xaa, 0x08, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x22, 0x8a, 0x88, 0x26,
+    0x82, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x09, 0x00, 0x00, 0x00, 0x90, 0x02, 0xa0, 0x08, 0x02,
+    0x2a, 0x2a, 0x86, 0x20, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x80, 0x2a, 0xaa, 0xa8, 0x20, 0x02, 0x82, 0x00, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xa8, 0xaa, 0x0a, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x1a, 0x8a, 0x8a, 0xa6,
+    0x82, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x0a, 0x80, 0x00, 0x00, 0x90, 0x02, 0xaa, 0x2a, 0x02,
+    0x2a, 0xaa, 0x8a, 0x20, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xa2, 0xa2, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x1a, 0xaa, 0x4a, 0x55,
+    0x82, 0x00, 0x00, 0x08, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x02, 0x00, 0x90, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa0, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x16, 0xaa, 0x46, 0x55,
+    0x82, 0x00, 0x00, 0x08, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x02, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x00, 0x02, 0x82, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa4, 0x15, 0x9a, 0x65, 0x55,
+    0x82, 0x00, 0x00, 0x28, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x22, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x00, 0x02, 0x82, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0x15, 0x95, 0x65, 0x55,
+    0x81, 0x00, 0x00, 0x28, 0xaa, 0xaa, 0xaa, 0x0a, 0xa2, 0x22, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x8a,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x0a, 0x02, 0x82, 0x20, 0x00, 0x0a, 0x00, 0x09,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa2, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x81, 0x00, 0x80, 0xa8, 0xaa, 0xaa, 0x9a, 0x2a, 0xa2, 0xa2, 0x02, 0xaa, 0x02, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x0a, 0x82, 0x82, 0xa0, 0x00, 0x8a, 0x80, 0x09,
+    0x2a, 0xaa, 0xaa, 0x22, 0x2a, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa2, 0x21,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xa9, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x81, 0x00, 0x80, 0xa8, 0xaa, 0x66, 0x9a, 0x2a, 0xa2, 0xa2, 0x22, 0xaa, 0x02, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xa9, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0x2a, 0xa2, 0xa0, 0x80, 0x02, 0x8a, 0x80, 0x05,
+    0x2a, 0xaa, 0x8a, 0x20, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xa0, 0x61,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x81, 0x00, 0x80, 0x94, 0xaa, 0x65, 0x56, 0x26, 0xa2, 0xaa, 0x22, 0xaa, 0x82, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xa9, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x82, 0x02, 0x8a, 0x82, 0x05,
+    0x2a, 0xaa, 0x8a, 0x21, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa1, 0xaa, 0xaa, 0xa0, 0x65,
+    0xaa, 0xaa, 0x20, 0x85, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0x95, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x41, 0x00, 0x40, 0x94, 0x69, 0x65, 0x56, 0x26, 0xa2, 0xaa, 0x22, 0xaa, 0x82, 0xaa, 0xaa, 0xa1,
+    0x2a, 0xaa, 0xaa, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xa2, 0x05,
+    0x2a, 0xaa, 0x8a, 0x21, 0x2a, 0xaa, 0xaa, 0x9a, 0xaa, 0x28, 0xaa, 0xa9, 0xaa, 0xaa, 0x80, 0x65,
+    0xaa, 0x2a, 0x00, 0x85, 0xaa, 0xaa, 0xaa, 0x65, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x42, 0x00, 0x48, 0xa4, 0x59, 0x55, 0x55, 0xa9, 0xa6, 0xaa, 0x22, 0x69, 0x82, 0xaa, 0xaa, 0x25,
+    0x2a, 0xaa, 0xaa, 0x9a, 0xa8, 0x80, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0x05,
+    0x2a, 0xaa, 0x8a, 0x29, 0x2a, 0xaa, 0x56, 0x99, 0xaa, 0x28, 0xa8, 0xa9, 0xaa, 0x82, 0x80, 0x65,
+    0xaa, 0x2a, 0x00, 0x85, 0xaa, 0xaa, 0xa6, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x42, 0x00, 0x48, 0x64, 0x59, 0x59, 0x65, 0xa9, 0xa6, 0xaa, 0x22, 0x69, 0x82, 0xaa, 0xa0, 0x65,
+    0x2a, 0xaa, 0xa9, 0x56, 0xaa, 0x80, 0x00, 0x09, 0xaa, 0xa9, 0xa9, 0x8a, 0xaa, 0xaa, 0xaa, 0x05,
+    0xaa, 0x2a, 0x86, 0x69, 0x2a, 0x55, 0x55, 0x99, 0xaa, 0x69, 0x89, 0xa9, 0x96, 0x82, 0x00, 0x45,
+    0xaa, 0x22, 0x10, 0x05, 0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x62, 0x00, 0x4a, 0x54, 0x59, 0x9a, 0xa9, 0x95, 0xaa, 0xaa, 0x22, 0x65, 0x82, 0xaa, 0xa5, 0x45,
+    0x26, 0xaa, 0xa9, 0x55, 0xaa, 0x80, 0x00, 0x09, 0xaa, 0xa9, 0x29, 0x89, 0xaa, 0xaa, 0xaa, 0x85,
+    0xaa, 0x55, 0x54, 0x69, 0x21, 0x55, 0x55, 0x55, 0xaa, 0x69, 0x45, 0xa9, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0x22, 0x10, 0x05, 0x89, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0xa2, 0x00, 0x8a, 0x54, 0x66, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x22, 0x55, 0x82, 0x95, 0x55, 0x55,
+    0x16, 0xa5, 0x55, 0x55, 0xa2, 0xa0, 0x20, 0x09, 0xaa, 0xa9, 0x29, 0x09, 0xaa, 0xaa, 0xaa, 0x85,
+    0xa9, 0x55, 0x55, 0x59, 0x11, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xa1, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x55,
+    0xa2, 0x00, 0x86, 0x54, 0xa6, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x22, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x16, 0x5a, 0xa9, 0x55, 0xa2, 0xaa, 0x28, 0x01, 0xaa, 0xa9, 0x29, 0x55, 0xaa, 0xaa, 0xaa, 0x85,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x29, 0x55, 0x55, 0x55,
+    0xa1, 0x00, 0x85, 0x54, 0xaa, 0xa2, 0xaa, 0x65, 0xaa, 0xaa, 0x21, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0xa5, 0x55, 0x55, 0xa2, 0xaa, 0xa8, 0x01, 0xaa, 0xa9, 0x69, 0x55, 0xaa, 0xa5, 0x69, 0xa5,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x29, 0x45, 0x55, 0x55,
+    0xa1, 0x00, 0x85, 0x54, 0x9a, 0x95, 0x55, 0x55, 0xaa, 0x99, 0x21, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x16, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0xa2, 0xa9, 0x55, 0x55, 0xaa, 0xa5, 0x69, 0x65,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x2a, 0x65, 0x85, 0x55,
+    0x61, 0x00, 0x45, 0x54, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x99, 0x61, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0x65, 0x55, 0x65,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x2a, 0x65, 0x89, 0x95,
+    0x61, 0x00, 0x45, 0x50, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0x95, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0x8a, 0xa9,
+    0x61, 0x00, 0x45, 0x10, 0x15, 0x55, 0x55, 0x55, 0x59, 0x55, 0x95, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x65, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0xaa, 0xa9,
+    0x51, 0x20, 0x41, 0x00, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x99, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x16, 0x6a, 0x94, 0x65, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x50, 0x2a, 0x40, 0x00, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x18, 0x2a, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x28, 0xa9, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x28, 0x95, 0x20, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x24, 0x95, 0x20, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x9a, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x14, 0x6a, 0x20, 0x01, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x65, 0x55, 0x14, 0x54, 0x56, 0x41, 0x55, 0x55, 0x56,
+    0x55, 0x55, 0x55, 0x5a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x14, 0x6a, 0x10, 0x01, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x11,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x54,
+    0x55, 0x55, 0x55, 0x15, 0x6a, 0xaa, 0xaa, 0x99, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x18, 0x96, 0x10, 0x05, 0x55, 0x55, 0x55, 0x19, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x21,
+    0xa9, 0x55, 0x56, 0xa5, 0x55, 0x55, 0x55, 0x55, 0x66, 0xa9, 0x69, 0x54, 0x55, 0x50, 0x14, 0x1a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x46, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x18, 0x95, 0x10, 0x45, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x65, 0x6a, 0x6a, 0x9a, 0x11,
+    0x95, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x59, 0x51, 0x56, 0x16, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x18, 0x55, 0x10, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x65, 0x56, 0x54, 0x55, 0x15, 0x45, 0x02,
+    0x80, 0x00, 0x00, 0x0a, 0x55, 0x55, 0x55, 0x16, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x15, 0x55, 0x15, 0x55, 0x00, 0x00, 0x00, 0x00, 0x59, 0x55, 0x55, 0x14, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x65, 0x45, 0x54, 0x0a, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x8a, 0x80, 0x00, 0x15, 0x55, 0x5a, 0xa4, 0x44, 0x10, 0x0a, 0x41, 0x40, 0x00, 0x09, 0x54,
+    0x10, 0x50, 0x00, 0x00, 0x00, 0x00, 0x04, 0x28, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00,
+    0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x68, 0x00, 0x00, 0x40, 0x00,
+    0x00, 0x00, 0x0a, 0x54, 0x00, 0x0a, 0x80, 0x00, 0x10, 0x09, 0x60, 0x00, 0x00, 0x00, 0x00, 0x50,
+    0xaa, 0xaa, 0x8a, 0x00, 0x0a, 0x60, 0x0a, 0xa8, 0x54, 0x54, 0x8a, 0x41, 0x41, 0x02, 0x09, 0x54,
+    0x15, 0x50, 0x06, 0x24, 0x00, 0x08, 0xa4, 0x28, 0x44, 0x00, 0x09, 0xa8, 0x05, 0x00, 0x2a, 0xa8,
+    0x01, 0x10, 0x46, 0xa8, 0x04, 0x00, 0x00, 0xa8, 0x00, 0x40, 0x00, 0x54, 0x04, 0x00, 0x44, 0xa8,
+    0x00, 0x00, 0x8a, 0x54, 0x00, 0x0a, 0x8a, 0x58, 0x50, 0x19, 0x65, 0x54, 0x00, 0x00, 0x00, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x2a, 0x6a, 0xaa, 0xa8, 0x55, 0x94, 0x89, 0x55, 0x41, 0x02, 0x09, 0x54,
+    0x15, 0x50, 0x66, 0x24, 0x40, 0x04, 0xa4, 0x68, 0x44, 0x0a, 0x09, 0x54, 0x05, 0x08, 0x55, 0x54,
+    0x11, 0x14, 0x56, 0x54, 0x04, 0x40, 0x00, 0x58, 0x01, 0x40, 0x0a, 0x54, 0x04, 0x0a, 0x44, 0xa8,
+    0x01, 0x00, 0x8a, 0x54, 0x00, 0x0a, 0x6a, 0x54, 0x50, 0x19, 0x65, 0x54, 0x00, 0x00, 0x01, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x2a, 0x2a, 0xaa, 0xa8, 0x96, 0x95, 0x55, 0x55, 0x51, 0x0a, 0x05, 0x54,
+    0x15, 0x50, 0x66, 0xa4, 0x40, 0x04, 0x54, 0x54, 0x44, 0x05, 0x09, 0x54, 0x05, 0x18, 0x55, 0x54,
+    0x51, 0x14, 0x56, 0x54, 0x04, 0x40, 0x02, 0x54, 0x01, 0x40, 0x8a, 0x54, 0x04, 0x05, 0x44, 0x58,
+    0x01, 0x0a, 0x85, 0x54, 0x01, 0x05, 0x65, 0x54, 0x54, 0x15, 0x55, 0x54, 0x00, 0x00, 0x01, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x96, 0xa5, 0x55, 0x55, 0x51, 0x09, 0x05, 0x55,
+    0x15, 0x50, 0x65, 0x94, 0x44, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x54, 0x05, 0x56, 0x55, 0x54,
+    0x51, 0x14, 0x55, 0x54, 0x14, 0x40, 0x82, 0x54, 0x01, 0x40, 0x8a, 0x54, 0x04, 0x05, 0x44, 0x54,
+    0x01, 0x0a, 0x45, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x54, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x9a, 0xa6, 0x55, 0x55, 0x51, 0x49, 0x25, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x14, 0x55, 0x55, 0x14, 0x40, 0x8a, 0x54, 0x01, 0x40, 0x85, 0x54, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x0a, 0x65, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x9a, 0xa6, 0x55, 0x55, 0x51, 0x55, 0x25, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x14, 0x40, 0xa9, 0x54, 0x01, 0x40, 0x45, 0x55, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x05, 0x65, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x14, 0x40, 0x69, 0x55, 0x01, 0x40, 0x65, 0x55, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x05, 0x55, 0x54, 0x01, 0x05, 0x55, 0x55, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x52, 0x55, 0x54, 0x54, 0x14, 0x56, 0x54, 0x54, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x40, 0x65, 0x55, 0x01, 0x50, 0x65, 0x55, 0x04, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x92, 0x55, 0x55, 0x55, 0x14, 0x56, 0x54, 0x54, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x40, 0x55, 0x55, 0x01, 0x50, 0x55, 0x55, 0x05, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x91, 0x55, 0x15, 0x55,
+    0x16, 0x92, 0x55, 0x55, 0x55, 0x14, 0x56, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x48, 0x55, 0x55, 0x01, 0x50, 0x55, 0x55, 0x05, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0x92, 0x55, 0x95, 0x55,
+    0x6a, 0x91, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x48, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x05, 0x15, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa5, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x6a, 0x91, 0x55, 0x55, 0x95, 0x54, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x4a, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa5, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x4a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x6a, 0xa1, 0x55, 0x55, 0x95, 0x54, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x46, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa9, 0x15, 0x55, 0x55, 0x00, 0x10, 0x45, 0x54,
+    0xaa, 0xa5, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x95, 0x56, 0x55,
+    0x6a, 0xa5, 0x55, 0x55, 0x99, 0x54, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55,
+    0x56, 0x55, 0x55, 0x55, 0x15, 0x46, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x56, 0x05, 0x55, 0x55, 0xa9, 0x66, 0x55, 0x55, 0x00, 0x10, 0x45, 0x54,
+    0xaa, 0x95, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xa6, 0x55, 0xa6, 0xa5, 0x56, 0x55,
+    0x6a, 0xa5, 0x55, 0x55, 0xa9, 0x54, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55,
+    0xa6, 0x55, 0x55, 0x55, 0x15, 0x45, 0x55, 0x55, 0x11, 0x55, 0x55, 0x55, 0x19, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x56, 0x15, 0x55, 0x55, 0xa9, 0x66, 0x95, 0x55, 0x00, 0x10, 0x55, 0x54,
+    0xaa, 0x95, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xa6, 0x96, 0xa6, 0xa5, 0x56, 0x55,
+    0x6a, 0xa5, 0x99, 0x55, 0xaa, 0x68, 0x59, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x5a, 0x95, 0x55, 0x55,
+    0xa6, 0x55, 0x55, 0x55, 0x19, 0x45, 0x55, 0x55, 0x11, 0x95, 0x55, 0x55, 0x19, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x66, 0x15, 0x55, 0x55, 0xaa, 0x66, 0x9a, 0x55, 0x00, 0x14, 0x55, 0x54,
+    0x9a, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0x96, 0xaa, 0xa6, 0x56, 0x55,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0x68, 0x59, 0x55, 0xa9, 0x65, 0x55, 0x55, 0x5a, 0xa5, 0x55, 0x55,
+    0xaa, 0x65, 0x55, 0x55, 0x29, 0x85, 0x55, 0x55, 0x12, 0x95, 0x55, 0x55, 0x59, 0x55, 0x56, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0xa6, 0x15, 0x55, 0x55, 0xaa, 0x6a, 0x9a, 0x55, 0x00, 0x14, 0x55, 0x54,
+    0x95, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x56, 0x55,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0xa8, 0x59, 0x55, 0xa9, 0x65, 0x55, 0x55, 0x9a, 0xa5, 0x55, 0x55,
+    0xaa, 0x69, 0x95, 0x55, 0x2a, 0x85, 0x55, 0x55, 0x52, 0x95, 0x55, 0x55, 0x5a, 0x55, 0x95, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0xaa, 0x1a, 0x95, 0x55, 0xaa, 0x6a, 0xaa, 0xa9, 0x00, 0x15, 0x55, 0x54,
+    0x55, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x56, 0xa9,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0xa8, 0xa9, 0x55, 0xa9, 0xaa, 0x56, 0x55, 0xaa, 0xa5, 0x95, 0x55,
+    0xaa, 0x69, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x52, 0x95, 0x55, 0x55, 0x5a, 0x6a, 0x99, 0x55,
+    0x66, 0x15, 0x55, 0x55, 0xaa, 0x1a, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0xa9, 0x20, 0x95, 0x55, 0x54,
+    0x55, 0x55, 0x8a, 0x00, 0xaa, 0xaa, 0xa5, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xa9,
+    0xaa, 0xa5, 0x99, 0x59, 0xaa, 0xa8, 0xa9, 0x55, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0x6a, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x5a, 0x6a, 0x99, 0x55,
+    0xa6, 0x15, 0x55, 0xa9, 0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0x5a, 0x8a, 0x00, 0xaa, 0xaa, 0x55, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xa9,
+    0xaa, 0xa5, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x6a, 0xaa, 0x99, 0x55,
+    0xaa, 0x1a, 0x9a, 0xa9, 0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0x6a, 0x8a, 0x00, 0xaa, 0x55, 0x5a, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa,
+    0xaa, 0xa9, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0xa5, 0x55, 0x95, 0x6a, 0xaa, 0x99, 0x55,
+    0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0xaa, 0x8a, 0x00, 0xa9, 0x55, 0x5a, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x6a, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0x6a, 0x95, 0x55, 0x55, 0x66, 0xa5, 0x55, 0xa9, 0xaa, 0xaa, 0x99, 0x55,
+    0xaa, 0x2a, 0xaa, 0xa9, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x12, 0x45, 0x55, 0x54,
+    0x6a, 0xaa, 0x8a, 0x00, 0xa5, 0x55, 0xa5, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0x95, 0x55, 0xa5, 0xa6, 0xa5, 0x9a, 0xa9, 0xaa, 0xaa, 0x99, 0xa5,
+    0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x41, 0x55, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x95, 0x6a, 0xa5, 0x56, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xa9, 0xa9, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x96, 0xa9, 0xa6, 0xa5, 0xaa, 0xaa, 0xaa, 0xaa, 0x99, 0xa9,
+    0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x40, 0x00, 0x54,
+    0xaa, 0xaa, 0x85, 0x00, 0x55, 0x6a, 0xa5, 0x56, 0xa9, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xa9,
+    0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x40, 0x00, 0x54,
+    0xaa, 0xaa, 0x45, 0x00, 0x5a, 0x6a, 0x55, 0x56, 0xa9, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x01, 0x40, 0x02, 0xa0,
+    0xaa, 0xa5, 0x45, 0x00, 0x6a, 0x55, 0x55, 0x56, 0x69, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x40, 0x02, 0xa8,
+    0xaa, 0x55, 0x45, 0x00, 0x6a, 0x95, 0x55, 0x55, 0x65, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0xa5, 0x55, 0x45, 0x00, 0x66, 0x95, 0x55, 0x55, 0x65, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x99, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x55, 0x69, 0x69, 0x6a, 0xaa, 0xaa,
+    0xaa, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa,
+    0xa9, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0xa9,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0x5a, 0xa9, 0xaa,
+    0xa5, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x0a, 0xa9,
+    0x55, 0x55, 0x40, 0x00, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0xa9, 0xaa,
+    0x95, 0x6a, 0x66, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x8a, 0xa9,
+    0x55, 0x50, 0x00, 0x80, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0x99, 0xaa,
+    0x55, 0x46, 0x66, 0xaa, 0xa8, 0x6a, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x8a, 0xa9,
+    0x50, 0x00, 0x00, 0x80, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0x95, 0x56,
+    0x55, 0x45, 0x55, 0xa6, 0x65, 0x96, 0x96, 0xaa, 0x26, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x28, 0xaa, 0xa9,
+    0x00, 0x00, 0x00, 0x60, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x45, 0x55, 0xa6, 0x55, 0x96, 0x66, 0xaa, 0x2a, 0x4a, 0xaa, 0xaa, 0xaa, 0x8a, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x88, 0x2a, 0xaa, 0xa8,
+    0x00, 0x00, 0x20, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x66, 0x6a, 0x12, 0x86, 0xaa, 0xaa, 0x85, 0x4a, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0xa8, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x61, 0x09, 0x55, 0x55, 0x45, 0x55, 0x55, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x98, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x99, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x56, 0xaa, 0x94, 0x6a, 0xaa, 0xaa, 0x56, 0x65, 0x55, 0x55, 0xa6, 0x55, 0x66, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x25, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x68, 0x95, 0x55, 0x55, 0xa9, 0x8a, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x05, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0xa4, 0x6a, 0xaa, 0xaa, 0x02, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x50, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// Phases of the GL16 (epdiy mode 5) mode for each temperature range.
+#[cfg(feature = "waveform-gl16")]
+pub(super) static GL16: Table = Table {
+    phases: &[46, 43, 40, 38, 38, 44, 57],
+    luts: &GL16_LUTS,
+};
+
+#[cfg(feature = "waveform-gl16")]
+static GL16_LUTS: [u8; 19584] = [
+    0x00, 0x00, 0x00, 0x00, 0x20, 0x88, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x08, 0x00, 0x00, 0x02, 0x00, 0x80,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa2, 0x88, 0x20, 0x88, 0x22, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xa2, 0x08,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x44, 0x08, 0x00, 0xa0, 0x02, 0x20, 0xa8,
+    0x08, 0x62, 0x88, 0x80, 0x00, 0x00, 0x00, 0x08, 0x08, 0x22, 0x88, 0x80, 0x00, 0x80, 0x00, 0x00,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x04, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x80, 0x82, 0xa2, 0x08,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x02, 0x84, 0x84, 0x28, 0x80, 0xa0, 0x02, 0x20, 0xaa,
+    0xa8, 0xaa, 0x8a, 0x80, 0x22, 0x84, 0x00, 0x28, 0x2a, 0xaa, 0xaa, 0xa0, 0x00, 0x80, 0x28, 0x00,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x08, 0x00, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x40, 0x20, 0x00, 0x80, 0xa2, 0xa2, 0x88,
+    0x00, 0x00, 0x20, 0x02, 0x00, 0x00, 0x00, 0x02, 0x84, 0x88, 0x2a, 0xa2, 0xa0, 0xaa, 0xa8, 0xa8,
+    0xa8, 0xaa, 0x8a, 0x80, 0x2a, 0xaa, 0x08, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x80, 0x28, 0x08,
+    0xaa, 0xaa, 0xa8, 0xa8, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x28, 0x24, 0x80, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x29, 0x80, 0x00, 0x00, 0x00, 0x40, 0x20, 0x00, 0x80, 0xa2, 0xa2, 0x88,
+    0x00, 0x02, 0xa0, 0x00, 0x02, 0x00, 0x00, 0x81, 0x88, 0xa8, 0xaa, 0xa9, 0xa2, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0x8a, 0x81, 0x2a, 0xaa, 0x2a, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x80, 0x28, 0x04,
+    0xaa, 0xaa, 0xa8, 0xa4, 0x2a, 0xaa, 0xaa, 0x02, 0x00, 0x08, 0x00, 0x00, 0x28, 0xa8, 0x80, 0x00,
+    0x00, 0x20, 0x00, 0x00, 0x2a, 0x84, 0xa0, 0x08, 0x2a, 0x68, 0x20, 0x80, 0x80, 0xa2, 0xa2, 0xa8,
+    0x80, 0x02, 0xa0, 0x01, 0x02, 0x80, 0x00, 0xa9, 0x9a, 0xa8, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0x89, 0x2a, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0x22, 0x8a, 0x28, 0x04,
+    0xaa, 0xaa, 0xa8, 0x64, 0x2a, 0xaa, 0xaa, 0x01, 0x00, 0x88, 0x02, 0x00, 0x29, 0xa8, 0x80, 0x00,
+    0x00, 0x28, 0x08, 0x02, 0x2a, 0x88, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa0, 0xaa, 0xa2, 0xa2, 0xa9,
+    0x80, 0x02, 0xa0, 0x01, 0x02, 0xa0, 0x00, 0xa9, 0x9a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0x2a, 0x8a, 0xa8, 0x84,
+    0xaa, 0xaa, 0xa8, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0x00, 0x8a, 0x02, 0x08, 0x2a, 0xa8, 0x88, 0x00,
+    0x00, 0x28, 0x8a, 0x01, 0x2a, 0x88, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa0, 0xaa, 0xaa, 0xaa, 0xa9,
+    0x80, 0x02, 0xa0, 0x01, 0x0a, 0xaa, 0x80, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x21,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0x6a, 0x8a, 0xaa, 0x54,
+    0xaa, 0xaa, 0xa8, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0x20, 0x8a, 0x0a, 0x08, 0x2a, 0xa8, 0xaa, 0x00,
+    0x00, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xa8, 0x08, 0x2a, 0xaa, 0xa0, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x80, 0x02, 0xa0, 0x81, 0x2a, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x59, 0x69, 0x8a, 0xaa, 0x54,
+    0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0xa0, 0x8a, 0x0a, 0x0a, 0xaa, 0xaa, 0xaa, 0x02,
+    0x08, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xaa, 0x09, 0x2a, 0xaa, 0xa0, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x82, 0x82, 0xa0, 0xa1, 0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0x8a, 0xaa, 0x54,
+    0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0xaa, 0x01, 0xa2, 0x8a, 0x2a, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x28, 0x2a, 0x8a, 0x01, 0x2a, 0xaa, 0xaa, 0x89, 0x2a, 0xaa, 0xa8, 0xa1, 0xaa, 0xaa, 0xaa, 0x25,
+    0xaa, 0xaa, 0xa0, 0xa9, 0x2a, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0xaa, 0xaa, 0x54,
+    0xaa, 0xaa, 0xaa, 0x58, 0x2a, 0xaa, 0xa5, 0x05, 0xaa, 0x8a, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0x01, 0x2a, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x01, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x59, 0xaa, 0xaa, 0x54,
+    0xaa, 0xaa, 0x96, 0xa8, 0xaa, 0xaa, 0x55, 0x09, 0xaa, 0x8a, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0x21, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x51, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0x98, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa4, 0x55, 0xaa, 0xa8, 0x8a, 0x55,
+    0xaa, 0xaa, 0xa5, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x54, 0xaa, 0xaa, 0x54,
+    0xaa, 0xa5, 0x96, 0xa8, 0xaa, 0xa5, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x09, 0xaa, 0xaa, 0xaa, 0x09,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xa8, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0xaa, 0xa9, 0x90, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa6, 0x99, 0x55, 0x55, 0x94, 0xaa, 0xa6, 0x50,
+    0xaa, 0xa5, 0x56, 0xa8, 0xaa, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x05, 0xaa, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x98, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0xaa, 0xa9, 0x15, 0x55,
+    0x92, 0x95, 0x65, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x95, 0x95, 0x55, 0x55, 0x94, 0xaa, 0x96, 0x00,
+    0xa9, 0x55, 0x56, 0xa8, 0xa9, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0x05, 0xaa, 0xaa, 0xaa, 0xa5,
+    0x2a, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0x98, 0x51, 0xaa, 0xa9, 0x59, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x55, 0x55, 0x9a, 0x85, 0x55, 0x55,
+    0x94, 0x95, 0x55, 0x55, 0x99, 0xa9, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x94, 0xaa, 0x96, 0x00,
+    0x95, 0x55, 0x56, 0xa8, 0xa5, 0x55, 0x5a, 0xa5, 0xaa, 0xa6, 0xaa, 0x05, 0xaa, 0xaa, 0x6a, 0xa5,
+    0x2a, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x96, 0x65, 0xaa, 0xaa, 0x1a, 0x59, 0xaa, 0x59, 0x51, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa8, 0x55, 0x55, 0x55, 0x98, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x94, 0x6a, 0x96, 0x00,
+    0x95, 0x55, 0x55, 0x94, 0x95, 0x55, 0xaa, 0x95, 0xaa, 0xa6, 0xaa, 0x05, 0xaa, 0xaa, 0x6a, 0x95,
+    0x2a, 0xaa, 0xa5, 0x55, 0x9a, 0x6a, 0x55, 0x55, 0xaa, 0x85, 0x5a, 0x55, 0xaa, 0x59, 0x55, 0x55,
+    0xaa, 0xa9, 0x5a, 0x55, 0xa8, 0xaa, 0x00, 0x55, 0xa8, 0x55, 0x55, 0x55, 0x91, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x90, 0x69, 0x95, 0x00,
+    0x55, 0x55, 0x55, 0x54, 0x96, 0x5a, 0xaa, 0x15, 0xaa, 0xa6, 0xa9, 0x05, 0xaa, 0x9a, 0x65, 0x55,
+    0x2a, 0x96, 0x65, 0x55, 0x96, 0x65, 0x55, 0x55, 0xa1, 0x95, 0x56, 0x55, 0x28, 0x55, 0x55, 0x55,
+    0xaa, 0xa9, 0x52, 0x55, 0xa9, 0x01, 0x05, 0x55, 0x25, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x00,
+    0x55, 0x55, 0x69, 0x55, 0x9a, 0xaa, 0xaa, 0x55, 0xaa, 0xa6, 0xa5, 0x85, 0x9a, 0x56, 0x55, 0x55,
+    0xaa, 0x95, 0x65, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x56, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0xa8, 0xa1, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x00,
+    0x55, 0x5a, 0xa9, 0x55, 0x9a, 0xaa, 0xaa, 0x55, 0xaa, 0xa5, 0xa5, 0x85, 0x92, 0x55, 0x55, 0x55,
+    0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x15, 0x56, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0xa8, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa0, 0x65, 0x55, 0x00,
+    0x56, 0xaa, 0xa9, 0x55, 0x99, 0xaa, 0xa1, 0x55, 0xaa, 0x65, 0x95, 0x45, 0x95, 0x55, 0x55, 0x55,
+    0xa6, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x05, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa0, 0x55, 0x55, 0x00,
+    0x6a, 0xaa, 0xaa, 0x55, 0x95, 0xaa, 0x15, 0x55, 0x9a, 0x65, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa0, 0x55, 0x55, 0x00,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0xa5, 0x55, 0x55, 0x99, 0x65, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa8, 0x55, 0x55, 0x00,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x00,
+    0xaa, 0xaa, 0xaa, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x41, 0x00,
+    0xaa, 0xaa, 0x56, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x65, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0xaa, 0x55, 0x41, 0x00,
+    0xa9, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x68, 0x55, 0x55, 0x96, 0x65, 0x55, 0x55, 0x56, 0x6a, 0x65, 0x55, 0xa6, 0xaa, 0x94, 0x49, 0x00,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x6a, 0x2a, 0x8a, 0xaa, 0x6a, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x15, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x56, 0x65, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x65, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0xaa, 0x55, 0x54, 0x45, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0xa6, 0x50,
+    0x55, 0x56, 0x95, 0x56, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x95, 0x04, 0x45, 0x05, 0x55, 0xaa,
+    0x55, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x55, 0x56, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x54, 0x45, 0x48, 0x58, 0x50, 0x55, 0x55, 0x41, 0x06, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x54, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65,
+    0x55, 0x55, 0x55, 0x54, 0x41, 0x04, 0x44, 0x12, 0x6a, 0x2a, 0x21, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x54, 0x45, 0x05, 0x15, 0x16,
+    0x41, 0x41, 0x15, 0x02, 0x6a, 0x00, 0xa0, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x66, 0x55, 0x59, 0x59, 0xa6, 0x60, 0x00, 0x00, 0x0a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x6a, 0x59, 0x51, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x50, 0x55, 0x55, 0x52, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0x55, 0x55, 0x02, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xa5, 0x96, 0xa6, 0x5a, 0x80, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x56, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0xaa, 0x00, 0x00, 0x20, 0x22, 0xa8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x22, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x22, 0xa8, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x02, 0x08, 0x80, 0x00, 0x80, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2a, 0xaa, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28,
+    0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x22, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x22, 0x02, 0x08, 0x80, 0x00, 0xa0, 0x20, 0x2a, 0x28, 0x22, 0xa8, 0xa8, 0x28, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x28,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0xa0, 0x00, 0x00, 0x00, 0x00,
+    0x22, 0x02, 0x08, 0x80, 0x00, 0xa8, 0x22, 0x2a, 0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x80,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0xaa, 0x80,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x22, 0x2a, 0xaa, 0xa0, 0x00, 0x00, 0x00, 0x00,
+    0xa2, 0x82, 0x08, 0x80, 0x08, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x40,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x02, 0x00, 0x88, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x20, 0x00, 0x00, 0x02, 0xaa, 0x2a, 0xaa, 0x80,
+    0x80, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x2a, 0x2a, 0xaa, 0xa8, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0x82, 0x88, 0xa0, 0x8a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0x94, 0x24, 0x00, 0x28, 0x40,
+    0xaa, 0xaa, 0x20, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x02, 0x20, 0x88, 0x00, 0x2a,
+    0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0x20, 0x80, 0x00, 0x02, 0xaa, 0x2a, 0xaa, 0x80,
+    0x80, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x2a, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x00,
+    0xaa, 0x8a, 0x8a, 0xa0, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0x24, 0x00, 0x28, 0x40,
+    0xaa, 0xaa, 0xa0, 0x00, 0x20, 0xaa, 0xaa, 0x80, 0x80, 0x00, 0x00, 0x22, 0x20, 0x88, 0x00, 0x2a,
+    0x22, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x28, 0xa0, 0xa0, 0xa8, 0x02, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x2a, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa5, 0xa6, 0xaa, 0xaa, 0x95, 0x24, 0x00, 0x26, 0x40,
+    0xaa, 0xaa, 0xa0, 0x00, 0x20, 0xaa, 0xaa, 0x42, 0x80, 0x00, 0x00, 0x22, 0x20, 0x88, 0x00, 0x2a,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0x80, 0x20, 0x28, 0xa2, 0xa8, 0xaa, 0x02, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x01, 0x20, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0xaa, 0xa8, 0x08, 0x00, 0x00, 0x20,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0xa6, 0xaa, 0xa6, 0x55, 0x56, 0x02, 0x95, 0x54,
+    0xaa, 0xaa, 0xa0, 0x04, 0x20, 0xaa, 0xa5, 0x4a, 0x80, 0x00, 0x00, 0x29, 0xa0, 0x88, 0x00, 0x26,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0x80, 0x20, 0x28, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0x2a, 0xaa, 0x82,
+    0x80, 0x00, 0x00, 0x01, 0x20, 0x80, 0x00, 0xa2, 0xaa, 0xaa, 0xaa, 0x98, 0x88, 0x80, 0x00, 0xa2,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xa5, 0xa9, 0x65, 0x55, 0x56, 0x0a, 0x95, 0x54,
+    0xaa, 0xa9, 0xa0, 0x24, 0x20, 0xa9, 0x55, 0x4a, 0x80, 0x00, 0x00, 0x29, 0xa0, 0x88, 0x00, 0x25,
+    0x2a, 0x80, 0x00, 0x02, 0x20, 0xa0, 0x28, 0x28, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0x2a, 0xaa, 0x80,
+    0xa0, 0x00, 0x00, 0x01, 0x20, 0xa2, 0xa8, 0xa9, 0xaa, 0xaa, 0xaa, 0x94, 0x88, 0xa2, 0xa8, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xa5, 0x95, 0x55, 0x55, 0x56, 0x0a, 0x95, 0x54,
+    0xaa, 0x95, 0xa8, 0xa4, 0x20, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x29, 0xa2, 0x88, 0x00, 0x15,
+    0x2a, 0x80, 0x00, 0x01, 0x20, 0xa8, 0x28, 0x14, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x81,
+    0xa0, 0x00, 0x00, 0x01, 0xa2, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x94, 0x88, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0x95, 0x55, 0x55, 0x55, 0x56, 0x89, 0x55, 0x54,
+    0xaa, 0x95, 0x98, 0x94, 0xa0, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x25, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x00, 0x21, 0x20, 0xaa, 0xa8, 0x94, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0xa1,
+    0xa0, 0x22, 0xa0, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x94, 0xa8, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xa9, 0xa6, 0x55, 0xaa, 0xaa, 0xa9, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x14,
+    0xa9, 0x55, 0x9a, 0x98, 0xa8, 0xa9, 0x55, 0x45, 0xa0, 0x00, 0x00, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x00, 0x21, 0xa2, 0xaa, 0xaa, 0x94, 0xaa, 0xaa, 0xaa, 0x89, 0xaa, 0xaa, 0xa9, 0xa1,
+    0xa2, 0x2a, 0xa0, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x56, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xa9, 0xa6, 0x55, 0xaa, 0xaa, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x14,
+    0xa5, 0x55, 0x5a, 0xa8, 0xa8, 0x95, 0x55, 0x45, 0xa0, 0x82, 0x20, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x20, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x85, 0x69,
+    0xaa, 0x2a, 0xa8, 0x29, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x95,
+    0xa9, 0xa9, 0xa5, 0x55, 0xa6, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0xa5, 0x41, 0x14,
+    0x95, 0x55, 0x6a, 0x68, 0xa8, 0x55, 0x55, 0x85, 0xa8, 0xaa, 0xa0, 0x15, 0xaa, 0x88, 0x00, 0x15,
+    0xaa, 0x80, 0x20, 0x21, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xa9, 0x55, 0x69,
+    0xaa, 0x2a, 0xaa, 0x15, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x95,
+    0x99, 0xa9, 0x65, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x65, 0x40, 0x14,
+    0x95, 0x55, 0x66, 0x58, 0xa8, 0x55, 0x5a, 0x85, 0xa8, 0xaa, 0xa0, 0x15, 0xaa, 0xa8, 0xa0, 0x15,
+    0xaa, 0x62, 0xa8, 0x19, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x95, 0xaa, 0x95, 0x55, 0x55,
+    0xaa, 0x2a, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0xa9, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0x95, 0x65, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x91, 0x55, 0x40, 0x14,
+    0x95, 0x56, 0xa5, 0x94, 0xa8, 0x56, 0xaa, 0x85, 0xa8, 0xaa, 0xa0, 0x95, 0xaa, 0xa8, 0xa0, 0x15,
+    0xaa, 0x6a, 0xaa, 0x15, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x55, 0x55, 0x89, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x55, 0x99, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x50, 0x00, 0x14,
+    0x55, 0x6a, 0x89, 0x94, 0x98, 0x6a, 0xaa, 0x85, 0xaa, 0xaa, 0xa0, 0x95, 0xaa, 0xa6, 0xa0, 0x15,
+    0x8a, 0x6a, 0xaa, 0x95, 0xaa, 0xaa, 0x96, 0x55, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0xa6, 0xaa, 0xa5, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x89, 0x50, 0x00, 0x14,
+    0x56, 0xaa, 0x9a, 0x14, 0x98, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x66, 0xa2, 0x15,
+    0x99, 0x6a, 0xaa, 0x95, 0xaa, 0x6a, 0x95, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0xa6, 0xa9, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0xa9, 0x10, 0x00, 0x04,
+    0x5a, 0xaa, 0x12, 0x54, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0x66, 0xaa, 0x15,
+    0x95, 0x6a, 0xaa, 0x95, 0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55, 0xa9, 0x10, 0x00, 0x04,
+    0x6a, 0xaa, 0x55, 0x54, 0xaa, 0xaa, 0xaa, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0x9a, 0x66, 0xaa, 0x15,
+    0x95, 0x6a, 0xaa, 0x95, 0x9a, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55,
+    0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x8a, 0x55, 0x55, 0x55, 0xaa, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0x55, 0x55, 0x9a, 0x96, 0xaa, 0x41, 0x6a, 0xaa, 0xaa, 0x55, 0x99, 0x66, 0xaa, 0x95,
+    0x95, 0x6a, 0x95, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x59, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0x99, 0x65, 0xaa, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0x55, 0x55, 0x96, 0x56, 0xa5, 0x51, 0x6a, 0x69, 0x8a, 0x55, 0x95, 0x66, 0xaa, 0x95,
+    0x95, 0x69, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00,
+    0xaa, 0xa8, 0x55, 0x55, 0x96, 0x55, 0x55, 0x55, 0x6a, 0x65, 0x58, 0x55, 0x95, 0x66, 0x59, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00,
+    0xa9, 0x45, 0x55, 0x45, 0x96, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00,
+    0xa5, 0x55, 0x55, 0x41, 0x96, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x95, 0x55, 0x55, 0x41, 0x56, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x45, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0x56, 0x59, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x2a, 0x55, 0x55, 0x55, 0x61, 0x55, 0x55, 0x55, 0x54,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x42, 0x55, 0x55, 0x55, 0x56,
+    0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x51, 0x59, 0x55, 0x55, 0x2a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x51,
+    0x55, 0x55, 0x55, 0x56, 0x55, 0x15, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0x6a, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0x6a, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x00, 0x15, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x41,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0x6a, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x10, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x40, 0x55, 0x55, 0x55, 0x42,
+    0x6a, 0x95, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x59, 0x15, 0x55, 0x55, 0x82, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x99, 0x50, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x55, 0x6a, 0x00, 0x00, 0x00, 0x00, 0xaa, 0x99, 0x55, 0x6a,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x0a, 0x08, 0x80, 0x00, 0x00, 0x00, 0x08, 0x00, 0x80, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa0, 0x80, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x00, 0x12, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1a, 0x20, 0x00, 0x00, 0x01, 0x80, 0x00, 0x00, 0x88, 0x00, 0x00, 0x00, 0x00, 0x10, 0x80, 0x00,
+    0x20, 0x0a, 0x88, 0x82, 0x80, 0x00, 0x05, 0x08, 0x0a, 0xa0, 0x00, 0x20, 0x20, 0x00, 0x00, 0x20,
+    0x00, 0x80, 0x00, 0x08, 0x80, 0x28, 0x08, 0x80, 0x2a, 0x80, 0xa0, 0x28, 0x20, 0x00, 0x80, 0x04,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x11, 0x22, 0xa2, 0x00, 0x04, 0x04, 0x00, 0x00,
+    0x2a, 0xa0, 0x20, 0x00, 0x22, 0xa0, 0x00, 0x00, 0x98, 0x12, 0x00, 0x0a, 0x18, 0xa0, 0x80, 0x00,
+    0x28, 0x0a, 0x8a, 0x82, 0x88, 0x88, 0x09, 0x28, 0x0a, 0xa2, 0x20, 0x20, 0x20, 0x00, 0x02, 0xa0,
+    0x00, 0xa4, 0x00, 0x08, 0xa8, 0xa8, 0xa8, 0xa0, 0xaa, 0xaa, 0xaa, 0xa8, 0x20, 0x02, 0x88, 0x84,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0x80, 0x20, 0x00, 0x22, 0x22, 0xa2, 0x28, 0x04, 0x08, 0x00, 0x00,
+    0x2a, 0xa0, 0x28, 0x00, 0x2a, 0xa0, 0x20, 0x0a, 0x98, 0x56, 0x02, 0x8a, 0xa8, 0xa4, 0x80, 0x00,
+    0x28, 0x0a, 0xaa, 0x82, 0x88, 0xaa, 0x0a, 0x28, 0x2a, 0xaa, 0xa8, 0x22, 0x20, 0x40, 0xa2, 0xa2,
+    0x12, 0xa8, 0xa0, 0x28, 0xa8, 0xaa, 0xaa, 0xa0, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0x82, 0xa8, 0x84,
+    0xa8, 0xa0, 0x00, 0x00, 0x08, 0xa0, 0x20, 0x00, 0x26, 0x22, 0xa2, 0x28, 0x05, 0x08, 0x00, 0x00,
+    0x2a, 0xa0, 0x28, 0x00, 0x2a, 0xa0, 0x28, 0x0a, 0x99, 0xaa, 0x82, 0x8a, 0xaa, 0xa8, 0xa2, 0x00,
+    0x28, 0x8a, 0xaa, 0x82, 0xaa, 0xaa, 0xaa, 0x28, 0x2a, 0xaa, 0xa8, 0xa2, 0x20, 0xa6, 0xa2, 0xa2,
+    0x92, 0xaa, 0xa8, 0x28, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x98, 0x58, 0x8a, 0xa8, 0x94,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xa0, 0x20, 0x00, 0x2a, 0x66, 0xa2, 0x28, 0x09, 0x0a, 0x00, 0x00,
+    0x2a, 0xa2, 0x28, 0x00, 0x2a, 0xa0, 0xa8, 0x0a, 0xa9, 0xaa, 0x82, 0x8a, 0xaa, 0xaa, 0xaa, 0x00,
+    0x28, 0x8a, 0xaa, 0x82, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xa2, 0x25, 0xa6, 0xaa, 0xa2,
+    0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0x8a, 0xaa, 0x94,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0x20, 0x80, 0x2a, 0xa6, 0xa2, 0x28, 0x19, 0x0a, 0x00, 0x00,
+    0x2a, 0xa2, 0xa8, 0x00, 0x2a, 0xaa, 0xa8, 0x89, 0xaa, 0xaa, 0x8a, 0x89, 0xaa, 0xaa, 0xaa, 0x02,
+    0x2a, 0x8a, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0x81, 0x2a, 0xaa, 0xaa, 0xa1,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0xaa, 0xa9, 0x94,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0xa0, 0x82, 0xaa, 0xaa, 0xa2, 0x28, 0x9a, 0x2a, 0x00, 0x00,
+    0x2a, 0xaa, 0xa8, 0x04, 0x2a, 0xaa, 0xa8, 0xa9, 0xaa, 0xaa, 0x8a, 0x89, 0xaa, 0xaa, 0xaa, 0x82,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa4, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x95, 0x58, 0xaa, 0xa9, 0x94,
+    0xa8, 0xa0, 0x00, 0x00, 0x0a, 0xaa, 0xa8, 0x82, 0xaa, 0xaa, 0xa2, 0x28, 0x9a, 0x2a, 0x00, 0x00,
+    0xaa, 0xaa, 0xa8, 0x88, 0x2a, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x8a, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x96, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa9, 0x95, 0x5a, 0xaa, 0xa9, 0x54,
+    0xa8, 0xa0, 0x00, 0x14, 0x0a, 0xaa, 0xa8, 0x82, 0xaa, 0xaa, 0xaa, 0x28, 0xaa, 0x2a, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0x99, 0x2a, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0x55, 0x55, 0x5a, 0xaa, 0x69, 0x54,
+    0xa8, 0x50, 0x00, 0x14, 0x8a, 0xaa, 0x9a, 0x81, 0xaa, 0xaa, 0xaa, 0x16, 0xaa, 0x2a, 0x08, 0x00,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0x99,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa5, 0x55, 0xa9, 0xa5, 0x55, 0x55, 0x9a, 0xaa, 0x65, 0x50,
+    0x98, 0x50, 0x00, 0x14, 0x8a, 0xaa, 0x9a, 0x81, 0xaa, 0xaa, 0xaa, 0x15, 0xaa, 0x2a, 0x08, 0x00,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x85, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa6, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0xa9, 0x64, 0x50,
+    0x98, 0x50, 0x00, 0x28, 0x8a, 0x6a, 0x9a, 0x41, 0xaa, 0xaa, 0xa9, 0x15, 0xaa, 0xaa, 0x18, 0x80,
+    0xaa, 0xaa, 0x96, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xa9, 0x65, 0xaa, 0xaa, 0x69, 0x95,
+    0xaa, 0xaa, 0xa5, 0x59, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x80, 0x55, 0xaa, 0xaa, 0xa9, 0x55,
+    0xaa, 0xaa, 0x55, 0x55, 0xaa, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0xa9, 0x54, 0x50,
+    0x94, 0x50, 0x00, 0x28, 0xa6, 0x55, 0x55, 0x61, 0xaa, 0xaa, 0xa9, 0x15, 0xaa, 0xaa, 0x28, 0x80,
+    0xa9, 0x9a, 0x96, 0x95, 0xaa, 0xaa, 0x96, 0x55, 0xaa, 0xa9, 0x69, 0x65, 0xaa, 0xaa, 0x69, 0x95,
+    0xaa, 0xa5, 0x65, 0x55, 0xaa, 0xa6, 0x25, 0x55, 0xaa, 0x01, 0x55, 0x55, 0xaa, 0xaa, 0x59, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x9a, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa9, 0xa5, 0x54, 0x40,
+    0x94, 0xa0, 0x00, 0x28, 0xa5, 0x55, 0x55, 0x51, 0xaa, 0xa9, 0x59, 0x15, 0xaa, 0xaa, 0x68, 0xa1,
+    0xa5, 0x59, 0x55, 0x55, 0xaa, 0x5a, 0x55, 0x55, 0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xaa, 0x55, 0x55,
+    0xaa, 0xa5, 0x55, 0x55, 0xaa, 0xa5, 0x15, 0x55, 0xa9, 0x55, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa9, 0x65, 0x54, 0x40,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x55, 0x59, 0xaa, 0xa9, 0x59, 0x15, 0xaa, 0xaa, 0x68, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x65, 0x55, 0xaa, 0x55, 0x55, 0x55,
+    0xaa, 0xa5, 0x55, 0x55, 0xaa, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55,
+    0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa8, 0x65, 0x54, 0x40,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x65, 0x55, 0x8a, 0x99, 0x59, 0x15, 0xaa, 0xa5, 0xaa, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0xa5, 0x65, 0x55, 0xa5, 0x55, 0x55, 0x55,
+    0x92, 0x25, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x54, 0x40,
+    0x64, 0xa0, 0x00, 0x14, 0xa5, 0x55, 0x65, 0x95, 0x89, 0x99, 0x55, 0x15, 0xaa, 0xa5, 0xaa, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x26, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x96, 0x25, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x54, 0x40,
+    0x64, 0x50, 0x80, 0x15, 0xa5, 0x95, 0x65, 0x95, 0x99, 0x95, 0x55, 0x15, 0xaa, 0x95, 0xa6, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x26, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x14, 0x00,
+    0x58, 0x5a, 0x80, 0x15, 0xa9, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0xaa, 0x95, 0xa6, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa4, 0x55, 0x14, 0x00,
+    0x98, 0x5a, 0x80, 0x15, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0x2a, 0x95, 0xa5, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0xa6, 0x54, 0x14, 0x00,
+    0x98, 0x5a, 0x48, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x55, 0x55, 0x15, 0x25, 0x95, 0x95, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x2a, 0x55, 0x55, 0x65, 0xa6, 0x54, 0x10, 0x00,
+    0x9a, 0x55, 0x68, 0x55, 0x6a, 0xaa, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x15, 0x25, 0x55, 0x95, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x66, 0x2a, 0xaa, 0xaa, 0x6a, 0xa6, 0x10, 0x00, 0x00,
+    0x9a, 0x55, 0x64, 0x41, 0x5a, 0xaa, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x15, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xa6, 0x10, 0x08, 0x00,
+    0x9a, 0x55, 0x94, 0x81, 0x5a, 0x6a, 0x9a, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x46, 0x0a, 0x9a, 0x81, 0xa6, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65,
+    0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x45, 0x0a, 0xa9, 0x41, 0xa5, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x44, 0x85, 0x56,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x45, 0x0a, 0x65, 0x41, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x6a, 0x55, 0x55, 0x69, 0x55, 0x55, 0x55, 0x69, 0x55, 0x56, 0x11, 0x59, 0x55, 0x55, 0x55, 0x61,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x66, 0x65, 0x44, 0x42, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x46, 0x05, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x51,
+    0x55, 0xaa, 0xaa, 0x54, 0x45, 0x55, 0x55, 0x56, 0x59, 0x59, 0x11, 0x56, 0x55, 0x55, 0x14, 0x52,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x46, 0x05, 0x55, 0x41, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x61,
+    0x40, 0x55, 0x55, 0x80, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x04, 0x02, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x59, 0x55, 0x65, 0x46, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x45, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51,
+    0x40, 0x00, 0x00, 0x42, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x64, 0x0a, 0x9a, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x45, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x65, 0x55, 0x55, 0x55, 0x55, 0x64, 0x55, 0x41,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x6a, 0x2a, 0x82, 0x2a, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x44, 0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x40, 0x55, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x42, 0x95, 0x55, 0x51, 0x42, 0x55, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x50, 0x50, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x8a, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x80, 0x08, 0x82, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0x00, 0x8a, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x82, 0x20, 0x00, 0x00, 0x08, 0x20, 0x00, 0x00, 0x00,
+    0x02, 0x82, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00, 0xaa, 0x08, 0xaa, 0x08, 0x00, 0x00, 0x08, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0x20, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x82, 0xa0, 0x00, 0x00, 0x0a, 0x20, 0x00, 0x00, 0x00,
+    0x02, 0x82, 0x00, 0x2a, 0x80, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xa8, 0x00, 0x20, 0x08, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x08, 0xa0, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0xa0, 0x08, 0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x81, 0xa0, 0x00, 0x00, 0x05, 0x20, 0x00, 0x00, 0x01,
+    0x02, 0x82, 0x00, 0xa9, 0x80, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xaa, 0xa4, 0x12, 0x28, 0xaa, 0x00,
+    0xa8, 0x00, 0x0a, 0x00, 0x88, 0xa0, 0x8a, 0x02, 0x00, 0x20, 0x00, 0x20, 0x80, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x82, 0xa8, 0x08, 0x81, 0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x02, 0x0a, 0x80, 0x88, 0x00, 0x02, 0x81, 0xa0, 0x00, 0x00, 0x25, 0x28, 0x00, 0x00, 0x21,
+    0x22, 0x82, 0x00, 0xa5, 0x80, 0x00, 0x00, 0x21, 0xaa, 0xaa, 0xaa, 0xa4, 0x19, 0x2a, 0xaa, 0x80,
+    0xa8, 0x00, 0x0a, 0x00, 0x8a, 0xa8, 0x8a, 0x0a, 0x00, 0x20, 0x00, 0x28, 0x80, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x0a, 0x82, 0xaa, 0x08, 0x81, 0x08, 0x20, 0x02, 0x02, 0x08, 0x00, 0x00, 0x08,
+    0x28, 0x02, 0x0a, 0x80, 0x88, 0x08, 0x0a, 0x81, 0xa0, 0x00, 0x08, 0x25, 0x2a, 0x00, 0x0a, 0x21,
+    0x22, 0xa2, 0x00, 0x95, 0x80, 0x02, 0x00, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5, 0x19, 0xaa, 0xaa, 0x80,
+    0xa8, 0x00, 0x05, 0x00, 0x8a, 0xaa, 0x8a, 0x0a, 0x02, 0x20, 0x00, 0x28, 0x88, 0x00, 0x00, 0x02,
+    0x20, 0x00, 0x00, 0x09, 0xa2, 0xaa, 0xa8, 0x85, 0x8a, 0x28, 0x0a, 0x09, 0x88, 0x00, 0x00, 0x08,
+    0x28, 0x02, 0x8a, 0x80, 0x88, 0x0a, 0x0a, 0x81, 0xa8, 0x02, 0x08, 0x25, 0xaa, 0xaa, 0x0a, 0x21,
+    0x2a, 0xaa, 0x2a, 0x95, 0xa0, 0x02, 0x82, 0x81, 0xaa, 0xaa, 0xaa, 0x95, 0x19, 0xaa, 0xaa, 0x80,
+    0xa8, 0x00, 0x05, 0x00, 0x8a, 0xaa, 0xaa, 0x09, 0x02, 0x20, 0x08, 0x04, 0x88, 0x00, 0x00, 0x21,
+    0x28, 0x00, 0x00, 0x09, 0xaa, 0xaa, 0xa8, 0x85, 0x8a, 0xaa, 0x0a, 0x09, 0x88, 0x08, 0x00, 0x04,
+    0xaa, 0x82, 0x8a, 0x82, 0xaa, 0xaa, 0xaa, 0x81, 0xa8, 0x22, 0xa8, 0x15, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0x2a, 0x95, 0xa0, 0x22, 0x82, 0x91, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x6a, 0xaa, 0x84,
+    0xa8, 0x00, 0x05, 0x10, 0x8a, 0xaa, 0xaa, 0x09, 0x22, 0x20, 0x28, 0x14, 0x88, 0x00, 0x00, 0x25,
+    0x28, 0x00, 0x00, 0x05, 0xaa, 0xaa, 0xa8, 0x45, 0xaa, 0xaa, 0xaa, 0x05, 0x88, 0x0a, 0x00, 0x85,
+    0xaa, 0xa2, 0x8a, 0xa9, 0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0x2a, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x95, 0xa2, 0x2a, 0x8a, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0x15, 0x6a, 0xa6, 0x84,
+    0xa8, 0x00, 0x05, 0x90, 0x8a, 0xaa, 0x65, 0x09, 0xaa, 0x22, 0xaa, 0x15, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x00, 0x00, 0x25, 0xaa, 0xaa, 0xa8, 0x65, 0xaa, 0xaa, 0xaa, 0x25, 0xa8, 0x2a, 0x80, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x55, 0xa2, 0xaa, 0x8a, 0x95, 0xa9, 0xaa, 0x66, 0x55, 0x15, 0x6a, 0x56, 0x44,
+    0x94, 0x00, 0x05, 0x90, 0x8a, 0xaa, 0x65, 0x29, 0xaa, 0x22, 0xaa, 0x95, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x08, 0x00, 0x25, 0xaa, 0xaa, 0xa6, 0x65, 0xaa, 0xaa, 0xaa, 0x25, 0xaa, 0x2a, 0x8a, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x45, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0x29, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0xa9, 0xa9, 0x55, 0x55, 0x25, 0x6a, 0x55, 0x54,
+    0x94, 0x00, 0x0a, 0x64, 0x8a, 0xaa, 0x65, 0x19, 0xaa, 0x22, 0xaa, 0x95, 0x88, 0x00, 0x00, 0x25,
+    0xa8, 0x0a, 0x0a, 0x15, 0xaa, 0x6a, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x8a, 0xa5,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xa9, 0x69, 0xaa, 0x55, 0xaa, 0xaa, 0xaa, 0x55, 0x99, 0xa5, 0x55, 0x55, 0x21, 0x15, 0x55, 0x54,
+    0x94, 0x00, 0x0a, 0x64, 0xaa, 0x9a, 0x65, 0x19, 0xaa, 0x22, 0xaa, 0x95, 0x8a, 0x00, 0x00, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xaa, 0x5a, 0xa6, 0x55, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x95,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa8, 0x55, 0x9a, 0xaa, 0xa6, 0x55, 0x9a, 0xaa, 0xa5, 0x55,
+    0xa9, 0x69, 0xaa, 0x55, 0xaa, 0xa9, 0x6a, 0x55, 0x95, 0x55, 0x55, 0x55, 0x22, 0x15, 0x55, 0x54,
+    0x94, 0x00, 0x0a, 0xa5, 0xaa, 0x5a, 0x65, 0x15, 0xaa, 0x22, 0xaa, 0x95, 0xaa, 0xa0, 0x08, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xaa, 0x55, 0x56, 0x55, 0xaa, 0xaa, 0xa9, 0x95, 0xaa, 0xaa, 0xaa, 0x15,
+    0xaa, 0xaa, 0xaa, 0x55, 0xaa, 0xaa, 0xa5, 0x55, 0x9a, 0xa9, 0x56, 0x55, 0x9a, 0xa1, 0x55, 0x55,
+    0xa9, 0x69, 0x95, 0x55, 0xaa, 0xa9, 0x69, 0x55, 0x95, 0x55, 0x55, 0x55, 0x22, 0x15, 0x55, 0x54,
+    0x68, 0x00, 0x0a, 0x95, 0xa6, 0x55, 0x55, 0x15, 0xaa, 0x12, 0x8a, 0x15, 0xaa, 0xa0, 0x28, 0x15,
+    0xa8, 0x2a, 0x8a, 0x15, 0xa9, 0x55, 0x56, 0x55, 0xaa, 0xaa, 0xa5, 0x95, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xa9, 0xa5, 0x55, 0xaa, 0xa5, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55, 0x91, 0x55, 0x55, 0x55,
+    0x99, 0x55, 0x55, 0x55, 0x9a, 0xa9, 0x65, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa2, 0x15, 0x55, 0x54,
+    0x68, 0x00, 0x0a, 0x55, 0xa6, 0x55, 0x9a, 0x15, 0xaa, 0x12, 0x06, 0x55, 0xaa, 0xaa, 0x28, 0x15,
+    0xa8, 0x2a, 0xaa, 0x15, 0xa9, 0x55, 0x56, 0x55, 0xaa, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xaa, 0xa5, 0x55, 0x55, 0x9a, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x99, 0x55, 0x55, 0x55, 0x99, 0x95, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa2, 0x15, 0x55, 0x54,
+    0x68, 0x00, 0x05, 0x55, 0xa5, 0x55, 0x9a, 0x25, 0xa9, 0x91, 0x55, 0x55, 0xaa, 0xaa, 0x28, 0x15,
+    0xa8, 0x2a, 0xaa, 0x95, 0x59, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x6a, 0x55,
+    0xaa, 0xa9, 0x65, 0x55, 0xa4, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x15, 0x55, 0x54,
+    0x68, 0x00, 0x05, 0x55, 0xa5, 0x65, 0x9a, 0x25, 0x99, 0x91, 0x55, 0x55, 0xaa, 0xaa, 0xa8, 0x95,
+    0x9a, 0x25, 0x65, 0x95, 0x51, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xa6, 0x85, 0x65, 0x55,
+    0x9a, 0x29, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x15, 0x55, 0x54,
+    0x54, 0x00, 0x05, 0x55, 0xa9, 0xa5, 0x9a, 0x25, 0x99, 0x91, 0x55, 0x55, 0xa6, 0xaa, 0xaa, 0x95,
+    0x96, 0xa5, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x85, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x96, 0x55, 0x55, 0x55, 0xaa, 0x95, 0x11, 0x54,
+    0x94, 0x00, 0x05, 0x55, 0x69, 0xaa, 0xaa, 0x25, 0x95, 0x99, 0x55, 0x55, 0xa6, 0xaa, 0x96, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x56, 0xa6, 0x55, 0x55, 0x59, 0xaa, 0x95, 0x21, 0x54,
+    0x94, 0x00, 0x85, 0x51, 0x6a, 0xaa, 0xaa, 0xa5, 0x95, 0x99, 0x55, 0x55, 0x65, 0x59, 0x96, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x66, 0xaa, 0x56, 0x99, 0x6a, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x94, 0x22, 0x85, 0x51, 0x6a, 0xaa, 0x20, 0x95, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x66, 0x56, 0x95, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x96, 0x2a, 0x65, 0x01, 0xa2, 0x8a, 0x65, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x96, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56,
+    0x15, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x96, 0xa9, 0x50, 0x01, 0xa6, 0x0a, 0x65, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x4a, 0x55, 0x55, 0x55, 0x55,
+    0x66, 0x96, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x42, 0x95, 0x90, 0x01, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x52, 0x65, 0x54, 0x01, 0x2a, 0x65, 0x55, 0x55, 0x6a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x41, 0x56, 0xa0, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x64, 0x66, 0x6a, 0xa9, 0x50, 0x55, 0x55, 0x55, 0x64, 0x55, 0x55, 0x55, 0x5a,
+    0x55, 0x55, 0x55, 0x55, 0x45, 0x50, 0x00, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x41, 0x6a, 0x60, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x56,
+    0x55, 0x5a, 0x95, 0x5a, 0x55, 0x15, 0x55, 0x20, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55, 0x95,
+    0x55, 0x55, 0x55, 0x56, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x42, 0xa9, 0x50, 0x05, 0x55, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55,
+    0x59, 0x65, 0x6a, 0xa6, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x41, 0x50, 0x15, 0x6a,
+    0x45, 0x54, 0x55, 0x91, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x42, 0x95, 0x50, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x51, 0x65, 0x55, 0x55, 0x54,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x45, 0x54, 0x10, 0x6a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x41, 0x55, 0x50, 0x15, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xa2, 0xaa, 0xa2, 0x52, 0xa6, 0x65, 0x92,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x41, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xa2,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x40, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x40, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x84, 0x50, 0x05, 0x0a, 0x40, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00,
+    0x80, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x2a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0xa5, 0x50, 0x15, 0x48, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x45, 0x00, 0x00, 0x00, 0x00, 0x81, 0x51, 0x11, 0x68,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x54, 0x45, 0x55, 0x55, 0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0a, 0x82, 0x02, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x80, 0x02, 0x08,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0a, 0x8a, 0x82, 0x00, 0x40, 0x08, 0x00, 0x40, 0x00, 0x80, 0x22, 0x08,
+    0x00, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x04, 0x00, 0x00, 0x11, 0x40, 0x20, 0x00,
+    0x81, 0x00, 0x00, 0x20, 0x28, 0x08, 0x00, 0x82, 0x00, 0x20, 0x80, 0x80, 0x00, 0x00, 0x02, 0x80,
+    0x80, 0x00, 0x00, 0x00, 0x6a, 0x8a, 0x8a, 0x00, 0x90, 0x08, 0x01, 0x40, 0x00, 0x80, 0x22, 0x08,
+    0x00, 0x28, 0x88, 0x0a, 0x80, 0x08, 0x00, 0x00, 0x00, 0x08, 0x02, 0x00, 0x00, 0x08, 0x02, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x14, 0x14, 0x00, 0x00, 0x21, 0x50, 0x20, 0x80,
+    0x81, 0x18, 0x00, 0xa0, 0x29, 0x0a, 0x02, 0x81, 0x2a, 0xa2, 0xaa, 0xa0, 0x00, 0x2a, 0x02, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x00, 0xa0, 0x08, 0x02, 0x80, 0x80, 0x88, 0x22, 0x08,
+    0x20, 0xa8, 0x89, 0x0a, 0x80, 0xa8, 0x00, 0x02, 0xaa, 0x8a, 0x0a, 0x08, 0x00, 0x08, 0x02, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x08, 0x08, 0x80, 0x00, 0x24, 0x54, 0x22, 0x80, 0x25, 0x52, 0x2a, 0xa0,
+    0x86, 0xa8, 0x20, 0xa8, 0xaa, 0x2a, 0x82, 0x81, 0x2a, 0xaa, 0xaa, 0xa0, 0x00, 0xaa, 0x22, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0x8a, 0x2a, 0x80, 0x80, 0x88, 0x22, 0x08,
+    0x22, 0xaa, 0x8a, 0x0a, 0x82, 0xa8, 0x00, 0x02, 0xaa, 0xaa, 0x0a, 0x0a, 0x00, 0x08, 0x02, 0x28,
+    0x00, 0x00, 0x00, 0x02, 0x2a, 0x8a, 0x80, 0x00, 0xa5, 0xaa, 0xa2, 0xa0, 0x26, 0x96, 0xaa, 0xa0,
+    0x8a, 0xa8, 0xa0, 0xa8, 0xaa, 0x2a, 0x8a, 0x81, 0x2a, 0xaa, 0xaa, 0xa8, 0x00, 0xaa, 0x22, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0x8a, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x0a, 0xa2, 0xa8, 0x02, 0x02, 0xaa, 0xaa, 0x2a, 0x0a, 0x00, 0x08, 0x02, 0x2a,
+    0x00, 0x00, 0x00, 0x02, 0x2a, 0xaa, 0x80, 0x00, 0xa9, 0xaa, 0xa2, 0xa0, 0x26, 0xa6, 0xaa, 0xa0,
+    0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x2a, 0x8a, 0x41, 0xaa, 0xaa, 0xaa, 0xa8, 0x08, 0xaa, 0x12, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0x8a, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x29, 0xa2, 0xa8, 0x02, 0x02, 0xaa, 0xaa, 0xaa, 0x0a, 0x00, 0x08, 0x02, 0x2a,
+    0x00, 0x00, 0x08, 0x80, 0x2a, 0xaa, 0x8a, 0x22, 0xaa, 0xaa, 0xaa, 0xa0, 0x2a, 0xaa, 0xaa, 0xa0,
+    0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0xaa, 0x12, 0x40,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x80, 0x88, 0x22, 0x24,
+    0xaa, 0xaa, 0x8a, 0x25, 0xaa, 0xaa, 0x0a, 0x02, 0xaa, 0xaa, 0xaa, 0x09, 0x20, 0x08, 0x02, 0x28,
+    0x00, 0x02, 0x0a, 0xa0, 0x2a, 0xaa, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0xa0, 0x2a, 0xaa, 0xaa, 0xa8,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xaa, 0xa8, 0x28, 0xaa, 0x11, 0x44,
+    0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0x8a, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x81, 0x88, 0xa2, 0x24,
+    0xaa, 0xaa, 0x8a, 0x25, 0xaa, 0xaa, 0x0a, 0x21, 0xaa, 0xaa, 0xaa, 0x05, 0x2a, 0x8a, 0x02, 0x28,
+    0x00, 0x82, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xa8,
+    0xaa, 0xaa, 0xaa, 0x95, 0xa6, 0xaa, 0xa9, 0x55, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0x91, 0x44,
+    0x80, 0x00, 0x00, 0x20, 0xaa, 0xaa, 0x89, 0x80, 0xaa, 0xaa, 0xaa, 0x80, 0x82, 0x88, 0xaa, 0x24,
+    0xaa, 0xaa, 0xaa, 0x25, 0xaa, 0xaa, 0x0a, 0x21, 0xaa, 0xaa, 0xaa, 0x05, 0x2a, 0xaa, 0x82, 0x04,
+    0x20, 0xa2, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x29, 0xaa, 0xaa, 0xaa, 0xa8, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x55, 0x96, 0xa5, 0x65, 0x55, 0xaa, 0xaa, 0xaa, 0x54, 0x2a, 0xaa, 0x91, 0x44,
+    0x80, 0x00, 0x00, 0x10, 0xaa, 0xaa, 0x89, 0x80, 0xaa, 0xaa, 0xaa, 0xa0, 0xa2, 0xa8, 0x9a, 0x14,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x8a, 0x21, 0xaa, 0xaa, 0xaa, 0x85, 0x2a, 0xaa, 0x8a, 0x15,
+    0x20, 0xaa, 0xaa, 0xa1, 0x2a, 0xaa, 0xaa, 0x25, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x5a,
+    0xaa, 0xaa, 0xaa, 0x55, 0x96, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0x56, 0x2a, 0x99, 0x91, 0x44,
+    0x80, 0x00, 0x00, 0x94, 0xaa, 0xa9, 0x45, 0x44, 0xaa, 0xaa, 0xaa, 0xa0, 0xa2, 0xa8, 0x99, 0x16,
+    0xaa, 0xa6, 0xa6, 0x95, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xa9, 0xa5, 0xaa, 0xaa, 0xa9, 0x95,
+    0x2a, 0xaa, 0xaa, 0x69, 0x2a, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0x5a, 0xaa, 0xaa, 0x9a, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0x95, 0x95, 0x55, 0x55, 0xaa, 0xaa, 0x55, 0x56, 0x1a, 0x95, 0x99, 0x04,
+    0x80, 0x00, 0x00, 0x64, 0xa9, 0x65, 0x45, 0x48, 0xaa, 0xaa, 0xa9, 0xa2, 0xa2, 0x6a, 0x99, 0x15,
+    0xaa, 0xa5, 0x65, 0x95, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xa5, 0xa5, 0xaa, 0xaa, 0xa9, 0x95,
+    0xaa, 0xaa, 0xaa, 0x59, 0x2a, 0xaa, 0x6a, 0x95, 0xaa, 0xaa, 0x99, 0x55, 0xaa, 0xaa, 0x95, 0x55,
+    0xaa, 0xaa, 0x9a, 0x55, 0x95, 0x95, 0x55, 0x55, 0xaa, 0x99, 0x55, 0x55, 0x19, 0x55, 0x89, 0x04,
+    0x40, 0x00, 0x00, 0xa4, 0xa5, 0x65, 0x45, 0x4a, 0xaa, 0xaa, 0xa9, 0xa1, 0xa2, 0x6a, 0x99, 0x15,
+    0xa9, 0x95, 0x65, 0x95, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xa5, 0x95, 0xa5, 0xaa, 0xa6, 0xa9, 0x95,
+    0xaa, 0xaa, 0xa6, 0x55, 0x2a, 0xaa, 0x6a, 0x95, 0xaa, 0xaa, 0x59, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xaa, 0xa6, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x99, 0x55, 0x55, 0x15, 0x55, 0x49, 0x14,
+    0x40, 0x00, 0x00, 0x54, 0xa5, 0x65, 0x45, 0x88, 0xaa, 0xaa, 0xa9, 0xa1, 0xaa, 0x66, 0x11, 0x15,
+    0xa9, 0x55, 0x65, 0x55, 0xaa, 0x96, 0xa9, 0x95, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa6, 0xa9, 0x15,
+    0xaa, 0xaa, 0x95, 0x55, 0x26, 0xa5, 0x65, 0x95, 0xaa, 0xa9, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x14,
+    0x40, 0x00, 0x00, 0x55, 0xa5, 0x55, 0x46, 0x89, 0xaa, 0xa6, 0xa5, 0x61, 0xaa, 0x66, 0x51, 0x15,
+    0x95, 0x55, 0x55, 0x55, 0x28, 0x56, 0xa5, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xa6, 0xa9, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0xa9, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x14,
+    0xa0, 0x00, 0x00, 0x55, 0x95, 0x56, 0x8a, 0x99, 0xaa, 0xaa, 0x9a, 0x51, 0xaa, 0x66, 0x55, 0x95,
+    0x95, 0x55, 0x55, 0x55, 0x29, 0x55, 0xa5, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xa5, 0x45, 0x55,
+    0xaa, 0xa9, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x59, 0x55, 0x55, 0x9a, 0xa9, 0x55, 0x55,
+    0xa5, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x45, 0x14,
+    0xa0, 0x00, 0x00, 0x55, 0x9a, 0x9a, 0x8a, 0x65, 0xaa, 0xa5, 0x56, 0x51, 0x6a, 0x56, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x55, 0x55,
+    0xaa, 0x59, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x96, 0x95, 0x55, 0x55, 0x55, 0x05, 0x55, 0x45, 0x14,
+    0xa0, 0x00, 0x20, 0x51, 0x5a, 0xaa, 0x8a, 0x65, 0xa5, 0x65, 0x56, 0x59, 0x69, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x85, 0x55, 0x45, 0x10,
+    0x60, 0x28, 0xa0, 0x51, 0x6a, 0xaa, 0x8a, 0x65, 0x95, 0x55, 0x56, 0x55, 0x59, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x84, 0x55, 0x44, 0x10,
+    0x6a, 0xaa, 0xa8, 0x11, 0x6a, 0xaa, 0x8a, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x45, 0x44, 0x10,
+    0x5a, 0xaa, 0x64, 0x01, 0xa6, 0x99, 0x45, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xa6, 0x44, 0x46, 0x10,
+    0x5a, 0x95, 0x56, 0x01, 0x95, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x99, 0x01, 0x95, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x56, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x95, 0x95, 0x55, 0x55, 0x51, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x65, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x25, 0x6a, 0x99, 0x01, 0x15, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x65,
+    0x56, 0x5a, 0x56, 0x56, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x65,
+    0x95, 0x55, 0x41, 0x56, 0x95, 0x55, 0x55, 0x41, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x5a, 0x66, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x2a, 0xaa, 0x56, 0x05, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x95, 0x61,
+    0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55, 0x59, 0x55, 0x55, 0x54, 0x55,
+    0x55, 0x54, 0x00, 0x15, 0x91, 0x55, 0x15, 0x42, 0x55, 0x55, 0x24, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x2a, 0x95, 0x56, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x65, 0x49, 0x91,
+    0x55, 0x55, 0x54, 0x44, 0x55, 0x55, 0x55, 0x49, 0x15, 0x55, 0x55, 0x54, 0x55, 0x51, 0x54, 0x0a,
+    0x55, 0x04, 0x00, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0x55, 0x54, 0x10, 0x55, 0x65, 0x56, 0x9a, 0x55,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0xa2, 0x1a, 0x06, 0x50,
+    0x68, 0xa0, 0xa8, 0xaa, 0x55, 0x45, 0x55, 0x06, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x45, 0x04, 0x00, 0xaa, 0x55, 0x55, 0x65, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x51, 0x05, 0x01, 0x02,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x6a, 0x00, 0x00, 0x00, 0x00,
+    0x80, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x2a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x06, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x15, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x58, 0x55, 0x55, 0x55, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x58, 0x55, 0x55, 0x55, 0x00, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x6a, 0xaa, 0xa9, 0xa4, 0x55, 0x55, 0x55, 0x00, 0x15, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x54, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x80, 0x00, 0x08, 0x02,
+    0x00, 0x80, 0x00, 0x08, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x00, 0x00, 0xa2, 0x88, 0x20, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x08, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x80, 0x20, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x82, 0x00, 0x00, 0xa8, 0x00, 0x08, 0x82,
+    0x00, 0x80, 0x20, 0x0a, 0x92, 0x00, 0x00, 0x8a, 0x2a, 0x20, 0xa2, 0xa8, 0x00, 0x00, 0x00, 0x08,
+    0x02, 0x00, 0x00, 0x00, 0xa2, 0x8a, 0x20, 0x05, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x08, 0x05, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x00, 0x80, 0x20, 0x00, 0x02, 0xa0, 0xa0, 0x00, 0x00, 0x82, 0x00, 0x02, 0xaa, 0x20, 0x08, 0x82,
+    0x84, 0x80, 0xa0, 0x0a, 0xa2, 0x84, 0x00, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x08,
+    0x82, 0x00, 0x00, 0x00, 0xa2, 0xaa, 0xa0, 0x05, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x29, 0x15, 0x42, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x02, 0xa0, 0x20, 0x80, 0x02, 0xaa, 0xa8, 0x00, 0x00, 0x82, 0x00, 0x02, 0xaa, 0xa8, 0x2a, 0x82,
+    0x9a, 0x90, 0xa8, 0x0a, 0xa6, 0xa8, 0x00, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x22, 0x00, 0x80, 0x28,
+    0x82, 0x00, 0x00, 0x00, 0xa2, 0xaa, 0xa8, 0x09, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x02,
+    0x29, 0x1a, 0x46, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x0a,
+    0x02, 0xaa, 0x28, 0x80, 0x2a, 0xaa, 0xa8, 0x00, 0x02, 0x82, 0x00, 0x02, 0xaa, 0xa8, 0x2a, 0x8a,
+    0xaa, 0xa8, 0xaa, 0x0a, 0xaa, 0xaa, 0x08, 0x8a, 0x2a, 0xaa, 0xaa, 0xaa, 0x22, 0x8a, 0x88, 0x24,
+    0x82, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x09, 0x00, 0x00, 0x00, 0x90, 0x02, 0xa0, 0x08, 0x02,
+    0x2a, 0x2a, 0x86, 0x20, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x80, 0x2a, 0xaa, 0xa8, 0x20, 0x02, 0x82, 0x00, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xa8, 0xaa, 0x0a, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x1a, 0x8a, 0x8a, 0xa4,
+    0x82, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xa8, 0x0a, 0x80, 0x00, 0x00, 0x90, 0x02, 0xaa, 0x2a, 0x02,
+    0x2a, 0xaa, 0x8a, 0x20, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xa2, 0xa2, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0x1a, 0xaa, 0x4a, 0x54,
+    0x82, 0x00, 0x00, 0x08, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x02, 0x00, 0x90, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa0, 0x08, 0x00, 0x00, 0x02, 0x00, 0x02, 0x02, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xa8, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x16, 0xaa, 0x46, 0x54,
+    0x82, 0x00, 0x00, 0x08, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x02, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x00, 0x02, 0x82, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0x8a,
+    0xaa, 0xaa, 0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa4, 0x15, 0x9a, 0x65, 0x54,
+    0x82, 0x00, 0x00, 0x28, 0xaa, 0xaa, 0xaa, 0x0a, 0x80, 0x22, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x02,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x00, 0x02, 0x82, 0x20, 0x00, 0x00, 0x00, 0x0a,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0x20, 0x2a, 0xaa, 0xaa, 0x02, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0x55, 0x15, 0x95, 0x65, 0x54,
+    0x81, 0x00, 0x00, 0x28, 0xaa, 0xaa, 0xaa, 0x0a, 0xa2, 0x22, 0x00, 0xaa, 0x02, 0xaa, 0xaa, 0x8a,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x0a, 0x02, 0x82, 0x20, 0x00, 0x0a, 0x00, 0x09,
+    0x2a, 0xaa, 0xaa, 0x82, 0x2a, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa2, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x81, 0x00, 0x80, 0xa8, 0xaa, 0xaa, 0x9a, 0x2a, 0xa2, 0xa2, 0x02, 0xaa, 0x02, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xaa, 0xa8, 0x28, 0x00, 0x00, 0x02, 0x0a, 0x82, 0x82, 0xa0, 0x00, 0x8a, 0x80, 0x09,
+    0x2a, 0xaa, 0xaa, 0x22, 0x2a, 0xaa, 0xaa, 0xa2, 0xaa, 0xaa, 0xaa, 0x01, 0xaa, 0xaa, 0xa2, 0x21,
+    0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0xa9, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x81, 0x00, 0x80, 0xa8, 0xaa, 0x66, 0x9a, 0x2a, 0xa2, 0xa2, 0x22, 0xaa, 0x02, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xa9, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0x2a, 0xa2, 0xa0, 0x80, 0x02, 0x8a, 0x80, 0x05,
+    0x2a, 0xaa, 0x8a, 0x20, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa1, 0xaa, 0xaa, 0xa0, 0x61,
+    0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0xaa, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x81, 0x00, 0x80, 0x94, 0xaa, 0x65, 0x56, 0x26, 0xa2, 0xaa, 0x22, 0xaa, 0x82, 0xaa, 0xaa, 0xa9,
+    0x2a, 0xaa, 0xa9, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x82, 0x02, 0x8a, 0x82, 0x05,
+    0x2a, 0xaa, 0x8a, 0x21, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa1, 0xaa, 0xaa, 0xa0, 0x65,
+    0xaa, 0xaa, 0x20, 0x85, 0xaa, 0xaa, 0xaa, 0x65, 0xaa, 0x95, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x41, 0x00, 0x40, 0x94, 0x69, 0x65, 0x56, 0x26, 0xa2, 0xaa, 0x22, 0xaa, 0x82, 0xaa, 0xaa, 0xa1,
+    0x2a, 0xaa, 0xaa, 0xaa, 0xa8, 0x00, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xa2, 0x05,
+    0x2a, 0xaa, 0x8a, 0x21, 0x2a, 0xaa, 0xaa, 0x9a, 0xaa, 0x28, 0xaa, 0xa9, 0xaa, 0xaa, 0x80, 0x65,
+    0xaa, 0x2a, 0x00, 0x85, 0xaa, 0xaa, 0xaa, 0x65, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x42, 0x00, 0x48, 0xa4, 0x59, 0x55, 0x55, 0xa9, 0xa6, 0xaa, 0x22, 0x69, 0x82, 0xaa, 0xaa, 0x25,
+    0x2a, 0xaa, 0xaa, 0x9a, 0xa8, 0x80, 0x00, 0x01, 0xaa, 0xaa, 0xa8, 0x8a, 0xaa, 0xaa, 0xaa, 0x05,
+    0x2a, 0xaa, 0x8a, 0x29, 0x2a, 0xaa, 0x56, 0x99, 0xaa, 0x28, 0xa8, 0xa9, 0xaa, 0x82, 0x80, 0x65,
+    0xaa, 0x2a, 0x00, 0x85, 0xaa, 0xaa, 0xa6, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x42, 0x00, 0x48, 0x64, 0x59, 0x59, 0x65, 0xa9, 0xa6, 0xaa, 0x22, 0x69, 0x82, 0xaa, 0xa0, 0x65,
+    0x2a, 0xaa, 0xa9, 0x56, 0xaa, 0x80, 0x00, 0x09, 0xaa, 0xa9, 0xa9, 0x8a, 0xaa, 0xaa, 0xaa, 0x05,
+    0xaa, 0x2a, 0x86, 0x69, 0x2a, 0x55, 0x55, 0x99, 0xaa, 0x69, 0x89, 0xa9, 0x96, 0x82, 0x00, 0x45,
+    0xaa, 0x22, 0x10, 0x05, 0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0x62, 0x00, 0x4a, 0x54, 0x59, 0x9a, 0xa9, 0x95, 0xaa, 0xaa, 0x22, 0x65, 0x82, 0xaa, 0xa5, 0x45,
+    0x26, 0xaa, 0xa9, 0x55, 0xaa, 0x80, 0x00, 0x09, 0xaa, 0xa9, 0x29, 0x89, 0xaa, 0xaa, 0xaa, 0x85,
+    0xaa, 0x55, 0x54, 0x69, 0x21, 0x55, 0x55, 0x55, 0xaa, 0x69, 0x45, 0xa9, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0x22, 0x10, 0x05, 0x89, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x54,
+    0xa2, 0x00, 0x8a, 0x54, 0x66, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0x22, 0x55, 0x82, 0x95, 0x55, 0x55,
+    0x16, 0xa5, 0x55, 0x55, 0xa2, 0xa0, 0x20, 0x09, 0xaa, 0xa9, 0x29, 0x09, 0xaa, 0xaa, 0xaa, 0x85,
+    0xa9, 0x55, 0x55, 0x59, 0x11, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xa1, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x25, 0x55, 0x55, 0x54,
+    0xa2, 0x00, 0x86, 0x54, 0xa6, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0x22, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x16, 0x5a, 0xa9, 0x55, 0xa2, 0xaa, 0x28, 0x01, 0xaa, 0xa9, 0x29, 0x55, 0xaa, 0xaa, 0xaa, 0x85,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x29, 0x55, 0x55, 0x54,
+    0xa1, 0x00, 0x85, 0x54, 0xaa, 0xa2, 0xaa, 0x65, 0xaa, 0xaa, 0x21, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0xa5, 0x55, 0x55, 0xa2, 0xaa, 0xa8, 0x01, 0xaa, 0xa9, 0x69, 0x55, 0xaa, 0xa5, 0x69, 0xa5,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x29, 0x45, 0x55, 0x54,
+    0xa1, 0x00, 0x85, 0x54, 0x9a, 0x95, 0x55, 0x55, 0xaa, 0x99, 0x21, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x16, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0xa2, 0xa9, 0x55, 0x55, 0xaa, 0xa5, 0x69, 0x65,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x2a, 0x65, 0x85, 0x54,
+    0x61, 0x00, 0x45, 0x54, 0x95, 0x55, 0x55, 0x55, 0xaa, 0x99, 0x61, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0xa5, 0x55, 0x55, 0x55, 0xaa, 0x65, 0x55, 0x65,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x2a, 0x65, 0x89, 0x94,
+    0x61, 0x00, 0x45, 0x50, 0x95, 0x55, 0x55, 0x55, 0x9a, 0x99, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x85, 0x95, 0x55, 0x55, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0x8a, 0xa8,
+    0x61, 0x00, 0x45, 0x10, 0x15, 0x55, 0x55, 0x55, 0x59, 0x55, 0x95, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x96, 0xaa, 0xaa, 0x65, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0xaa, 0xa8,
+    0x51, 0x20, 0x41, 0x00, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x99, 0x55, 0xa9, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x16, 0x6a, 0x94, 0x65, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x50, 0x2a, 0x40, 0x00, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x18, 0x2a, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x51, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x15, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xa9, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x28, 0xa9, 0x00, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x28, 0x95, 0x20, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x24, 0x95, 0x20, 0x01, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x99,
+    0x95, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x99,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x9a, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x14, 0x6a, 0x20, 0x01, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x65, 0x55, 0x14, 0x54, 0x56, 0x41, 0x55, 0x55, 0x56,
+    0x55, 0x55, 0x55, 0x5a, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x14, 0x6a, 0x10, 0x01, 0x55, 0x55, 0x55, 0x56, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x11,
+    0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x59, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x54,
+    0x55, 0x55, 0x55, 0x15, 0x6a, 0xaa, 0xaa, 0x99, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x18, 0x96, 0x10, 0x05, 0x55, 0x55, 0x55, 0x19, 0x55, 0x55, 0x55, 0x65, 0x55, 0x55, 0x55, 0x21,
+    0xa9, 0x55, 0x56, 0xa5, 0x55, 0x55, 0x55, 0x55, 0x66, 0xa9, 0x69, 0x54, 0x55, 0x50, 0x14, 0x1a,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x46, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x18, 0x95, 0x10, 0x45, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x55, 0x65, 0x6a, 0x6a, 0x9a, 0x11,
+    0x95, 0x55, 0x55, 0x55, 0x69, 0x55, 0x55, 0x59, 0x51, 0x56, 0x16, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x18, 0x55, 0x10, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x65, 0x56, 0x54, 0x55, 0x15, 0x45, 0x02,
+    0x80, 0x00, 0x00, 0x0a, 0x55, 0x55, 0x55, 0x16, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x15, 0x55, 0x15, 0x55, 0x00, 0x00, 0x00, 0x00, 0x59, 0x55, 0x55, 0x14, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x65, 0x45, 0x54, 0x0a, 0x2a, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x6a, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x95, 0x55, 0x55, 0x55, 0x40, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0x15, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55,
+    0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x80, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x95, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6a, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x8a, 0x80, 0x00, 0x15, 0x55, 0x5a, 0xa4, 0x44, 0x10, 0x0a, 0x41, 0x40, 0x00, 0x09, 0x54,
+    0x10, 0x50, 0x00, 0x00, 0x00, 0x00, 0x04, 0x28, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00,
+    0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x68, 0x00, 0x00, 0x40, 0x00,
+    0x00, 0x00, 0x0a, 0x54, 0x00, 0x0a, 0x80, 0x00, 0x10, 0x09, 0x60, 0x00, 0x00, 0x00, 0x00, 0x50,
+    0xaa, 0xaa, 0x8a, 0x00, 0x0a, 0x60, 0x0a, 0xa8, 0x54, 0x54, 0x8a, 0x41, 0x41, 0x02, 0x09, 0x54,
+    0x15, 0x50, 0x06, 0x24, 0x00, 0x08, 0xa4, 0x28, 0x44, 0x00, 0x09, 0xa8, 0x05, 0x00, 0x2a, 0xa8,
+    0x01, 0x10, 0x46, 0xa8, 0x04, 0x00, 0x00, 0xa8, 0x00, 0x40, 0x00, 0x54, 0x04, 0x00, 0x44, 0xa8,
+    0x00, 0x00, 0x8a, 0x54, 0x00, 0x0a, 0x8a, 0x58, 0x50, 0x19, 0x65, 0x54, 0x00, 0x00, 0x00, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x2a, 0x6a, 0xaa, 0xa8, 0x55, 0x94, 0x89, 0x55, 0x41, 0x02, 0x09, 0x54,
+    0x15, 0x50, 0x66, 0x24, 0x40, 0x04, 0xa4, 0x68, 0x44, 0x0a, 0x09, 0x54, 0x05, 0x08, 0x55, 0x54,
+    0x11, 0x14, 0x56, 0x54, 0x04, 0x40, 0x00, 0x58, 0x01, 0x40, 0x0a, 0x54, 0x04, 0x0a, 0x44, 0xa8,
+    0x01, 0x00, 0x8a, 0x54, 0x00, 0x0a, 0x6a, 0x54, 0x50, 0x19, 0x65, 0x54, 0x00, 0x00, 0x01, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x2a, 0x2a, 0xaa, 0xa8, 0x96, 0x95, 0x55, 0x55, 0x51, 0x0a, 0x05, 0x54,
+    0x15, 0x50, 0x66, 0xa4, 0x40, 0x04, 0x54, 0x54, 0x44, 0x05, 0x09, 0x54, 0x05, 0x18, 0x55, 0x54,
+    0x51, 0x14, 0x56, 0x54, 0x04, 0x40, 0x02, 0x54, 0x01, 0x40, 0x8a, 0x54, 0x04, 0x05, 0x44, 0x58,
+    0x01, 0x0a, 0x85, 0x54, 0x01, 0x05, 0x65, 0x54, 0x54, 0x15, 0x55, 0x54, 0x00, 0x00, 0x01, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x96, 0xa5, 0x55, 0x55, 0x51, 0x09, 0x05, 0x55,
+    0x15, 0x50, 0x65, 0x94, 0x44, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x54, 0x05, 0x56, 0x55, 0x54,
+    0x51, 0x14, 0x55, 0x54, 0x14, 0x40, 0x82, 0x54, 0x01, 0x40, 0x8a, 0x54, 0x04, 0x05, 0x44, 0x54,
+    0x01, 0x0a, 0x45, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x54, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x9a, 0xa6, 0x55, 0x55, 0x51, 0x49, 0x25, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x14, 0x55, 0x55, 0x14, 0x40, 0x8a, 0x54, 0x01, 0x40, 0x85, 0x54, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x0a, 0x65, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x6a, 0xaa, 0xaa, 0xa8, 0x9a, 0xa6, 0x55, 0x55, 0x51, 0x55, 0x25, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x95, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x14, 0x40, 0xa9, 0x54, 0x01, 0x40, 0x45, 0x55, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x05, 0x65, 0x54, 0x01, 0x05, 0x55, 0x54, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x50, 0x55, 0x54, 0x54, 0x14, 0x54, 0x54, 0x54, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x14, 0x40, 0x69, 0x55, 0x01, 0x40, 0x65, 0x55, 0x04, 0x15, 0x44, 0x54,
+    0x01, 0x05, 0x55, 0x54, 0x01, 0x05, 0x55, 0x55, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x52, 0x55, 0x54, 0x54, 0x14, 0x56, 0x54, 0x54, 0x15, 0x55, 0x55, 0x05, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x40, 0x65, 0x55, 0x01, 0x50, 0x65, 0x55, 0x04, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x54, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x51, 0x55, 0x15, 0x55,
+    0x15, 0x92, 0x55, 0x55, 0x55, 0x14, 0x56, 0x54, 0x54, 0x55, 0x55, 0x55, 0x45, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x40, 0x55, 0x55, 0x01, 0x50, 0x55, 0x55, 0x05, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xa6, 0x65, 0x55, 0x91, 0x55, 0x15, 0x55,
+    0x16, 0x92, 0x55, 0x55, 0x55, 0x14, 0x56, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x48, 0x55, 0x55, 0x01, 0x50, 0x55, 0x55, 0x05, 0x15, 0x64, 0x54,
+    0x01, 0x05, 0x55, 0x55, 0x11, 0x05, 0x55, 0x55, 0x55, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0x92, 0x55, 0x95, 0x55,
+    0x6a, 0x91, 0x55, 0x55, 0x55, 0x54, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x15, 0x55, 0x55, 0x15, 0x48, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x05, 0x15, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa5, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x6a, 0x91, 0x55, 0x55, 0x95, 0x54, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x4a, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa5, 0x15, 0x55, 0x55, 0x00, 0x00, 0x05, 0x54,
+    0xaa, 0xaa, 0x4a, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x55, 0x55, 0x55,
+    0x6a, 0xa1, 0x55, 0x55, 0x95, 0x54, 0x55, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x15, 0x46, 0x55, 0x55, 0x01, 0x5a, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x11, 0x05, 0x55, 0x55, 0x55, 0x05, 0x55, 0x55, 0xa9, 0x15, 0x55, 0x55, 0x00, 0x10, 0x45, 0x54,
+    0xaa, 0xa5, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0x65, 0x55, 0xa6, 0x95, 0x56, 0x55,
+    0x6a, 0xa5, 0x55, 0x55, 0x99, 0x54, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55,
+    0x56, 0x55, 0x55, 0x55, 0x15, 0x46, 0x55, 0x55, 0x01, 0x55, 0x55, 0x55, 0x15, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x56, 0x05, 0x55, 0x55, 0xa9, 0x66, 0x55, 0x55, 0x00, 0x10, 0x45, 0x54,
+    0xaa, 0x95, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xa6, 0x55, 0xa6, 0xa5, 0x56, 0x55,
+    0x6a, 0xa5, 0x55, 0x55, 0xa9, 0x54, 0x55, 0x55, 0x99, 0x55, 0x55, 0x55, 0x5a, 0x55, 0x55, 0x55,
+    0xa6, 0x55, 0x55, 0x55, 0x15, 0x45, 0x55, 0x55, 0x11, 0x55, 0x55, 0x55, 0x19, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x56, 0x15, 0x55, 0x55, 0xa9, 0x66, 0x95, 0x55, 0x00, 0x10, 0x55, 0x54,
+    0xaa, 0x95, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xa6, 0x96, 0xa6, 0xa5, 0x56, 0x55,
+    0x6a, 0xa5, 0x99, 0x55, 0xaa, 0x68, 0x59, 0x55, 0xa9, 0x55, 0x55, 0x55, 0x5a, 0x95, 0x55, 0x55,
+    0xa6, 0x55, 0x55, 0x55, 0x19, 0x45, 0x55, 0x55, 0x11, 0x95, 0x55, 0x55, 0x19, 0x55, 0x54, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0x66, 0x15, 0x55, 0x55, 0xaa, 0x66, 0x9a, 0x55, 0x00, 0x14, 0x55, 0x54,
+    0x9a, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0x96, 0xaa, 0xa6, 0x56, 0x55,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0x68, 0x59, 0x55, 0xa9, 0x65, 0x55, 0x55, 0x5a, 0xa5, 0x55, 0x55,
+    0xaa, 0x65, 0x55, 0x55, 0x29, 0x85, 0x55, 0x55, 0x12, 0x95, 0x55, 0x55, 0x59, 0x55, 0x56, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0xa6, 0x15, 0x55, 0x55, 0xaa, 0x6a, 0x9a, 0x55, 0x00, 0x14, 0x55, 0x54,
+    0x95, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x56, 0x55,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0xa8, 0x59, 0x55, 0xa9, 0x65, 0x55, 0x55, 0x9a, 0xa5, 0x55, 0x55,
+    0xaa, 0x69, 0x95, 0x55, 0x2a, 0x85, 0x55, 0x55, 0x52, 0x95, 0x55, 0x55, 0x5a, 0x55, 0x95, 0x55,
+    0x56, 0x15, 0x55, 0x55, 0xaa, 0x1a, 0x95, 0x55, 0xaa, 0x6a, 0xaa, 0xa9, 0x00, 0x15, 0x55, 0x54,
+    0x55, 0x55, 0x45, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x56, 0xa9,
+    0xaa, 0xa5, 0x99, 0x55, 0xaa, 0xa8, 0xa9, 0x55, 0xa9, 0xaa, 0x56, 0x55, 0xaa, 0xa5, 0x95, 0x55,
+    0xaa, 0x69, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x52, 0x95, 0x55, 0x55, 0x5a, 0x6a, 0x99, 0x55,
+    0x66, 0x15, 0x55, 0x55, 0xaa, 0x1a, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0xa9, 0x20, 0x95, 0x55, 0x54,
+    0x55, 0x55, 0x8a, 0x00, 0xaa, 0xaa, 0xa5, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xa9,
+    0xaa, 0xa5, 0x99, 0x59, 0xaa, 0xa8, 0xa9, 0x55, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0x6a, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x5a, 0x6a, 0x99, 0x55,
+    0xa6, 0x15, 0x55, 0xa9, 0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0x5a, 0x8a, 0x00, 0xaa, 0xaa, 0x55, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xa9,
+    0xaa, 0xa5, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0x95, 0x55, 0x55, 0x6a, 0xaa, 0x99, 0x55,
+    0xaa, 0x1a, 0x9a, 0xa9, 0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0x6a, 0x8a, 0x00, 0xaa, 0x55, 0x5a, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa,
+    0xaa, 0xa9, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x66, 0x55, 0xaa, 0xaa, 0xaa, 0x55,
+    0xaa, 0xaa, 0xa9, 0x55, 0x6a, 0x95, 0x55, 0x55, 0x56, 0xa5, 0x55, 0x95, 0x6a, 0xaa, 0x99, 0x55,
+    0xaa, 0x1a, 0xaa, 0xa9, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x26, 0x95, 0x55, 0x54,
+    0x55, 0xaa, 0x8a, 0x00, 0xa9, 0x55, 0x5a, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0x95, 0xaa, 0xaa, 0x6a, 0xa9, 0xaa, 0xaa, 0xaa, 0xa9,
+    0xaa, 0xaa, 0xaa, 0xa9, 0x6a, 0x95, 0x55, 0x55, 0x66, 0xa5, 0x55, 0xa9, 0xaa, 0xaa, 0x99, 0x55,
+    0xaa, 0x2a, 0xaa, 0xa9, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x12, 0x45, 0x55, 0x54,
+    0x6a, 0xaa, 0x8a, 0x00, 0xa5, 0x55, 0xa5, 0x56, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0x59, 0xaa, 0xa9, 0xa9, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x6a, 0x95, 0x55, 0xa5, 0xa6, 0xa5, 0x9a, 0xa9, 0xaa, 0xaa, 0x99, 0xa5,
+    0xaa, 0x2a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x41, 0x55, 0x54,
+    0xaa, 0xaa, 0x8a, 0x00, 0x95, 0x6a, 0xa5, 0x56, 0xaa, 0x69, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xa9, 0xa9, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0x96, 0xa9, 0xa6, 0xa5, 0xaa, 0xaa, 0xaa, 0xaa, 0x99, 0xa9,
+    0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x40, 0x00, 0x54,
+    0xaa, 0xaa, 0x85, 0x00, 0x55, 0x6a, 0xa5, 0x56, 0xa9, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x95, 0xaa, 0xaa, 0xaa, 0xa5, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xa9,
+    0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x11, 0x40, 0x00, 0x54,
+    0xaa, 0xaa, 0x45, 0x00, 0x5a, 0x6a, 0x55, 0x56, 0xa9, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x99, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x01, 0x40, 0x02, 0xa0,
+    0xaa, 0xa5, 0x45, 0x00, 0x6a, 0x55, 0x55, 0x56, 0x69, 0x59, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x40, 0x02, 0xa8,
+    0xaa, 0x55, 0x45, 0x00, 0x6a, 0x95, 0x55, 0x55, 0x65, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0xa5, 0x55, 0x45, 0x00, 0x66, 0x95, 0x55, 0x55, 0x65, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x55, 0x95, 0x55, 0x55, 0x55, 0x55, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x99, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x59, 0x55, 0x69, 0x69, 0x6a, 0xaa, 0xaa,
+    0xaa, 0x5a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0x6a, 0xaa, 0xaa,
+    0xa9, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a, 0xa8,
+    0x55, 0x55, 0x45, 0x00, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x6a, 0x5a, 0xa9, 0xaa,
+    0xa5, 0x6a, 0xaa, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x0a, 0xa8,
+    0x55, 0x55, 0x40, 0x00, 0x95, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0xa9, 0xaa,
+    0x95, 0x6a, 0x66, 0xaa, 0xaa, 0x6a, 0xaa, 0xaa, 0xaa, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x8a, 0xa8,
+    0x55, 0x50, 0x00, 0x80, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0x99, 0xaa,
+    0x55, 0x46, 0x66, 0xaa, 0xa8, 0x6a, 0x9a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x8a, 0xa8,
+    0x50, 0x00, 0x00, 0x80, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x94, 0x95, 0x95, 0x56,
+    0x55, 0x45, 0x55, 0xa6, 0x65, 0x96, 0x96, 0xaa, 0x26, 0x8a, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x28, 0xaa, 0xa8,
+    0x00, 0x00, 0x00, 0x60, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x45, 0x55, 0xa6, 0x55, 0x96, 0x66, 0xaa, 0x2a, 0x4a, 0xaa, 0xaa, 0xaa, 0x8a, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0x88, 0x2a, 0xaa, 0xa8,
+    0x00, 0x00, 0x20, 0x50, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x95, 0x66, 0x6a, 0x12, 0x86, 0xaa, 0xaa, 0x85, 0x4a, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0xa8, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa9, 0xaa, 0xaa, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55, 0xa8, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x61, 0x09, 0x55, 0x55, 0x45, 0x55, 0x55, 0xaa,
+    0xa8, 0xaa, 0xaa, 0xaa, 0x98, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x10, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x45, 0x99, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x56, 0xaa, 0x94, 0x6a, 0xaa, 0xaa, 0x56, 0x65, 0x55, 0x55, 0xa6, 0x55, 0x66, 0xaa,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x00, 0x25, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x68, 0x95, 0x55, 0x55, 0xa9, 0x8a, 0xaa, 0xaa, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x00, 0x05, 0x65, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0xa4, 0x6a, 0xaa, 0xaa, 0x02, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x05, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x01, 0x50, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x55, 0x55, 0x55, 0x55,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xa8,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x55, 0x55, 0x55, 0x55, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xaa, 0xaa, 0xaa, 0xaa,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
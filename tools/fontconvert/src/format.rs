@@ -0,0 +1,126 @@
+//! Writer for the binary font format, see `lilygo_epd47::font` for the
+//! layout.
+
+use anyhow::{bail, Result};
+
+const MAGIC: &[u8; 4] = b"EPF4";
+const VERSION: u8 = 1;
+
+/// A rasterized glyph.
+pub struct GlyphData {
+    pub code_point: u32,
+    /// Glyph index within the source font.
+    pub index: u32,
+    pub width: u16,
+    pub height: u16,
+    pub advance_x: u16,
+    pub left: i16,
+    pub top: i16,
+    /// Coverage (0x0 - 0xF) of each pixel, row by row.
+    pub coverage: Vec<u8>,
+}
+
+impl GlyphData {
+    fn bitmap(&self) -> Vec<u8> {
+        let mut bitmap = Vec::new();
+        if self.width == 0 {
+            return bitmap;
+        }
+        for row in self.coverage.chunks(self.width as usize) {
+            for pair in row.chunks(2) {
+                bitmap.push(pair[0] | pair.get(1).map_or(0, |right| right << 4));
+            }
+        }
+        bitmap
+    }
+}
+
+pub struct FontData {
+    pub line_height: u16,
+    /// Glyphs sorted by code point.
+    pub glyphs: Vec<GlyphData>,
+    /// Kerning pairs (left, right, offset).
+    pub kerning: Vec<(u32, u32, i16)>,
+}
+
+impl FontData {
+    pub fn new(
+        line_height: u16,
+        mut glyphs: Vec<GlyphData>,
+        mut kerning: Vec<(u32, u32, i16)>,
+    ) -> Self {
+        glyphs.sort_by_key(|glyph| glyph.code_point);
+        kerning.sort_by_key(|&(left, right, _)| (left, right));
+        FontData {
+            line_height,
+            glyphs,
+            kerning,
+        }
+    }
+
+    /// Consecutive code points (first, last, glyph index).
+    fn intervals(&self) -> Vec<(u32, u32, u32)> {
+        let mut intervals: Vec<(u32, u32, u32)> = Vec::new();
+        for (index, glyph) in self.glyphs.iter().enumerate() {
+            match intervals.last_mut() {
+                Some((_, last, _)) if *last + 1 == glyph.code_point => *last = glyph.code_point,
+                _ => intervals.push((glyph.code_point, glyph.code_point, index as u32)),
+            }
+        }
+        intervals
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let intervals = self.intervals();
+        if self.glyphs.len() > u16::MAX as usize
+            || intervals.len() > u16::MAX as usize
+            || self.kerning.len() > u16::MAX as usize
+        {
+            bail!("Too many glyphs, split the font into multiple ranges");
+        }
+        // glyphs without any pixels (e.g. space) don't contribute to the
+        // ascender / descender
+        let visible = self.glyphs.iter().filter(|glyph| glyph.height > 0);
+        let ascender = visible.clone().map(|glyph| glyph.top).max().unwrap_or(0);
+        let descender = visible
+            .map(|glyph| glyph.top - glyph.height as i16)
+            .min()
+            .unwrap_or(0);
+
+        let mut data = Vec::new();
+        data.extend_from_slice(MAGIC);
+        data.extend_from_slice(&[VERSION, 0]);
+        data.extend_from_slice(&self.line_height.to_le_bytes());
+        data.extend_from_slice(&ascender.to_le_bytes());
+        data.extend_from_slice(&descender.to_le_bytes());
+        data.extend_from_slice(&(self.glyphs.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(intervals.len() as u16).to_le_bytes());
+        data.extend_from_slice(&(self.kerning.len() as u16).to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+
+        for (first, last, index) in intervals {
+            data.extend_from_slice(&first.to_le_bytes());
+            data.extend_from_slice(&last.to_le_bytes());
+            data.extend_from_slice(&index.to_le_bytes());
+        }
+        let mut bitmaps = Vec::new();
+        for glyph in &self.glyphs {
+            data.extend_from_slice(&glyph.width.to_le_bytes());
+            data.extend_from_slice(&glyph.height.to_le_bytes());
+            data.extend_from_slice(&glyph.advance_x.to_le_bytes());
+            data.extend_from_slice(&glyph.left.to_le_bytes());
+            data.extend_from_slice(&glyph.top.to_le_bytes());
+            data.extend_from_slice(&[0, 0]);
+            data.extend_from_slice(&(bitmaps.len() as u32).to_le_bytes());
+            bitmaps.extend(glyph.bitmap());
+        }
+        for &(left, right, offset) in &self.kerning {
+            data.extend_from_slice(&left.to_le_bytes());
+            data.extend_from_slice(&right.to_le_bytes());
+            data.extend_from_slice(&offset.to_le_bytes());
+            data.extend_from_slice(&[0, 0]);
+        }
+        data.extend(bitmaps);
+        Ok(data)
+    }
+}
@@ -0,0 +1,265 @@
+//! Convert TTF/OTF fonts to the anti-aliased 4bpp font format of
+//! `lilygo-epd47` (see `lilygo_epd47::font`).
+//!
+//! This is a port of epdiy's `fontconvert.py`. The glyphs are rasterized and
+//! hinted by FreeType, the coverage is gamma corrected and quantized to 16
+//! levels. Kerning pairs are read from the `kern` table of the font.
+//!
+//! ```sh
+//! cargo run -p fontconvert --target x86_64-unknown-linux-gnu -Zbuild-std=std -- \
+//!     DejaVuSans.ttf --size 24 --range 0x20-0x7E --range 0xA0-0xFF \
+//!     --output src/fonts/dejavu_24.rs
+//! ```
+
+mod format;
+
+use std::{fmt::Write as _, fs, ops::RangeInclusive, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use freetype::{
+    face::{KerningMode, LoadFlag},
+    Face,
+    Library,
+};
+
+use crate::format::{FontData, GlyphData};
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// TTF or OTF font file.
+    font: PathBuf,
+    /// Font size in pixels.
+    #[arg(short, long)]
+    size: u32,
+    /// Unicode range to include, e.g. `0x20-0x7E`, `U+00A0-U+00FF` or `8364`.
+    /// Can be repeated, defaults to printable ASCII.
+    #[arg(short, long = "range", value_parser = parse_range)]
+    ranges: Vec<RangeInclusive<u32>>,
+    /// Output file.
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Output format, derived from the file extension by default (`.rs` for
+    /// a Rust module, a binary blob otherwise).
+    #[arg(short, long)]
+    format: Option<OutputFormat>,
+    /// Name of the static in the generated Rust module.
+    #[arg(long, default_value = "FONT")]
+    name: String,
+    /// Hinting mode.
+    #[arg(long, default_value = "light")]
+    hinting: Hinting,
+    /// Gamma applied to the glyph coverage, values above 1.0 result in
+    /// darker (bolder) text.
+    #[arg(long, default_value_t = 1.0)]
+    gamma: f32,
+    /// Don't include kerning pairs.
+    #[arg(long)]
+    no_kerning: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    /// Rust module containing the font as a static byte slice.
+    Rust,
+    /// Raw binary blob, e.g. for `include_bytes!` or a flash partition.
+    Binary,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Hinting {
+    /// No hinting, the outlines are rendered as designed.
+    None,
+    /// Light hinting, snaps to the pixel grid vertically only.
+    Light,
+    /// Full hinting using the hinting instructions of the font.
+    Normal,
+    /// FreeType's auto-hinter, useful for fonts with poor hinting.
+    Auto,
+}
+
+impl Hinting {
+    fn load_flags(self) -> LoadFlag {
+        LoadFlag::RENDER
+            | match self {
+                Hinting::None => LoadFlag::NO_HINTING,
+                Hinting::Light => LoadFlag::TARGET_LIGHT,
+                Hinting::Normal => LoadFlag::TARGET_NORMAL,
+                Hinting::Auto => LoadFlag::FORCE_AUTOHINT | LoadFlag::TARGET_LIGHT,
+            }
+    }
+}
+
+fn parse_code_point(value: &str) -> Result<u32> {
+    let value = value.trim();
+    let hex = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .or_else(|| value.strip_prefix("U+"))
+        .or_else(|| value.strip_prefix("u+"));
+    let code_point = match hex {
+        Some(hex) => u32::from_str_radix(hex, 16)?,
+        None => value.parse()?,
+    };
+    if char::from_u32(code_point).is_none() {
+        bail!("{value} is not a valid code point");
+    }
+    Ok(code_point)
+}
+
+fn parse_range(value: &str) -> Result<RangeInclusive<u32>> {
+    let (first, last) = value.split_once('-').unwrap_or((value, value));
+    let (first, last) = (parse_code_point(first)?, parse_code_point(last)?);
+    if first > last {
+        bail!("range {value} is empty");
+    }
+    Ok(first..=last)
+}
+
+/// 26.6 fixed point to pixels.
+fn to_pixels(value: impl Into<i64>) -> i64 {
+    (value.into() + 32) >> 6
+}
+
+fn render_glyph(face: &Face, args: &Args, c: char) -> Result<Option<GlyphData>> {
+    if face.get_char_index(c as usize) == 0 {
+        return Ok(None);
+    }
+    face.load_char(c as usize, args.hinting.load_flags())
+        .with_context(|| format!("Failed to render {c:?}"))?;
+    let slot = face.glyph();
+    let bitmap = slot.bitmap();
+    let (width, height) = (bitmap.width() as usize, bitmap.rows() as usize);
+    let pitch = bitmap.pitch().unsigned_abs() as usize;
+    // the buffer of empty glyphs (e.g. space) is a null pointer
+    let buffer = match width * height {
+        0 => &[],
+        _ => bitmap.buffer(),
+    };
+
+    let mut coverage = Vec::with_capacity(width * height);
+    for y in 0..height {
+        for x in 0..width {
+            let value = buffer[y * pitch + x] as f32 / 255.0;
+            let value = value.powf(1.0 / args.gamma);
+            coverage.push((value * 15.0).round() as u8);
+        }
+    }
+    Ok(Some(GlyphData {
+        code_point: c as u32,
+        index: face.get_char_index(c as usize),
+        width: width as u16,
+        height: height as u16,
+        advance_x: to_pixels(slot.advance().x) as u16,
+        left: slot.bitmap_left() as i16,
+        top: slot.bitmap_top() as i16,
+        coverage,
+    }))
+}
+
+fn kerning(face: &Face, glyphs: &[GlyphData]) -> Result<Vec<(u32, u32, i16)>> {
+    let mut pairs = Vec::new();
+    if !face.has_kerning() {
+        return Ok(pairs);
+    }
+    for left in glyphs {
+        for right in glyphs {
+            let offset = face.get_kerning(left.index, right.index, KerningMode::KerningDefault)?;
+            let offset = to_pixels(offset.x);
+            if offset != 0 {
+                pairs.push((left.code_point, right.code_point, offset as i16));
+            }
+        }
+    }
+    Ok(pairs)
+}
+
+fn rust_module(args: &Args, ranges: &[RangeInclusive<u32>], data: &[u8]) -> String {
+    let file_name = args.font.file_name().unwrap_or_default().to_string_lossy();
+    let ranges = ranges
+        .iter()
+        .map(|r| format!("0x{:X}-0x{:X}", r.start(), r.end()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut module = String::new();
+    writeln!(module, "//! Generated by fontconvert, do not edit.").unwrap();
+    writeln!(module, "//!").unwrap();
+    writeln!(module, "//! Font: `{file_name}`, {}px", args.size).unwrap();
+    writeln!(module, "//! Ranges: {ranges}").unwrap();
+    writeln!(module).unwrap();
+    writeln!(module, "/// Load using `lilygo_epd47::font::Font::new`.").unwrap();
+    writeln!(module, "#[rustfmt::skip]").unwrap();
+    writeln!(module, "pub static {}: &[u8] = &[", args.name).unwrap();
+    for line in data.chunks(16) {
+        let bytes = line
+            .iter()
+            .map(|b| format!("0x{b:02X},"))
+            .collect::<Vec<_>>();
+        writeln!(module, "    {}", bytes.join(" ")).unwrap();
+    }
+    writeln!(module, "];").unwrap();
+    module
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.size == 0 || args.gamma <= 0.0 {
+        bail!("Size and gamma must be positive");
+    }
+    let ranges = match args.ranges.is_empty() {
+        true => vec![0x20..=0x7E],
+        false => args.ranges.clone(),
+    };
+
+    let library = Library::init()?;
+    let face = library
+        .new_face(&args.font, 0)
+        .with_context(|| format!("Failed to load {}", args.font.display()))?;
+    face.set_pixel_sizes(0, args.size)?;
+
+    let mut code_points = ranges.iter().cloned().flatten().collect::<Vec<_>>();
+    code_points.sort_unstable();
+    code_points.dedup();
+    let mut glyphs = Vec::new();
+    for c in code_points.into_iter().filter_map(char::from_u32) {
+        match render_glyph(&face, &args, c)? {
+            Some(glyph) => glyphs.push(glyph),
+            None => eprintln!(
+                "warning: {c:?} (U+{:04X}) is not part of the font",
+                c as u32
+            ),
+        }
+    }
+    if glyphs.is_empty() {
+        bail!("The font contains none of the requested characters");
+    }
+    let kerning = match args.no_kerning {
+        true => Vec::new(),
+        false => kerning(&face, &glyphs)?,
+    };
+    let line_height = face
+        .size_metrics()
+        .map_or(args.size as i64, |metrics| to_pixels(metrics.height));
+
+    let font = FontData::new(line_height as u16, glyphs, kerning);
+    let data = font.to_bytes()?;
+    let format = args.format.unwrap_or_else(|| {
+        match args.output.extension().is_some_and(|ext| ext == "rs") {
+            true => OutputFormat::Rust,
+            false => OutputFormat::Binary,
+        }
+    });
+    match format {
+        OutputFormat::Rust => fs::write(&args.output, rust_module(&args, &ranges, &data))?,
+        OutputFormat::Binary => fs::write(&args.output, &data)?,
+    }
+    eprintln!(
+        "{} glyphs, {} kerning pairs, {} bytes written to {}",
+        font.glyphs.len(),
+        font.kerning.len(),
+        data.len(),
+        args.output.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Stands in for `lilygo_epd47::display` in the included modules: the screen
+//! content, the framebuffer and the refresh counters, without the panel.
+
+use std::{marker::PhantomData, sync::OnceLock, time::Instant};
+
+use crate::refresh::{RefreshPolicy, RefreshState};
+
+const FRAMEBUFFER_SIZE: usize = Display::WIDTH as usize / 2 * Display::HEIGHT as usize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rectangle {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+pub struct Display<'a> {
+    framebuffer: Vec<u8>,
+    screen: Vec<u8>,
+    inverted: bool,
+    refresh: RefreshState,
+    _panel: PhantomData<&'a ()>,
+}
+
+impl Default for Display<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Display<'a> {
+    pub const WIDTH: u16 = 960;
+    pub const HEIGHT: u16 = 540;
+
+    /// A white screen with an empty framebuffer.
+    pub fn new() -> Self {
+        Display {
+            framebuffer: vec![0xFF; FRAMEBUFFER_SIZE],
+            screen: vec![0xFF; FRAMEBUFFER_SIZE],
+            inverted: false,
+            refresh: RefreshState::new(RefreshPolicy::NEVER, now()),
+            _panel: PhantomData,
+        }
+    }
+
+    /// Like the driver, flips the levels of the screen.
+    pub fn set_inverted(&mut self, inverted: bool) {
+        if inverted != self.inverted {
+            self.screen.iter_mut().for_each(|byte| *byte = !*byte);
+            self.inverted = inverted;
+        }
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Count a flush touching the regions (bit mask), without drawing.
+    pub fn count_flush(&mut self, regions: u16) {
+        self.refresh.flush(regions, now());
+    }
+
+    pub fn partial_flushes(&self) -> u16 {
+        self.refresh.partial_flushes()
+    }
+
+    pub fn framebuffer(&self) -> &[u8] {
+        &self.framebuffer
+    }
+
+    pub fn screen(&self) -> &[u8] {
+        &self.screen
+    }
+
+    pub fn screen_mut(&mut self) -> &mut [u8] {
+        &mut self.screen
+    }
+
+    pub(crate) fn row_mut(&mut self, y: u16) -> &mut [u8] {
+        let stride = Self::WIDTH as usize / 2;
+        &mut self.framebuffer[y as usize * stride..(y as usize + 1) * stride]
+    }
+
+    pub(crate) fn refresh_state(&self) -> &RefreshState {
+        &self.refresh
+    }
+
+    pub(crate) fn refresh_state_mut(&mut self) -> &mut RefreshState {
+        &mut self.refresh
+    }
+}
+
+/// Time since the first call in microseconds, the system timer of the host.
+pub(crate) fn now() -> u64 {
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_micros() as u64
+}
@@ -0,0 +1,77 @@
+//! Hardware independent modules of the driver, compiled for the host so they
+//! can be tested without a board. The tests live in `tests/`, except for the
+//! tests of crate private items which live in `src/tests/`.
+
+extern crate alloc;
+
+#[path = "../../../src/battery_history.rs"]
+pub mod battery_history;
+
+// The stand-in power sequence is a unit struct.
+#[allow(clippy::default_constructed_unit_structs)]
+#[path = "../../../src/config.rs"]
+pub mod config;
+
+// The modes are only driven by the display driver and the tests.
+#[allow(dead_code)]
+#[path = "../../../src/draw_mode.rs"]
+pub mod draw_mode;
+
+// The bus accessor is only used by the display driver.
+#[allow(dead_code)]
+#[path = "../../../src/tps65185.rs"]
+pub mod tps65185;
+
+// The counters are only used by the display driver and the tests.
+#[allow(dead_code)]
+#[path = "../../../src/refresh.rs"]
+pub mod refresh;
+
+// A lint of newer toolchains, the driver uses `%` throughout.
+#[allow(clippy::manual_is_multiple_of)]
+#[path = "../../../src/image.rs"]
+pub mod image;
+
+#[path = "../../../src/rle.rs"]
+pub mod rle;
+
+#[path = "../../../src/snapshot.rs"]
+pub mod snapshot;
+
+// The lookup table is only used by the display driver and the tests.
+#[allow(dead_code)]
+#[path = "../../../src/tone.rs"]
+pub mod tone;
+
+// The per phase lookup tables are only used by the display driver.
+#[allow(dead_code)]
+#[path = "../../../src/waveform.rs"]
+pub mod waveform;
+
+/// The errors of the driver which don't depend on a peripheral, standing in
+/// for `lilygo_epd47::Error` in the included modules.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    Timeout,
+    OutOfBounds,
+    InvalidColor,
+    InvalidFormat,
+    InvalidConfig,
+    Storage,
+    PoweredOff,
+    Unknown,
+}
+
+type Result<T> = core::result::Result<T, Error>;
+
+/// Stands in for `lilygo_epd47::board` in the included modules.
+pub mod board {
+    /// The power sequence is board specific, the config only passes it on.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct PowerSequence;
+}
+
+pub mod display;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,178 @@
+use crate::{
+    config::DisplayConfig,
+    display::{Display, Rectangle},
+    draw_mode::{DrawMode, MixedRow},
+};
+
+/// Invert both pixels of a byte.
+fn invert(byte: u8) -> u8 {
+    !byte
+}
+
+#[test]
+fn drives_as_is_on_a_regular_screen() {
+    for mode in DrawMode::ALL {
+        assert_eq!(mode.drive(false), (mode, false));
+    }
+}
+
+#[test]
+fn inverts_the_direction_on_an_inverted_screen() {
+    assert_eq!(
+        DrawMode::BlackOnWhite.drive(true),
+        (DrawMode::WhiteOnBlack, true)
+    );
+    assert_eq!(
+        DrawMode::WhiteOnBlack.drive(true),
+        (DrawMode::BlackOnWhite, true)
+    );
+    assert_eq!(
+        DrawMode::WhiteOnWhite.drive(true),
+        (DrawMode::BlackOnWhite, false)
+    );
+}
+
+#[test]
+fn inverted_levels_merge_like_the_logical_content() {
+    // the screen holds the inverted levels, drawing in the drive mode must
+    // give the inverse of drawing the logical content
+    for mode in [DrawMode::BlackOnWhite, DrawMode::WhiteOnBlack] {
+        let (drive_mode, inverted) = mode.drive(true);
+        assert!(inverted);
+        for screen in 0..=255u8 {
+            for new in 0..=255u8 {
+                assert_eq!(
+                    invert(mode.merge(screen, new)),
+                    drive_mode.merge(invert(screen), invert(new)),
+                    "{mode:?}, {screen:#04x}, {new:#04x}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn contrast_cycles_follow_the_drive_direction() {
+    let config = DisplayConfig::new();
+    let cycles = |mode: DrawMode, inverted| mode.drive(inverted).0.contrast_cycles(&config);
+    assert_eq!(
+        cycles(DrawMode::BlackOnWhite, false),
+        &config.contrast_cycles
+    );
+    assert_eq!(
+        cycles(DrawMode::WhiteOnWhite, false),
+        &config.contrast_cycles
+    );
+    assert_eq!(
+        cycles(DrawMode::WhiteOnBlack, false),
+        &config.contrast_cycles_white
+    );
+    // black on white content of an inverted screen is drawn by lightening
+    assert_eq!(
+        cycles(DrawMode::BlackOnWhite, true),
+        &config.contrast_cycles_white
+    );
+    assert_eq!(
+        cycles(DrawMode::WhiteOnBlack, true),
+        &config.contrast_cycles
+    );
+    assert_eq!(
+        cycles(DrawMode::WhiteOnWhite, true),
+        &config.contrast_cycles
+    );
+}
+
+#[test]
+fn merges_per_pixel() {
+    assert_eq!(DrawMode::BlackOnWhite.merge(0xF8, 0x3F), 0x38);
+    assert_eq!(DrawMode::WhiteOnBlack.merge(0x08, 0x30), 0x38);
+    // white leaves the pixel alone, 0xC lightens by 3
+    assert_eq!(DrawMode::WhiteOnWhite.merge(0x48, 0xCF), 0x78);
+    assert_eq!(DrawMode::WhiteOnWhite.merge(0xE0, 0x0F), 0xF0);
+}
+
+fn area(x: u16, y: u16, width: u16, height: u16) -> Rectangle {
+    Rectangle {
+        x,
+        y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn later_areas_take_precedence() {
+    let areas = [
+        (area(0, 0, 100, 10), DrawMode::BlackOnWhite),
+        (area(50, 5, 100, 10), DrawMode::WhiteOnBlack),
+    ];
+    let row = MixedRow::new(&areas, 2);
+    assert!(row.owners[..100]
+        .iter()
+        .all(|&owner| owner == Some(DrawMode::BlackOnWhite)));
+    assert!(row.owners[100..].iter().all(Option::is_none));
+
+    let row = MixedRow::new(&areas, 7);
+    assert!(row.owners[..50]
+        .iter()
+        .all(|&owner| owner == Some(DrawMode::BlackOnWhite)));
+    assert!(row.owners[50..150]
+        .iter()
+        .all(|&owner| owner == Some(DrawMode::WhiteOnBlack)));
+    assert!(row.owners[150..].iter().all(Option::is_none));
+
+    // the bottom edge is exclusive
+    let row = MixedRow::new(&areas, 15);
+    assert!(row.owners.iter().all(Option::is_none));
+}
+
+#[test]
+fn lists_the_modes_of_a_row() {
+    let areas = [
+        (area(0, 0, 10, 20), DrawMode::WhiteOnBlack),
+        (area(20, 10, 10, 20), DrawMode::WhiteOnWhite),
+        (area(40, 0, 10, 30), DrawMode::BlackOnWhite),
+        // hides the white on black area in the rows it covers
+        (area(0, 5, 10, 5), DrawMode::BlackOnWhite),
+    ];
+    let modes = |y| MixedRow::new(&areas, y).modes().collect::<Vec<_>>();
+    assert_eq!(modes(0), [DrawMode::BlackOnWhite, DrawMode::WhiteOnBlack]);
+    assert_eq!(modes(5), [DrawMode::BlackOnWhite]);
+    assert_eq!(modes(15), DrawMode::ALL);
+    assert_eq!(modes(25), [DrawMode::BlackOnWhite, DrawMode::WhiteOnWhite]);
+    assert_eq!(modes(30), []);
+}
+
+#[test]
+fn clips_areas_to_the_screen() {
+    let areas = [(area(Display::WIDTH - 10, 0, 20, 1), DrawMode::WhiteOnBlack)];
+    let row = MixedRow::new(&areas, 0);
+    let covered = row.owners.iter().filter(|owner| owner.is_some()).count();
+    assert_eq!(covered, 10);
+}
+
+#[test]
+fn rows_take_the_longest_time_of_their_modes() {
+    let time = |mode: DrawMode| match mode {
+        DrawMode::BlackOnWhite => 30,
+        DrawMode::WhiteOnWhite => 20,
+        DrawMode::WhiteOnBlack => 50,
+    };
+    let areas = [
+        (area(0, 0, 10, 10), DrawMode::BlackOnWhite),
+        (area(10, 0, 10, 5), DrawMode::WhiteOnWhite),
+        (area(20, 5, 10, 5), DrawMode::WhiteOnBlack),
+    ];
+    assert_eq!(MixedRow::new(&areas, 0).time(time), 30);
+    assert_eq!(MixedRow::new(&areas, 5).time(time), 50);
+    // a mode without output time in the frame doesn't shorten the row
+    assert_eq!(
+        MixedRow::new(&areas, 5).time(|mode| match mode {
+            DrawMode::WhiteOnBlack => 0,
+            mode => time(mode),
+        }),
+        30
+    );
+    // rows the areas don't cover are skipped
+    assert_eq!(MixedRow::new(&areas, 10).time(time), 0);
+}
@@ -0,0 +1,5 @@
+//! Tests of the crate private items of the included modules.
+
+mod draw_mode;
+mod refresh;
+mod tone;
@@ -0,0 +1,150 @@
+use core::time::Duration;
+
+use crate::{
+    refresh::{Escalation, RefreshPolicy, RefreshState},
+    Error,
+};
+
+const SECOND: u64 = 1_000_000;
+/// A flush touching the first region only.
+const TOP: u16 = 1 << 0;
+
+fn counters(state: &RefreshState, now: u64) -> Vec<u8> {
+    let mut data = Vec::new();
+    state.to_bytes(&mut data, now);
+    data
+}
+
+#[test]
+fn never_refreshes() {
+    let mut state = RefreshState::new(RefreshPolicy::NEVER, 0);
+    for i in 0..1000 {
+        assert_eq!(
+            state.flush(RefreshPolicy::ALL_REGIONS, i * 3600 * SECOND),
+            Escalation::None
+        );
+    }
+    assert_eq!(state.partial_flushes(), 1000);
+}
+
+#[test]
+fn refreshes_every_time() {
+    let mut state = RefreshState::new(RefreshPolicy::EVERY_TIME, 0);
+    for _ in 0..3 {
+        assert_eq!(state.flush(TOP, 0), Escalation::Full);
+        assert_eq!(state.partial_flushes(), 0);
+    }
+}
+
+#[test]
+fn ignores_empty_flushes() {
+    let mut state = RefreshState::new(RefreshPolicy::EVERY_TIME, 0);
+    assert_eq!(state.flush(0, 0), Escalation::None);
+}
+
+#[test]
+fn refreshes_after_partial_flushes() {
+    let mut state = RefreshState::new(RefreshPolicy::after_flushes(3), 0);
+    for _ in 0..3 {
+        assert_eq!(state.flush(TOP, 0), Escalation::None);
+    }
+    assert_eq!(state.flush(TOP, 0), Escalation::Full);
+    assert_eq!(state.partial_flushes(), 0);
+    assert_eq!(state.flush(TOP, 0), Escalation::None);
+}
+
+#[test]
+fn refreshes_regions_after_partial_flushes() {
+    let policy = RefreshPolicy::NEVER.with_region_limit(2);
+    let mut state = RefreshState::new(policy, 0);
+    let bottom = 1 << (RefreshPolicy::REGIONS - 1);
+    assert_eq!(state.flush(TOP, 0), Escalation::None);
+    assert_eq!(state.flush(TOP | bottom, 0), Escalation::None);
+    // only the top region reached the limit
+    assert_eq!(state.flush(TOP | bottom, 0), Escalation::Regions(TOP));
+    assert_eq!(state.flush(bottom, 0), Escalation::Regions(bottom));
+    // the counters of the refreshed regions start over
+    assert_eq!(state.flush(TOP, 0), Escalation::None);
+    assert_eq!(state.partial_flushes(), 5);
+}
+
+#[test]
+fn cleared_regions_start_over() {
+    let policy = RefreshPolicy::NEVER.with_region_limit(1);
+    let mut state = RefreshState::new(policy, 0);
+    assert_eq!(state.flush(TOP, 0), Escalation::None);
+    state.reset_regions(TOP);
+    assert_eq!(state.flush(TOP, 0), Escalation::None);
+    assert_eq!(state.flush(TOP, 0), Escalation::Regions(TOP));
+}
+
+#[test]
+fn refreshes_after_interval() {
+    let policy = RefreshPolicy::NEVER.with_interval(60);
+    let mut state = RefreshState::new(policy, 10 * SECOND);
+    assert_eq!(state.flush(TOP, 69 * SECOND), Escalation::None);
+    assert_eq!(state.flush(TOP, 70 * SECOND), Escalation::Full);
+    // the interval starts over with the full refresh
+    assert_eq!(state.flush(TOP, 129 * SECOND), Escalation::None);
+    assert_eq!(state.flush(TOP, 130 * SECOND), Escalation::Full);
+}
+
+#[test]
+fn interval_includes_time_asleep() {
+    let policy = RefreshPolicy::NEVER.with_interval(60);
+    let mut state = RefreshState::new(policy, 0);
+    state.flush(TOP, 0);
+    let data = counters(&state, 20 * SECOND);
+
+    // the timer restarts after waking up, restored 1 s later
+    let mut restored = RefreshState::new(policy, 0);
+    let data = data.as_slice().try_into().unwrap();
+    restored
+        .restore(data, Duration::from_secs(30), SECOND)
+        .unwrap();
+    assert_eq!(restored.partial_flushes(), 1);
+    // 20 s awake, 30 s asleep and 10 s since waking up
+    assert_eq!(restored.flush(TOP, 10 * SECOND), Escalation::None);
+    assert_eq!(restored.flush(TOP, 11 * SECOND), Escalation::Full);
+}
+
+#[test]
+fn round_trip() {
+    let policy = RefreshPolicy::after_flushes(10).with_region_limit(5);
+    let mut state = RefreshState::new(policy, 0);
+    for regions in [TOP, TOP | 0b10, 0b110, TOP] {
+        state.flush(regions, 0);
+    }
+    let data = counters(&state, 0);
+    assert_eq!(data.len(), RefreshState::SIZE);
+
+    let mut restored = RefreshState::new(policy, 0);
+    let bytes = data.as_slice().try_into().unwrap();
+    restored.restore(bytes, Duration::ZERO, 0).unwrap();
+    assert_eq!(restored.partial_flushes(), 4);
+    assert_eq!(counters(&restored, 0), data);
+}
+
+#[test]
+fn restore_rejects_inconsistent_counters() {
+    let policy = RefreshPolicy::after_flushes(10).with_region_limit(5);
+    let mut state = RefreshState::new(policy, 0);
+    state.flush(TOP, 0);
+    let valid = counters(&state, 0);
+
+    // the top region flushed more often than the whole screen
+    let mut data = valid.clone();
+    data[2] = 2;
+    let mut restored = RefreshState::new(policy, 0);
+    let bytes = data.as_slice().try_into().unwrap();
+    assert_eq!(
+        restored.restore(bytes, Duration::ZERO, 0),
+        Err(Error::InvalidFormat)
+    );
+    // left untouched
+    assert_eq!(restored.partial_flushes(), 0);
+    assert_eq!(counters(&restored, 0), vec![0; RefreshState::SIZE]);
+
+    let bytes = valid.as_slice().try_into().unwrap();
+    assert_eq!(restored.restore(bytes, Duration::ZERO, 0), Ok(()));
+}
@@ -0,0 +1,18 @@
+use crate::tone::ToneCurve;
+
+#[test]
+fn byte_lut_maps_each_nibble() {
+    let curve = ToneCurve::new([1, 3, 5, 7, 9, 11, 13, 15, 0, 2, 4, 6, 8, 10, 12, 14]).unwrap();
+    for curve in [
+        ToneCurve::LINEAR,
+        ToneCurve::SRGB,
+        ToneCurve::HIGH_CONTRAST_TEXT,
+        curve,
+    ] {
+        let lut = curve.byte_lut();
+        for byte in 0..=255u8 {
+            let expected = curve.map(byte >> 4) << 4 | curve.map(byte & 0x0F);
+            assert_eq!(lut[byte as usize], expected, "{curve:?}, {byte:#04x}");
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use hosttest::battery_history::{
+    state_of_charge,
+    BatteryHistory,
+    ChargeState,
+    PowerSource,
+    Reading,
+    RuntimeEstimate,
+};
+
+const HALF_HOUR: u32 = 30 * 60;
+
+/// Readings every half hour starting at `start` millivolts, changing by
+/// `trend` millivolts per hour.
+fn trace<const N: usize>(history: &mut BatteryHistory<N>, start: f32, trend: f32, count: u32) {
+    for i in 0..count {
+        let hours = (i * HALF_HOUR) as f32 / 3600.0;
+        // half a millivolt up, the history truncates to whole millivolts
+        history.push(i * HALF_HOUR, (start + trend * hours + 0.5) / 1000.0);
+    }
+}
+
+#[test]
+fn state_of_charge_curve() {
+    assert_eq!(state_of_charge(4300), 100);
+    assert_eq!(state_of_charge(4200), 100);
+    assert_eq!(state_of_charge(3840), 50);
+    assert_eq!(state_of_charge(3830), 47);
+    assert_eq!(state_of_charge(3270), 0);
+    assert_eq!(state_of_charge(3000), 0);
+}
+
+#[test]
+fn ring_buffer_wraps_around() {
+    let mut history = BatteryHistory::<4>::new();
+    assert!(history.is_empty());
+    assert_eq!(history.latest(), None);
+    for i in 0..6 {
+        history.push(i * 60, 3.9 - i as f32 * 0.01);
+    }
+    assert_eq!(history.len(), 4);
+    let timestamps: Vec<u32> = history.iter().map(|r| r.timestamp).collect();
+    assert_eq!(timestamps, [120, 180, 240, 300]);
+    assert_eq!(
+        history.latest(),
+        Some(Reading {
+            timestamp: 300,
+            millivolts: 3850,
+        })
+    );
+}
+
+#[test]
+fn clock_reset_clears_history() {
+    let mut history = BatteryHistory::<4>::new();
+    history.push(1000, 3.9);
+    history.push(2000, 3.9);
+    history.push(500, 3.8);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.latest().map(|r| r.timestamp), Some(500));
+}
+
+#[test]
+fn regression_over_wrapped_buffer() {
+    let mut history = BatteryHistory::<8>::new();
+    // a fast drop first, which has been overwritten by the steady part
+    trace(&mut history, 4000.0, -100.0, 4);
+    for i in 4..16 {
+        history.push(i * HALF_HOUR, (3800.5 - (i - 4) as f32 * 10.0) / 1000.0);
+    }
+    let trend = history.voltage_trend().unwrap();
+    assert!((trend + 20.0).abs() < 0.1, "trend {trend}");
+}
+
+#[test]
+fn regression_needs_min_span() {
+    let mut history = BatteryHistory::<8>::new();
+    history.push(0, 3.9);
+    assert_eq!(history.voltage_trend(), None);
+    history.push(BatteryHistory::<8>::MIN_SPAN - 1, 3.8);
+    assert_eq!(history.voltage_trend(), None);
+    history.push(BatteryHistory::<8>::MIN_SPAN, 3.8);
+    assert!(history.voltage_trend().is_some());
+}
+
+#[test]
+fn estimate_linear_discharge() {
+    let mut history = BatteryHistory::<48>::new();
+    // 50% to 40% in two hours, 4 mV per percent in this part of the curve
+    trace(&mut history, 3840.0, -20.0, 5);
+    let estimate = history.estimate().unwrap();
+    // the state of charge of each reading is truncated to whole percents
+    assert!((39..=40).contains(&estimate.state_of_charge));
+    assert!(
+        (estimate.discharge_rate - 5.0).abs() < 0.5,
+        "rate {}",
+        estimate.discharge_rate
+    );
+    let hours = estimate.remaining.as_secs_f32() / 3600.0;
+    assert!((hours - 8.0).abs() < 0.5, "remaining {hours} h");
+}
+
+#[test]
+fn estimate_requires_discharge() {
+    let mut history = BatteryHistory::<48>::new();
+    trace(&mut history, 3800.0, 50.0, 5);
+    assert_eq!(history.estimate(), None);
+}
+
+#[test]
+fn estimate_display() {
+    let estimate = |secs| RuntimeEstimate {
+        state_of_charge: 50,
+        discharge_rate: 1.0,
+        remaining: Duration::from_secs(secs),
+    };
+    assert_eq!(estimate(1800).to_string(), "less than an hour left");
+    assert_eq!(estimate(3600).to_string(), "about 1 hour left");
+    assert_eq!(estimate(30 * 3600).to_string(), "about 30 hours left");
+    assert_eq!(estimate(12 * 24 * 3600).to_string(), "about 12 days left");
+}
+
+#[test]
+fn slow_discharge_of_full_cell() {
+    let mut history = BatteryHistory::<48>::new();
+    // 6 hours idling at 3 mV/h, just below the charge termination voltage
+    trace(&mut history, 4190.0, -3.0, 13);
+    let status = history.power_status();
+    assert_eq!(status.source, PowerSource::Battery);
+    assert_eq!(status.charge, ChargeState::Discharging);
+}
+
+#[test]
+fn typical_discharge() {
+    let mut history = BatteryHistory::<48>::new();
+    trace(&mut history, 3950.0, -3.0, 13);
+    let status = history.power_status();
+    assert_eq!(status.source, PowerSource::Battery);
+    assert_eq!(status.charge, ChargeState::Discharging);
+}
+
+#[test]
+fn charged_on_usb() {
+    let mut history = BatteryHistory::<48>::new();
+    // stable with a few millivolts of noise
+    for i in 0..13 {
+        let noise = [0.0, 0.004, -0.003, 0.002][i as usize % 4];
+        history.push(i * HALF_HOUR, 4.18 + noise);
+    }
+    let status = history.power_status();
+    assert_eq!(status.source, PowerSource::Usb);
+    assert_eq!(status.charge, ChargeState::Charged);
+}
+
+#[test]
+fn charging_on_usb() {
+    let mut history = BatteryHistory::<48>::new();
+    trace(&mut history, 3900.0, 100.0, 4);
+    let status = history.power_status();
+    assert_eq!(status.source, PowerSource::Usb);
+    assert_eq!(status.charge, ChargeState::Charging);
+}
+
+#[test]
+fn short_history_is_unknown() {
+    let mut history = BatteryHistory::<48>::new();
+    history.push(0, 3.9);
+    history.push(60, 3.9);
+    let status = history.power_status();
+    assert_eq!(status.source, PowerSource::Unknown);
+    assert_eq!(status.charge, ChargeState::Unknown);
+}
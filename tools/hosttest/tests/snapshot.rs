@@ -0,0 +1,163 @@
+use std::time::Duration;
+
+use hosttest::{display::Display, image::CompressedImage, rle, Error};
+
+/// Magic, version, flags and the refresh counters.
+const HEADER_SIZE: usize = 30;
+const STRIDE: usize = Display::WIDTH as usize / 2;
+
+/// A display with some content on the screen and a few partial flushes.
+fn display(inverted: bool) -> Display<'static> {
+    let mut display = Display::new();
+    let screen = display.screen_mut();
+    for y in 100..200 {
+        screen[y * STRIDE + 10..y * STRIDE + 200].fill(0x33);
+    }
+    screen[STRIDE * 300 + 7] = 0x5A;
+    for regions in [0b1, 0b11, 0b10] {
+        display.count_flush(regions);
+    }
+    display.set_inverted(inverted);
+    display
+}
+
+fn assert_untouched(display: &Display) {
+    assert!(!display.is_inverted());
+    assert!(display.screen().iter().all(|&byte| byte == 0xFF));
+    assert_eq!(display.partial_flushes(), 0);
+}
+
+#[test]
+fn header_and_screen() {
+    let display = display(true);
+    let snapshot = display.snapshot();
+    assert_eq!(&snapshot[0..4], b"EPS4");
+    assert_eq!(snapshot[4], 2);
+    assert_eq!(snapshot[5], 0b1);
+    // the partial flushes of the whole screen and the first region
+    assert_eq!(&snapshot[6..10], &[3, 0, 2, 0]);
+
+    let screen = CompressedImage::new(&snapshot[HEADER_SIZE..]).unwrap();
+    assert_eq!(
+        (screen.width(), screen.height()),
+        (Display::WIDTH, Display::HEIGHT)
+    );
+    let mut decoded = Vec::new();
+    let mut buffer = [0u8; STRIDE];
+    screen
+        .decode(&mut buffer, |_, row| decoded.extend_from_slice(row))
+        .unwrap();
+    assert_eq!(decoded, display.screen());
+    // a mostly blank screen compresses well
+    assert!(snapshot.len() < 4096);
+}
+
+#[test]
+fn round_trip() {
+    for inverted in [false, true] {
+        let display = display(inverted);
+        let snapshot = display.snapshot();
+
+        let mut restored = Display::new();
+        restored
+            .restore(&snapshot, Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(restored.is_inverted(), inverted);
+        assert_eq!(restored.screen(), display.screen());
+        assert_eq!(restored.partial_flushes(), 3);
+        assert_eq!(restored.snapshot()[..10], snapshot[..10]);
+        // the framebuffer is not part of the snapshot
+        assert!(restored.framebuffer().iter().all(|&byte| byte == 0xFF));
+    }
+}
+
+#[test]
+fn rejects_bad_magic_and_version() {
+    let snapshot = display(true).snapshot();
+    let mut magic = snapshot.clone();
+    magic[3] = b'3';
+    let mut version = snapshot.clone();
+    version[4] = 1;
+    for data in [magic, version] {
+        let mut restored = Display::new();
+        assert_eq!(
+            restored.restore(&data, Duration::ZERO),
+            Err(Error::InvalidFormat)
+        );
+        assert_untouched(&restored);
+    }
+}
+
+#[test]
+fn rejects_truncated_snapshots() {
+    let snapshot = display(true).snapshot();
+    for len in [
+        0,
+        4,
+        HEADER_SIZE - 1,
+        HEADER_SIZE,
+        HEADER_SIZE + 11,
+        snapshot.len() - 1,
+    ] {
+        let mut restored = Display::new();
+        assert_eq!(
+            restored.restore(&snapshot[..len], Duration::ZERO),
+            Err(Error::InvalidFormat),
+            "{len} bytes"
+        );
+        assert_untouched(&restored);
+    }
+}
+
+#[test]
+fn rejects_screens_of_the_wrong_size() {
+    let snapshot = display(true).snapshot();
+    let screen = vec![0x11; STRIDE * Display::HEIGHT as usize];
+    for (width, height) in [
+        (Display::WIDTH, Display::HEIGHT - 1),
+        (Display::WIDTH - 2, Display::HEIGHT),
+    ] {
+        let mut data = snapshot[..HEADER_SIZE].to_vec();
+        data.extend(rle::encode(&screen, width, height));
+        let mut restored = Display::new();
+        assert_eq!(
+            restored.restore(&data, Duration::ZERO),
+            Err(Error::InvalidFormat)
+        );
+        assert_untouched(&restored);
+    }
+
+    // the header claims the full screen, the rows decode to one row less
+    let mut data = snapshot[..HEADER_SIZE].to_vec();
+    let mut rows = rle::encode(&screen, Display::WIDTH, Display::HEIGHT - 1);
+    rows[8..10].copy_from_slice(&Display::HEIGHT.to_le_bytes());
+    data.extend(rows);
+    let mut restored = Display::new();
+    assert_eq!(
+        restored.restore(&data, Duration::ZERO),
+        Err(Error::InvalidFormat)
+    );
+    assert_untouched(&restored);
+
+    // and trailing data after the last row
+    let mut data = snapshot.clone();
+    data.push(0x80);
+    assert_eq!(
+        restored.restore(&data, Duration::ZERO),
+        Err(Error::InvalidFormat)
+    );
+    assert_untouched(&restored);
+}
+
+#[test]
+fn rejects_inconsistent_refresh_counters() {
+    let mut snapshot = display(true).snapshot();
+    // the second region flushed more often than the whole screen
+    snapshot[10] = 4;
+    let mut restored = Display::new();
+    assert_eq!(
+        restored.restore(&snapshot, Duration::ZERO),
+        Err(Error::InvalidFormat)
+    );
+    assert_untouched(&restored);
+}
@@ -0,0 +1,50 @@
+use hosttest::{tone::ToneCurve, Error};
+
+const PRESETS: [ToneCurve; 3] = [
+    ToneCurve::LINEAR,
+    ToneCurve::SRGB,
+    ToneCurve::HIGH_CONTRAST_TEXT,
+];
+
+#[test]
+fn linear_is_the_identity() {
+    for level in 0..16 {
+        assert_eq!(ToneCurve::LINEAR.map(level), level);
+    }
+    assert!(ToneCurve::LINEAR.is_linear());
+    assert_eq!(ToneCurve::default(), ToneCurve::LINEAR);
+    assert!(!ToneCurve::SRGB.is_linear());
+}
+
+#[test]
+fn presets_are_monotonic_and_keep_black_and_white() {
+    for curve in PRESETS {
+        let levels = curve.levels();
+        assert!(
+            levels.windows(2).all(|pair| pair[0] <= pair[1]),
+            "{curve:?}"
+        );
+        assert_eq!(curve.map(0x0), 0x0, "{curve:?}");
+        assert_eq!(curve.map(0xF), 0xF, "{curve:?}");
+    }
+}
+
+#[test]
+fn srgb_keeps_light_gray_below_white() {
+    assert!(ToneCurve::SRGB.map(14) < 15);
+    // dark levels are spread out
+    assert!(ToneCurve::SRGB.map(1) > 1);
+}
+
+#[test]
+fn map_ignores_the_high_nibble() {
+    assert_eq!(ToneCurve::SRGB.map(0x31), ToneCurve::SRGB.map(0x1));
+}
+
+#[test]
+fn new_rejects_levels_above_white() {
+    let mut levels = ToneCurve::LINEAR.levels();
+    assert_eq!(ToneCurve::new(levels), Ok(ToneCurve::LINEAR));
+    levels[3] = 0x10;
+    assert_eq!(ToneCurve::new(levels), Err(Error::InvalidColor));
+}
@@ -0,0 +1,366 @@
+use embedded_hal::{
+    delay::DelayNs,
+    i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation},
+};
+use hosttest::tps65185::{Enable, Error, Interrupts, Sequence, Tps65185, ADDRESS};
+
+const TMST_VALUE: usize = 0x00;
+const ENABLE: usize = 0x01;
+const VCOM1: usize = 0x03;
+const VCOM2: usize = 0x04;
+const INT_EN1: usize = 0x05;
+const INT_EN2: usize = 0x06;
+const INT1: usize = 0x07;
+const INT2: usize = 0x08;
+const UPSEQ0: usize = 0x09;
+const UPSEQ1: usize = 0x0A;
+const DWNSEQ0: usize = 0x0B;
+const DWNSEQ1: usize = 0x0C;
+const TMST1: usize = 0x0D;
+const PG: usize = 0x0F;
+
+const VCOM2_PROG: u8 = 1 << 6;
+const TMST1_READ_THERM: u8 = 1 << 7;
+const TMST1_CONV_END: u8 = 1 << 5;
+const INT1_PRGC: u8 = 1 << 0;
+
+/// Register model of the PMIC. Operations started by a write complete after
+/// the configured number of status reads, `None` never completes them.
+struct FakePmic {
+    registers: [u8; 0x11],
+    pointer: usize,
+    /// PG reads after entering active mode until all rails are good.
+    power_up_reads: Option<u32>,
+    /// INT1 reads after setting PROG until PRGC is flagged.
+    program_reads: Option<u32>,
+    /// TMST1 reads after setting READ_THERM until CONV_END is set.
+    conversion_reads: Option<u32>,
+    powering_up: Option<u32>,
+    programming: Option<u32>,
+    converting: Option<u32>,
+}
+
+impl FakePmic {
+    fn new() -> Self {
+        let mut registers = [0; 0x11];
+        registers[VCOM1] = 0x7D;
+        registers[UPSEQ0] = 0xE4;
+        registers[UPSEQ1] = 0x55;
+        registers[DWNSEQ0] = 0x1E;
+        registers[DWNSEQ1] = 0xE0;
+        FakePmic {
+            registers,
+            pointer: 0,
+            power_up_reads: Some(3),
+            program_reads: Some(3),
+            conversion_reads: Some(2),
+            powering_up: None,
+            programming: None,
+            converting: None,
+        }
+    }
+
+    fn write_register(&mut self, register: usize, value: u8) {
+        match register {
+            ENABLE if value & Enable::ACTIVE.0 != 0 => {
+                self.registers[ENABLE] = value & !Enable::ACTIVE.0;
+                self.powering_up = self.power_up_reads;
+            }
+            ENABLE if value & Enable::STANDBY.0 != 0 => {
+                self.registers[ENABLE] = value & !Enable::STANDBY.0;
+                self.registers[PG] = 0;
+            }
+            VCOM2 if value & VCOM2_PROG != 0 => {
+                self.registers[VCOM2] = value & !VCOM2_PROG;
+                self.programming = self.program_reads;
+            }
+            TMST1 if value & TMST1_READ_THERM != 0 => {
+                self.registers[TMST1] = value & !(TMST1_READ_THERM | TMST1_CONV_END);
+                self.converting = self.conversion_reads;
+            }
+            _ => self.registers[register] = value,
+        }
+    }
+
+    fn read_register(&mut self, register: usize) -> u8 {
+        fn tick(pending: &mut Option<u32>) -> bool {
+            match pending {
+                Some(0) => {
+                    *pending = None;
+                    true
+                }
+                Some(reads) => {
+                    *reads -= 1;
+                    false
+                }
+                None => false,
+            }
+        }
+        match register {
+            PG if tick(&mut self.powering_up) => self.registers[PG] = 0xFA,
+            INT1 if tick(&mut self.programming) => self.registers[INT1] |= INT1_PRGC,
+            TMST1 if tick(&mut self.converting) => self.registers[TMST1] |= TMST1_CONV_END,
+            _ => {}
+        }
+        let value = self.registers[register];
+        // reading the interrupt registers clears them
+        if register == INT1 || register == INT2 {
+            self.registers[register] = 0;
+        }
+        value
+    }
+}
+
+impl ErrorType for FakePmic {
+    type Error = ErrorKind;
+}
+
+impl I2c for FakePmic {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        if address != ADDRESS {
+            return Err(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address));
+        }
+        for operation in operations {
+            match operation {
+                Operation::Write(bytes) => {
+                    let Some((register, values)) = bytes.split_first() else {
+                        continue;
+                    };
+                    self.pointer = *register as usize;
+                    for value in values {
+                        self.write_register(self.pointer, *value);
+                        self.pointer += 1;
+                    }
+                }
+                Operation::Read(buffer) => {
+                    for value in buffer.iter_mut() {
+                        *value = self.read_register(self.pointer);
+                        self.pointer += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Delay which only records the elapsed time.
+#[derive(Default)]
+struct FakeDelay {
+    ns: u64,
+}
+
+impl FakeDelay {
+    fn ms(&self) -> u64 {
+        self.ns / 1_000_000
+    }
+}
+
+impl DelayNs for FakeDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.ns += ns as u64;
+    }
+}
+
+type Pmic = Tps65185<FakePmic>;
+
+#[test]
+fn power_up_polls_power_good() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    let mut delay = FakeDelay::default();
+    pmic.power_up(&mut delay).unwrap();
+    assert!(pmic.power_good().unwrap());
+    assert_eq!(pmic.enabled().unwrap(), Enable::ALL_RAILS);
+    // three polls without power good
+    assert_eq!(delay.ms(), 3);
+
+    pmic.power_down().unwrap();
+    assert!(!pmic.power_good().unwrap());
+}
+
+#[test]
+fn power_up_times_out() {
+    let mut fake = FakePmic::new();
+    fake.power_up_reads = None;
+    let mut pmic = Pmic::new(fake);
+    let mut delay = FakeDelay::default();
+    assert_eq!(pmic.power_up(&mut delay), Err(Error::Timeout));
+    assert_eq!(delay.ms(), Pmic::POLL_RETRIES as u64);
+}
+
+#[test]
+fn wrong_address_is_passed_through() {
+    let mut pmic = Pmic::with_address(FakePmic::new(), 0x48);
+    assert_eq!(
+        pmic.revision(),
+        Err(Error::I2c(ErrorKind::NoAcknowledge(
+            NoAcknowledgeSource::Address
+        )))
+    );
+}
+
+#[test]
+fn vcom_is_split_across_registers() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    assert_eq!(pmic.vcom().unwrap(), 1250);
+
+    pmic.set_vcom(2500).unwrap();
+    let fake = pmic.release();
+    assert_eq!(fake.registers[VCOM1], 250);
+    assert_eq!(fake.registers[VCOM2] & 1, 0);
+
+    let mut pmic = Pmic::new(fake);
+    pmic.set_vcom(5110).unwrap();
+    assert_eq!(pmic.vcom().unwrap(), 5110);
+    let fake = pmic.release();
+    assert_eq!(fake.registers[VCOM1], (511 & 0xFF) as u8);
+    assert_eq!(fake.registers[VCOM2] & 1, 1);
+
+    // the upper bit is cleared again, other bits of VCOM2 are kept
+    let mut fake = fake;
+    fake.registers[VCOM2] |= 0x80;
+    let mut pmic = Pmic::new(fake);
+    pmic.set_vcom(1000).unwrap();
+    assert_eq!(pmic.vcom().unwrap(), 1000);
+    assert_eq!(pmic.release().registers[VCOM2], 0x80);
+}
+
+#[test]
+fn vcom_out_of_range() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    assert_eq!(pmic.set_vcom(5120), Err(Error::InvalidVcom));
+    assert_eq!(pmic.vcom().unwrap(), 1250);
+}
+
+#[test]
+fn program_vcom_waits_for_completion() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    let mut delay = FakeDelay::default();
+    pmic.set_vcom(1560).unwrap();
+    pmic.program_vcom(&mut delay).unwrap();
+    assert_eq!(delay.ms(), 3);
+    assert_eq!(pmic.vcom().unwrap(), 1560);
+}
+
+#[test]
+fn program_vcom_ignores_stale_completion() {
+    let mut fake = FakePmic::new();
+    fake.program_reads = None;
+    // left over from an earlier programming
+    fake.registers[INT1] = INT1_PRGC;
+    let mut pmic = Pmic::new(fake);
+    let mut delay = FakeDelay::default();
+    assert_eq!(pmic.program_vcom(&mut delay), Err(Error::Timeout));
+}
+
+#[test]
+fn program_vcom_completes_on_first_poll() {
+    let mut fake = FakePmic::new();
+    fake.program_reads = Some(0);
+    let mut pmic = Pmic::new(fake);
+    let mut delay = FakeDelay::default();
+    pmic.program_vcom(&mut delay).unwrap();
+    assert_eq!(delay.ms(), 0);
+}
+
+#[test]
+fn interrupts_are_decoded() {
+    let mut fake = FakePmic::new();
+    fake.registers[INT1] = 0x02;
+    fake.registers[INT2] = 0x05;
+    let mut pmic = Pmic::new(fake);
+    let interrupts = pmic.interrupts().unwrap();
+    assert_eq!(interrupts, Interrupts(0x0205));
+    assert!(interrupts.contains(Interrupts::ACQC));
+    assert!(interrupts.contains(Interrupts::VCOMF));
+    assert!(interrupts.contains(Interrupts::EOC));
+    assert!(!interrupts.contains(Interrupts::PRGC));
+    assert!(interrupts.is_fault());
+    // reading clears them
+    assert_eq!(pmic.interrupts().unwrap(), Interrupts(0));
+    assert!(!Interrupts::EOC.is_fault());
+}
+
+#[test]
+fn interrupt_enable_covers_both_registers() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    pmic.set_interrupt_enable(Interrupts(
+        Interrupts::TSD.0 | Interrupts::PRGC.0 | Interrupts::VCOMF.0,
+    ))
+    .unwrap();
+    let fake = pmic.release();
+    assert_eq!(fake.registers[INT_EN1], 0x41);
+    assert_eq!(fake.registers[INT_EN2], 0x04);
+}
+
+#[test]
+fn temperature_conversion() {
+    let mut fake = FakePmic::new();
+    fake.registers[TMST_VALUE] = 0xF6;
+    let mut pmic = Pmic::new(fake);
+    let mut delay = FakeDelay::default();
+    assert_eq!(pmic.temperature(&mut delay).unwrap(), -10);
+    assert_eq!(delay.ms(), 2);
+
+    let mut fake = pmic.release();
+    fake.registers[TMST_VALUE] = 25;
+    fake.conversion_reads = None;
+    let mut pmic = Pmic::new(fake);
+    assert_eq!(pmic.temperature(&mut delay), Err(Error::Timeout));
+}
+
+#[test]
+fn sequences_are_encoded() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    pmic.set_power_up_sequence(Sequence {
+        vddh: 0,
+        vpos: 1,
+        vee: 2,
+        vneg: 3,
+        delays: [0, 1, 2, 3],
+    })
+    .unwrap();
+    pmic.set_power_down_sequence(Sequence::POWER_DOWN).unwrap();
+    let fake = pmic.release();
+    // strobes VDDH, VPOS, VEE, VNEG from the high bits, delays from the low bits
+    assert_eq!(fake.registers[UPSEQ0], 0b00_01_10_11);
+    assert_eq!(fake.registers[UPSEQ1], 0b11_10_01_00);
+    assert_eq!(fake.registers[DWNSEQ0], 0b00_01_10_11);
+    assert_eq!(fake.registers[DWNSEQ1], 0);
+
+    let mut pmic = Pmic::new(fake);
+    pmic.set_power_up_sequence(Sequence::POWER_UP).unwrap();
+    let fake = pmic.release();
+    assert_eq!(fake.registers[UPSEQ0], 0b11_10_01_00);
+    assert_eq!(fake.registers[UPSEQ1], 0b01_01_01_01);
+}
+
+#[test]
+fn invalid_sequence_is_not_written() {
+    let mut pmic = Pmic::new(FakePmic::new());
+    let invalid = Sequence {
+        delays: [0, 4, 0, 0],
+        ..Sequence::POWER_UP
+    };
+    assert_eq!(
+        pmic.set_power_up_sequence(invalid),
+        Err(Error::InvalidSequence)
+    );
+    let invalid = Sequence {
+        vneg: 4,
+        ..Sequence::POWER_DOWN
+    };
+    assert_eq!(
+        pmic.set_power_down_sequence(invalid),
+        Err(Error::InvalidSequence)
+    );
+    let fake = pmic.release();
+    assert_eq!(fake.registers[UPSEQ0], 0xE4);
+    assert_eq!(fake.registers[UPSEQ1], 0x55);
+    assert_eq!(fake.registers[DWNSEQ0], 0x1E);
+    assert_eq!(fake.registers[DWNSEQ1], 0xE0);
+}
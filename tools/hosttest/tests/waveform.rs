@@ -0,0 +1,201 @@
+use hosttest::{
+    waveform::{Action, TemperatureRange, Waveform, MAX_PHASES, PHASE_SIZE},
+    Error,
+};
+
+/// A temperature range of the binary format: bounds, lookup tables and
+/// optional phase times.
+struct Range {
+    min: i8,
+    max: i8,
+    luts: Vec<u8>,
+    times: Option<Vec<u16>>,
+}
+
+impl Range {
+    /// A range of `phases` phases darkening every pixel.
+    fn new(min: i8, max: i8, phases: usize) -> Self {
+        Range {
+            min,
+            max,
+            luts: vec![0x55; phases * PHASE_SIZE],
+            times: None,
+        }
+    }
+
+    fn with_times(self, times: &[u16]) -> Self {
+        Range {
+            times: Some(times.to_vec()),
+            ..self
+        }
+    }
+}
+
+/// The binary representation of the ranges.
+fn blob(ranges: &[Range]) -> Vec<u8> {
+    let mut data = b"EPW4".to_vec();
+    data.extend_from_slice(&[1, ranges.len() as u8, 0, 0]);
+    for range in ranges {
+        let phases = (range.luts.len() / PHASE_SIZE) as u8;
+        let flags = range.times.is_some() as u8;
+        data.extend_from_slice(&[range.min as u8, range.max as u8, phases, flags]);
+        data.extend_from_slice(&range.luts);
+        for time in range.times.iter().flatten() {
+            data.extend_from_slice(&time.to_le_bytes());
+        }
+    }
+    data
+}
+
+#[test]
+fn parses_ranges_and_phases() {
+    let mut first = Range::new(0, 20, 2);
+    // phase 1: lighten pixels at level 0 with the target 15
+    first.luts[PHASE_SIZE + 15 * 4] = 0b1000_0000;
+    let data = blob(&[first, Range::new(20, 40, 3).with_times(&[10, 20, 30])]);
+    let waveform = Waveform::from_bytes(&data).unwrap();
+
+    let ranges: Vec<_> = waveform.ranges().collect();
+    assert_eq!(ranges.len(), 2);
+    assert_eq!(ranges[0].0, TemperatureRange { min: 0, max: 20 });
+    assert_eq!(ranges[0].1.len(), 2);
+    assert_eq!(ranges[0].1.time(0), None);
+    assert_eq!(ranges[0].1.action(0, 0, 15), Action::Darken);
+    assert_eq!(ranges[0].1.action(1, 0, 15), Action::Lighten);
+    assert_eq!(ranges[0].1.action(1, 1, 15), Action::None);
+    assert_eq!(ranges[1].1.len(), 3);
+    assert_eq!(ranges[1].1.time(2), Some(30));
+}
+
+#[test]
+fn selects_the_closest_range() {
+    let data = blob(&[Range::new(0, 20, 1), Range::new(25, 40, 2)]);
+    let waveform = Waveform::from_bytes(&data).unwrap();
+    assert_eq!(waveform.phases(10).len(), 1);
+    assert_eq!(waveform.phases(-10).len(), 1);
+    assert_eq!(waveform.phases(21).len(), 1);
+    assert_eq!(waveform.phases(24).len(), 2);
+    assert_eq!(waveform.phases(60).len(), 2);
+}
+
+#[test]
+fn round_trip() {
+    let data = blob(&[
+        Range::new(-10, 5, 1),
+        Range::new(5, 30, MAX_PHASES).with_times(&[100; MAX_PHASES]),
+    ]);
+    let waveform = Waveform::from_bytes(&data).unwrap();
+    let bytes = waveform.to_bytes();
+    assert_eq!(bytes, data);
+    let copy = Waveform::from_bytes(&bytes).unwrap();
+    assert_eq!(copy.ranges().count(), 2);
+    for ((range, phases), (copy_range, copy_phases)) in waveform.ranges().zip(copy.ranges()) {
+        assert_eq!(range, copy_range);
+        assert_eq!(phases.len(), copy_phases.len());
+        assert_eq!(phases.time(0), copy_phases.time(0));
+    }
+}
+
+#[test]
+fn rejects_bad_magic_and_version() {
+    let data = blob(&[Range::new(0, 20, 1)]);
+    let mut magic = data.clone();
+    magic[0..4].copy_from_slice(b"EPW3");
+    assert_eq!(
+        Waveform::from_bytes(&magic).unwrap_err(),
+        Error::InvalidFormat
+    );
+    let mut version = data.clone();
+    version[4] = 2;
+    assert_eq!(
+        Waveform::from_bytes(&version).unwrap_err(),
+        Error::InvalidFormat
+    );
+}
+
+#[test]
+fn rejects_truncated_data() {
+    let data = blob(&[Range::new(0, 20, 2).with_times(&[10, 20])]);
+    // truncated header, range header, lookup tables and phase times
+    for len in [0, 5, 8, 10, 12 + PHASE_SIZE, data.len() - 1] {
+        assert_eq!(
+            Waveform::from_bytes(&data[..len]).unwrap_err(),
+            Error::InvalidFormat,
+            "{len} bytes"
+        );
+    }
+    // no temperature ranges
+    assert_eq!(
+        Waveform::from_bytes(&blob(&[])).unwrap_err(),
+        Error::InvalidFormat
+    );
+}
+
+#[test]
+fn rejects_invalid_phase_counts() {
+    let empty = blob(&[Range::new(0, 20, 0)]);
+    assert_eq!(
+        Waveform::from_bytes(&empty).unwrap_err(),
+        Error::InvalidFormat
+    );
+    let too_many = blob(&[Range::new(0, 20, MAX_PHASES + 1)]);
+    assert_eq!(
+        Waveform::from_bytes(&too_many).unwrap_err(),
+        Error::InvalidFormat
+    );
+    assert!(Waveform::from_bytes(&blob(&[Range::new(0, 20, MAX_PHASES)])).is_ok());
+}
+
+#[test]
+fn rejects_invalid_ranges() {
+    for ranges in [
+        // overlapping
+        [Range::new(0, 20, 1), Range::new(19, 40, 1)],
+        // unsorted
+        [Range::new(20, 40, 1), Range::new(0, 20, 1)],
+        // empty
+        [Range::new(0, 20, 1), Range::new(20, 20, 1)],
+    ] {
+        assert_eq!(
+            Waveform::from_bytes(&blob(&ranges)).unwrap_err(),
+            Error::InvalidFormat
+        );
+    }
+    // adjacent ranges are fine
+    assert!(Waveform::from_bytes(&blob(&[Range::new(0, 20, 1), Range::new(20, 40, 1)])).is_ok());
+}
+
+#[test]
+fn rejects_invalid_codes_flags_and_times() {
+    let mut code = Range::new(0, 20, 1);
+    code.luts[7] = 0b0011_0000;
+    assert_eq!(
+        Waveform::from_bytes(&blob(&[code])).unwrap_err(),
+        Error::InvalidFormat
+    );
+
+    let mut flags = blob(&[Range::new(0, 20, 1)]);
+    flags[11] = 0b10;
+    assert_eq!(
+        Waveform::from_bytes(&flags).unwrap_err(),
+        Error::InvalidFormat
+    );
+
+    for time in [0, 0x8000] {
+        let times = blob(&[Range::new(0, 20, 1).with_times(&[time])]);
+        assert_eq!(
+            Waveform::from_bytes(&times).unwrap_err(),
+            Error::InvalidFormat
+        );
+    }
+}
+
+#[test]
+fn rejects_trailing_bytes() {
+    let mut data = blob(&[Range::new(0, 20, 1)]);
+    data.push(0);
+    assert_eq!(
+        Waveform::from_bytes(&data).unwrap_err(),
+        Error::InvalidFormat
+    );
+}
@@ -0,0 +1,82 @@
+//! Quantization of grayscale images to 16 levels.
+
+use clap::ValueEnum;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Dithering {
+    /// Round to the nearest level, best for text and line art.
+    None,
+    /// Floyd-Steinberg error diffusion.
+    FloydSteinberg,
+    /// Atkinson error diffusion, higher contrast than Floyd-Steinberg.
+    Atkinson,
+    /// Ordered dithering using a 4x4 Bayer matrix, no "worms" in flat areas.
+    Ordered,
+}
+
+const LEVELS: f32 = 15.0;
+
+const BAYER_4X4: [[f32; 4]; 4] = [
+    [0.0, 8.0, 2.0, 10.0],
+    [12.0, 4.0, 14.0, 6.0],
+    [3.0, 11.0, 1.0, 9.0],
+    [15.0, 7.0, 13.0, 5.0],
+];
+
+/// (dx, dy, weight) of the error diffusion kernels.
+const FLOYD_STEINBERG: &[(isize, usize, f32)] = &[
+    (1, 0, 7.0 / 16.0),
+    (-1, 1, 3.0 / 16.0),
+    (0, 1, 5.0 / 16.0),
+    (1, 1, 1.0 / 16.0),
+];
+const ATKINSON: &[(isize, usize, f32)] = &[
+    (1, 0, 1.0 / 8.0),
+    (2, 0, 1.0 / 8.0),
+    (-1, 1, 1.0 / 8.0),
+    (0, 1, 1.0 / 8.0),
+    (1, 1, 1.0 / 8.0),
+    (0, 2, 1.0 / 8.0),
+];
+
+fn quantize(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * LEVELS).round() as u8
+}
+
+/// Quantize the gray values (0.0 black - 1.0 white) of an image to levels
+/// 0x0 - 0xF.
+pub fn dither(mut pixels: Vec<f32>, width: usize, dithering: Dithering) -> Vec<u8> {
+    let kernel = match dithering {
+        Dithering::None => return pixels.into_iter().map(quantize).collect(),
+        Dithering::Ordered => {
+            return pixels
+                .iter()
+                .enumerate()
+                .map(|(i, value)| {
+                    let threshold =
+                        BAYER_4X4[i / width % 4][i % width % 4] / 16.0 - 0.5 + 1.0 / 32.0;
+                    quantize(value + threshold / LEVELS)
+                })
+                .collect()
+        }
+        Dithering::FloydSteinberg => FLOYD_STEINBERG,
+        Dithering::Atkinson => ATKINSON,
+    };
+    let height = pixels.len() / width;
+    let mut levels = Vec::with_capacity(pixels.len());
+    for y in 0..height {
+        for x in 0..width {
+            let value = pixels[y * width + x];
+            let level = quantize(value);
+            let error = value - level as f32 / LEVELS;
+            for &(dx, dy, weight) in kernel {
+                let (nx, ny) = (x as isize + dx, y + dy);
+                if nx >= 0 && (nx as usize) < width && ny < height {
+                    pixels[ny * width + nx as usize] += error * weight;
+                }
+            }
+            levels.push(level);
+        }
+    }
+    levels
+}
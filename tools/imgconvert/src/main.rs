@@ -0,0 +1,313 @@
+//! Convert PNG, JPEG or BMP images to the packed 4bpp format of
+//! `lilygo-epd47` (see `lilygo_epd47::image`).
+//!
+//! ```sh
+//! cargo run -p imgconvert --target x86_64-unknown-linux-gnu -Zbuild-std=std -- \
+//!     logo.png --width 200 --dithering floyd-steinberg --output src/logo.rs
+//! ```
+
+extern crate alloc;
+
+mod dither;
+#[path = "../../../src/rle.rs"]
+#[allow(dead_code)]
+mod rle;
+
+use std::{fmt::Write as _, fs, path::PathBuf};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use image::{imageops::FilterType, GrayImage};
+
+use crate::dither::{dither, Dithering};
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// PNG, JPEG or BMP image.
+    input: PathBuf,
+    /// Output file.
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Output format, derived from the file extension by default (`.rs` for
+    /// a Rust module, raw packed pixels otherwise).
+    #[arg(short, long)]
+    format: Option<OutputFormat>,
+    /// Name of the static in the generated Rust module.
+    #[arg(long, default_value = "IMAGE")]
+    name: String,
+    /// Run-length encode the image, load it using `CompressedImage::new`.
+    #[arg(short, long)]
+    compress: bool,
+    /// Resize to the width in pixels, keeps the aspect ratio if no height is
+    /// provided.
+    #[arg(long)]
+    width: Option<u32>,
+    /// Resize to the height in pixels, keeps the aspect ratio if no width is
+    /// provided.
+    #[arg(long)]
+    height: Option<u32>,
+    /// Gamma correction, values above 1.0 brighten the mid tones.
+    #[arg(long, default_value_t = 1.0)]
+    gamma: f32,
+    /// Contrast adjustment, 1.0 keeps the contrast as is.
+    #[arg(long, default_value_t = 1.0)]
+    contrast: f32,
+    /// Invert the image, e.g. for `DrawMode::WhiteOnBlack`.
+    #[arg(long)]
+    invert: bool,
+    /// Dithering applied when reducing to 16 gray levels.
+    #[arg(short, long, default_value = "floyd-steinberg")]
+    dithering: Dithering,
+    /// Additionally write the converted image as PNG to check the result.
+    #[arg(long)]
+    preview: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    /// Rust module containing the image as a static byte slice.
+    Rust,
+    /// Raw packed pixels, e.g. for `include_bytes!`.
+    Raw,
+}
+
+/// Load the image as gray values (0.0 black - 1.0 white). Transparent areas
+/// are blended with white.
+fn load(args: &Args) -> Result<(Vec<f32>, u32, u32)> {
+    let image = image::open(&args.input)
+        .with_context(|| format!("Failed to load {}", args.input.display()))?;
+    let (width, height) = match (args.width, args.height) {
+        (None, None) => (image.width(), image.height()),
+        (Some(width), None) => (width, image.height() * width / image.width()),
+        (None, Some(height)) => (image.width() * height / image.height(), height),
+        (Some(width), Some(height)) => (width, height),
+    };
+    if width == 0 || height == 0 || width > u16::MAX as u32 || height > u16::MAX as u32 {
+        bail!("Invalid image size {width}x{height}");
+    }
+    let image = match (width, height) == (image.width(), image.height()) {
+        true => image,
+        false => image.resize_exact(width, height, FilterType::Lanczos3),
+    };
+    let pixels = image
+        .to_rgba8()
+        .pixels()
+        .map(|pixel| {
+            let [r, g, b, a] = pixel.0.map(|c| c as f32 / 255.0);
+            let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+            luma * a + (1.0 - a)
+        })
+        .collect();
+    Ok((pixels, width, height))
+}
+
+fn adjust(value: f32, args: &Args) -> f32 {
+    let value = ((value - 0.5) * args.contrast + 0.5).clamp(0.0, 1.0);
+    let value = value.powf(1.0 / args.gamma);
+    match args.invert {
+        true => 1.0 - value,
+        false => value,
+    }
+}
+
+/// Pack the levels, two pixels per byte with the low nibble holding the left
+/// pixel. Each row starts on a new byte.
+fn pack(levels: &[u8], width: usize) -> Vec<u8> {
+    if width == 0 {
+        return Vec::new();
+    }
+    levels
+        .chunks(width)
+        .flat_map(|row| row.chunks(2))
+        .map(|pair| pair[0] | pair.get(1).map_or(0, |right| right << 4))
+        .collect()
+}
+
+fn rust_module(args: &Args, width: u32, height: u32, data: &[u8]) -> String {
+    let file_name = args.input.file_name().unwrap_or_default().to_string_lossy();
+    let name = &args.name;
+    let mut module = String::new();
+    writeln!(module, "//! Generated by imgconvert, do not edit.").unwrap();
+    writeln!(module, "//!").unwrap();
+    writeln!(module, "//! Image: `{file_name}`, {width}x{height}").unwrap();
+    writeln!(module).unwrap();
+    writeln!(module, "/// Width of [`{name}`] in pixels.").unwrap();
+    writeln!(module, "pub const {name}_WIDTH: u16 = {width};").unwrap();
+    writeln!(module, "/// Height of [`{name}`] in pixels.").unwrap();
+    writeln!(module, "pub const {name}_HEIGHT: u16 = {height};").unwrap();
+    let loader = match args.compress {
+        true => "CompressedImage",
+        false => "Image",
+    };
+    writeln!(
+        module,
+        "/// Load using `lilygo_epd47::image::{loader}::new`."
+    )
+    .unwrap();
+    writeln!(module, "#[rustfmt::skip]").unwrap();
+    writeln!(module, "pub static {name}: &[u8] = &[").unwrap();
+    for line in data.chunks(16) {
+        let bytes = line
+            .iter()
+            .map(|b| format!("0x{b:02X},"))
+            .collect::<Vec<_>>();
+        writeln!(module, "    {}", bytes.join(" ")).unwrap();
+    }
+    writeln!(module, "];").unwrap();
+    module
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.gamma <= 0.0 || args.contrast < 0.0 {
+        bail!("Gamma must be positive and contrast must not be negative");
+    }
+    let (pixels, width, height) = load(&args)?;
+    let pixels = pixels
+        .into_iter()
+        .map(|value| adjust(value, &args))
+        .collect();
+    let levels = dither(pixels, width as usize, args.dithering);
+
+    if let Some(preview) = &args.preview {
+        let preview_pixels = levels.iter().map(|level| level * 17).collect();
+        GrayImage::from_raw(width, height, preview_pixels)
+            .context("Invalid preview size")?
+            .save(preview)?;
+    }
+    let mut data = pack(&levels, width as usize);
+    if args.compress {
+        data = rle::encode(&data, width as u16, height as u16);
+    }
+    let format = args.format.unwrap_or_else(|| {
+        match args.output.extension().is_some_and(|ext| ext == "rs") {
+            true => OutputFormat::Rust,
+            false => OutputFormat::Raw,
+        }
+    });
+    match format {
+        OutputFormat::Rust => fs::write(&args.output, rust_module(&args, width, height, &data))?,
+        OutputFormat::Raw => fs::write(&args.output, &data)?,
+    }
+    eprintln!(
+        "{width}x{height}, {} bytes written to {}",
+        data.len(),
+        args.output.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode using the decoder of the driver.
+    fn decode(data: &[u8]) -> Option<(u16, u16, Vec<u8>)> {
+        let (width, height, mut rows) = rle::parse_header(data)?;
+        let mut levels = Vec::new();
+        for _ in 0..height {
+            let mut row = Vec::new();
+            rle::decode_row(&mut rows, width as usize, |x, span| {
+                assert_eq!(x, row.len());
+                match span {
+                    rle::Span::Run { color, length } => row.extend((0..length).map(|_| color)),
+                    rle::Span::Literal { data, length } => {
+                        row.extend((0..length).map(|i| (data[i / 2] >> (4 * (i % 2))) & 0x0F))
+                    }
+                }
+            })?;
+            levels.extend(row);
+        }
+        rows.is_empty().then_some((width, height, levels))
+    }
+
+    fn round_trip(levels: &[u8], width: u16, height: u16) -> Vec<u8> {
+        let encoded = rle::encode(&pack(levels, width as usize), width, height);
+        let (rows_width, rows_height, rows) = rle::parse_header(&encoded).unwrap();
+        assert!(rle::validate(rows, rows_width, rows_height));
+        assert_eq!(decode(&encoded), Some((width, height, levels.to_vec())));
+        encoded
+    }
+
+    /// Deterministic pseudo random levels with runs of random length.
+    fn random_levels(len: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        let mut levels = Vec::with_capacity(len);
+        while levels.len() < len {
+            let (color, run) = ((next() % 16) as u8, next() % 8);
+            let run = match run {
+                0 => next() % 3000,
+                _ => run,
+            };
+            levels.extend((0..run).map(|_| color));
+        }
+        levels.truncate(len);
+        levels
+    }
+
+    #[test]
+    fn round_trip_random() {
+        for (seed, (width, height)) in [(1, 1), (2, 2), (3, 3), (7, 5), (960, 540), (4097, 2)]
+            .into_iter()
+            .enumerate()
+        {
+            let levels = random_levels(width * height, seed as u32 + 1);
+            round_trip(&levels, width as u16, height as u16);
+        }
+    }
+
+    #[test]
+    fn round_trip_noise() {
+        let levels = (0..131 * 17)
+            .map(|i| (i * 7 % 16) as u8)
+            .collect::<Vec<_>>();
+        round_trip(&levels, 131, 17);
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        round_trip(&[], 0, 0);
+        round_trip(&[], 0, 10);
+        round_trip(&[], 10, 0);
+    }
+
+    #[test]
+    fn blank_screen_is_small() {
+        let encoded = round_trip(&vec![0xF; 960 * 540], 960, 540);
+        assert_eq!(encoded.len(), rle::HEADER_SIZE + 540 * 2);
+    }
+
+    #[test]
+    fn rejects_malformed_data() {
+        let levels = random_levels(64 * 8, 42);
+        let encoded = round_trip(&levels, 64, 8);
+        // truncated
+        for len in [0, 4, rle::HEADER_SIZE, encoded.len() - 1] {
+            assert!(decode(&encoded[..len]).is_none());
+        }
+        // trailing data
+        let mut trailing = encoded.clone();
+        trailing.push(0);
+        let (width, height, rows) = rle::parse_header(&trailing).unwrap();
+        assert!(!rle::validate(rows, width, height));
+        // span exceeding the row
+        let mut overflow = rle::encode(&[], 4, 0);
+        overflow[8] = 1;
+        overflow.extend_from_slice(&[0x84, 0x0F]);
+        assert!(decode(&overflow).is_none());
+        // wrong magic / version
+        let mut magic = encoded.clone();
+        magic[0] = b'X';
+        assert!(decode(&magic).is_none());
+        let mut version = encoded;
+        version[4] = 2;
+        assert!(decode(&version).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+//! Waveform JSON files as used by [epdiy], e.g. extracted from a vendor
+//! `.wbf` file using its scripts.
+//!
+//! ```json
+//! {
+//!   "temperature_ranges": {"range_bounds": [0, 3, 6, ...]},
+//!   "modes": [
+//!     {
+//!       "mode": 2,
+//!       "ranges": [{"index": 5, "phases": [[[0, 1, ...], ...], ...]}]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Temperature range `i` spans from bound `i` (inclusive) to bound `i + 1`
+//! (exclusive). Each phase is a 16 x 16 matrix indexed by the target and the
+//! current level, `0` leaves the pixel alone, `1` darkens and `2` lightens
+//! it. Additionally, a range may contain `phase_times` (in ticks of 0.1µs).
+//!
+//! [epdiy]: https://github.com/vroland/epdiy
+
+use anyhow::{bail, ensure, Context, Result};
+use serde::Deserialize;
+
+/// Size of the packed lookup table of a single phase.
+pub const PHASE_SIZE: usize = 64;
+/// Longest phase time supported by the driver.
+const MAX_PHASE_TIME: u16 = 0x7FFF;
+/// Largest number of phases of a temperature range supported by the driver.
+const MAX_PHASES: usize = 128;
+
+#[derive(Deserialize)]
+struct WaveformJson {
+    temperature_ranges: TemperatureRangesJson,
+    modes: Vec<ModeJson>,
+}
+
+#[derive(Deserialize)]
+struct TemperatureRangesJson {
+    range_bounds: Vec<i32>,
+}
+
+#[derive(Deserialize)]
+struct ModeJson {
+    mode: u8,
+    ranges: Vec<RangeJson>,
+}
+
+#[derive(Deserialize)]
+struct RangeJson {
+    index: usize,
+    phases: Vec<Vec<Vec<u8>>>,
+    #[serde(default)]
+    phase_times: Option<Vec<u16>>,
+}
+
+/// A validated waveform.
+pub struct Waveform {
+    pub modes: Vec<Mode>,
+}
+
+pub struct Mode {
+    /// epdiy mode number, e.g. 2 for GC16.
+    pub mode: u8,
+    pub ranges: Vec<Range>,
+}
+
+pub struct Range {
+    /// Index of the temperature range.
+    pub index: usize,
+    /// Lower bound (inclusive) in °C.
+    pub min: i8,
+    /// Upper bound (exclusive) in °C.
+    pub max: i8,
+    /// Packed lookup tables, see [`pack_phase`].
+    pub phases: Vec<[u8; PHASE_SIZE]>,
+    pub phase_times: Option<Vec<u16>>,
+}
+
+impl Waveform {
+    pub fn mode(&self, mode: u8) -> Option<&Mode> {
+        self.modes.iter().find(|m| m.mode == mode)
+    }
+}
+
+/// Name of the epdiy mode.
+pub fn mode_name(mode: u8) -> String {
+    match mode {
+        1 => "DU".into(),
+        2 => "GC16".into(),
+        3 => "GC16_FAST".into(),
+        4 => "A2".into(),
+        5 => "GL16".into(),
+        6 => "GLR16".into(),
+        7 => "GLD16".into(),
+        _ => format!("MODE_{mode}"),
+    }
+}
+
+/// Pack a phase to 4 bytes per target level, the highest bits of each byte
+/// hold the first of its 4 current levels.
+fn pack_phase(phase: &[Vec<u8>]) -> Result<[u8; PHASE_SIZE]> {
+    ensure!(phase.len() == 16, "A phase must have 16 target levels");
+    let mut packed = [0u8; PHASE_SIZE];
+    for (to, row) in phase.iter().enumerate() {
+        ensure!(row.len() == 16, "A phase must have 16 current levels");
+        for (from, &code) in row.iter().enumerate() {
+            ensure!(code <= 2, "Invalid code {code}, expected 0, 1 or 2");
+            packed[to * 4 + from / 4] |= code << (6 - 2 * (from % 4));
+        }
+    }
+    Ok(packed)
+}
+
+/// Parse and validate a waveform JSON file.
+pub fn parse(json: &str) -> Result<Waveform> {
+    let waveform: WaveformJson = serde_json::from_str(json).context("Invalid waveform JSON")?;
+    let bounds = waveform.temperature_ranges.range_bounds;
+    ensure!(
+        bounds.windows(2).all(|bounds| bounds[0] < bounds[1]),
+        "Temperature range bounds must be ascending"
+    );
+    let bound = |index: usize| -> Result<i8> {
+        let bound = *bounds
+            .get(index)
+            .with_context(|| format!("Temperature range {index} does not exist"))?;
+        i8::try_from(bound).with_context(|| format!("Temperature {bound} is out of range"))
+    };
+    ensure!(!waveform.modes.is_empty(), "The waveform has no modes");
+    let mut modes: Vec<Mode> = Vec::new();
+    for mode in waveform.modes {
+        if modes.iter().any(|m| m.mode == mode.mode) {
+            bail!("Mode {} is defined twice", mode.mode);
+        }
+        ensure!(!mode.ranges.is_empty(), "Mode {} has no ranges", mode.mode);
+        let mut ranges: Vec<Range> = Vec::new();
+        for range in mode.ranges {
+            if let Some(previous) = ranges.last() {
+                ensure!(
+                    previous.index < range.index,
+                    "Ranges of mode {} must be sorted by index",
+                    mode.mode
+                );
+            }
+            let context = || format!("Mode {}, range {}", mode.mode, range.index);
+            ensure!(
+                (1..=MAX_PHASES).contains(&range.phases.len()),
+                "{}: Expected 1 to {MAX_PHASES} phases",
+                context()
+            );
+            let phases = range
+                .phases
+                .iter()
+                .map(|phase| pack_phase(phase))
+                .collect::<Result<Vec<_>>>()
+                .with_context(context)?;
+            if let Some(times) = &range.phase_times {
+                ensure!(
+                    times.len() == phases.len(),
+                    "{}: Expected a time for each phase",
+                    context()
+                );
+                ensure!(
+                    times.iter().all(|time| (1..=MAX_PHASE_TIME).contains(time)),
+                    "{}: Phase times must be between 1 and {MAX_PHASE_TIME}",
+                    context()
+                );
+            }
+            ranges.push(Range {
+                index: range.index,
+                min: bound(range.index)?,
+                max: bound(range.index + 1)?,
+                phases,
+                phase_times: range.phase_times,
+            });
+        }
+        modes.push(Mode {
+            mode: mode.mode,
+            ranges,
+        });
+    }
+    Ok(Waveform { modes })
+}
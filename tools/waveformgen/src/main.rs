@@ -0,0 +1,295 @@
+//! Generate the waveform tables of `lilygo-epd47` (see
+//! `lilygo_epd47::waveform`) from [epdiy] waveform JSON files.
+//!
+//! The built-in tables are generated as Rust module:
+//!
+//! ```sh
+//! cargo run -p waveformgen --target x86_64-unknown-linux-gnu -Zbuild-std=std -- \
+//!     waveforms/ed047tc2.json --output src/waveform/ed047tc2.rs
+//! ```
+//!
+//! A single mode can be exported in the binary format loaded by
+//! `Waveform::from_bytes`:
+//!
+//! ```sh
+//! cargo run -p waveformgen --target x86_64-unknown-linux-gnu -Zbuild-std=std -- \
+//!     custom.json --mode 2 --output gc16.epw
+//! ```
+//!
+//! [epdiy]: https://github.com/vroland/epdiy
+
+mod epdiy;
+
+use std::{fmt::Write as _, fs, path::PathBuf};
+
+use anyhow::{bail, ensure, Context, Result};
+use clap::{Parser, ValueEnum};
+
+use crate::epdiy::{mode_name, Waveform, PHASE_SIZE};
+
+const MAGIC: &[u8; 4] = b"EPW4";
+const VERSION: u8 = 1;
+const FLAG_PHASE_TIMES: u8 = 1 << 0;
+
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// epdiy waveform JSON file.
+    input: PathBuf,
+    /// Output file.
+    #[arg(short, long)]
+    output: PathBuf,
+    /// Output format, derived from the file extension by default (`.rs` for
+    /// a Rust module, binary otherwise).
+    #[arg(short, long)]
+    format: Option<OutputFormat>,
+    /// Name of the panel in the documentation of the Rust module, derived
+    /// from the input file name by default.
+    #[arg(long)]
+    name: Option<String>,
+    /// epdiy mode exported to the binary format, e.g. 2 for GC16.
+    #[arg(short, long)]
+    mode: Option<u8>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    /// Rust module with the tables of all modes.
+    Rust,
+    /// Binary waveform of a single mode, see `Waveform::from_bytes`.
+    Binary,
+}
+
+/// Rust module with the temperature ranges and the tables of each mode, gated
+/// by a `waveform-<mode>` feature. All modes must use the same temperature
+/// ranges.
+fn rust_module(waveform: &Waveform, name: &str, source: &str) -> Result<String> {
+    let first = &waveform.modes[0];
+    for mode in &waveform.modes {
+        ensure!(
+            mode.ranges
+                .iter()
+                .map(|r| r.index)
+                .eq(first.ranges.iter().map(|r| r.index)),
+            "All modes must use the same temperature ranges"
+        );
+        ensure!(
+            mode.ranges.iter().all(|range| range.phase_times.is_none()),
+            "Phase times are only supported by the binary format"
+        );
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! Waveforms of the {name} panel, taken from [epdiy]."
+    )?;
+    writeln!(out, "//!")?;
+    writeln!(out, "//! Generated from `{source}` by `tools/waveformgen`.")?;
+    writeln!(out, "//! Don't edit by hand.")?;
+    writeln!(out, "//!")?;
+    writeln!(out, "//! [epdiy]: https://github.com/vroland/epdiy")?;
+    writeln!(out)?;
+    writeln!(out, "use super::{{Table, TemperatureRange}};")?;
+    writeln!(out)?;
+    writeln!(out, "/// Temperature ranges of the phases of each mode.")?;
+    writeln!(
+        out,
+        "pub(super) const TEMPERATURE_RANGES: [TemperatureRange; {}] = [",
+        first.ranges.len()
+    )?;
+    for range in &first.ranges {
+        writeln!(
+            out,
+            "    TemperatureRange {{ min: {}, max: {} }},",
+            range.min, range.max
+        )?;
+    }
+    writeln!(out, "];")?;
+
+    for mode in &waveform.modes {
+        let mode_name = mode_name(mode.mode);
+        let feature = format!("waveform-{}", mode_name.to_lowercase().replace('_', "-"));
+        let luts: Vec<u8> = mode
+            .ranges
+            .iter()
+            .flat_map(|range| range.phases.concat())
+            .collect();
+        let phases: Vec<String> = mode
+            .ranges
+            .iter()
+            .map(|range| range.phases.len().to_string())
+            .collect();
+        writeln!(out)?;
+        writeln!(
+            out,
+            "/// Phases of the {mode_name} (epdiy mode {}) mode for each temperature range.",
+            mode.mode
+        )?;
+        writeln!(out, "#[cfg(feature = \"{feature}\")]")?;
+        writeln!(out, "pub(super) static {mode_name}: Table = Table {{")?;
+        writeln!(out, "    phases: &[{}],", phases.join(", "))?;
+        writeln!(out, "    luts: &{mode_name}_LUTS,")?;
+        writeln!(out, "}};")?;
+        writeln!(out)?;
+        writeln!(out, "#[cfg(feature = \"{feature}\")]")?;
+        writeln!(out, "static {mode_name}_LUTS: [u8; {}] = [", luts.len())?;
+        // 16 bytes per line as laid out by rustfmt
+        for line in luts.chunks(16) {
+            let bytes: Vec<String> = line.iter().map(|b| format!("0x{b:02x},")).collect();
+            writeln!(out, "    {}", bytes.join(" "))?;
+        }
+        writeln!(out, "];")?;
+    }
+    Ok(out)
+}
+
+/// Binary waveform of a single mode.
+fn binary(waveform: &Waveform, mode: u8) -> Result<Vec<u8>> {
+    let mode = waveform
+        .mode(mode)
+        .with_context(|| format!("The waveform has no mode {mode}"))?;
+    ensure!(mode.ranges.len() <= 255, "Too many temperature ranges");
+    let mut data = Vec::new();
+    data.extend_from_slice(MAGIC);
+    data.extend_from_slice(&[VERSION, mode.ranges.len() as u8, 0, 0]);
+    for range in &mode.ranges {
+        let flags = match range.phase_times {
+            Some(_) => FLAG_PHASE_TIMES,
+            None => 0,
+        };
+        data.extend_from_slice(&[
+            range.min as u8,
+            range.max as u8,
+            range.phases.len() as u8,
+            flags,
+        ]);
+        for phase in &range.phases {
+            data.extend_from_slice(phase);
+        }
+        for time in range.phase_times.iter().flatten() {
+            data.extend_from_slice(&time.to_le_bytes());
+        }
+    }
+    Ok(data)
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let json = fs::read_to_string(&args.input)
+        .with_context(|| format!("Failed to read {}", args.input.display()))?;
+    let waveform = epdiy::parse(&json)?;
+    let format = args.format.unwrap_or_else(|| {
+        match args.output.extension().is_some_and(|ext| ext == "rs") {
+            true => OutputFormat::Rust,
+            false => OutputFormat::Binary,
+        }
+    });
+    match format {
+        OutputFormat::Rust => {
+            let stem = args
+                .input
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .context("Invalid input file name")?;
+            let name = args.name.clone().unwrap_or_else(|| stem.to_uppercase());
+            let source = format!("waveforms/{stem}.json");
+            fs::write(&args.output, rust_module(&waveform, &name, &source)?)?
+        }
+        OutputFormat::Binary => {
+            let Some(mode) = args.mode else {
+                bail!("Select the mode to export using --mode");
+            };
+            fs::write(&args.output, binary(&waveform, mode)?)?
+        }
+    }
+    let phases: usize = waveform
+        .modes
+        .iter()
+        .flat_map(|mode| &mode.ranges)
+        .map(|range| range.phases.len())
+        .sum();
+    println!(
+        "{} modes, {} phases ({} bytes)",
+        waveform.modes.len(),
+        phases,
+        phases * PHASE_SIZE
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ED047TC2_JSON: &str = include_str!("../../../waveforms/ed047tc2.json");
+    const ED047TC2_RS: &str = include_str!("../../../src/waveform/ed047tc2.rs");
+
+    #[test]
+    fn built_in_tables_are_up_to_date() {
+        let waveform = epdiy::parse(ED047TC2_JSON).unwrap();
+        let module = rust_module(&waveform, "ED047TC2", "waveforms/ed047tc2.json").unwrap();
+        assert!(
+            module == ED047TC2_RS,
+            "src/waveform/ed047tc2.rs is outdated, regenerate it from waveforms/ed047tc2.json"
+        );
+    }
+
+    #[test]
+    fn binary_layout() {
+        let waveform = epdiy::parse(ED047TC2_JSON).unwrap();
+        let data = binary(&waveform, 2).unwrap();
+        assert_eq!(&data[0..6], &[b'E', b'P', b'W', b'4', VERSION, 7]);
+        // the first range starts right after the header
+        let phases = waveform.mode(2).unwrap().ranges[0].phases.len();
+        assert_eq!(&data[8..12], &[15, 18, phases as u8, 0]);
+        let size = waveform
+            .mode(2)
+            .unwrap()
+            .ranges
+            .iter()
+            .map(|range| 4 + range.phases.len() * PHASE_SIZE);
+        assert_eq!(data.len(), 8 + size.sum::<usize>());
+        assert!(binary(&waveform, 3).is_err());
+    }
+
+    fn waveform_json(phase: &str, extra: &str) -> String {
+        format!(
+            r#"{{"temperature_ranges": {{"range_bounds": [0, 10, 20]}},
+                "modes": [{{"mode": 1, "ranges": [{{"index": 1, "phases": [{phase}]{extra}}}]}}]}}"#
+        )
+    }
+
+    fn phase(code: u8) -> String {
+        let row = format!("[{}]", vec![code.to_string(); 16].join(","));
+        format!("[{}]", vec![row; 16].join(","))
+    }
+
+    #[test]
+    fn packs_codes() {
+        let mut rows = vec![vec![0u8; 16]; 16];
+        rows[15][0] = 2;
+        rows[0][15] = 1;
+        let json = waveform_json(&serde_json::to_string(&rows).unwrap(), "");
+        let waveform = epdiy::parse(&json).unwrap();
+        let range = &waveform.modes[0].ranges[0];
+        assert_eq!((range.min, range.max), (10, 20));
+        assert_eq!(range.phases[0][3], 0x01);
+        assert_eq!(range.phases[0][60], 0x80);
+    }
+
+    #[test]
+    fn rejects_malformed_waveforms() {
+        // invalid code
+        assert!(epdiy::parse(&waveform_json(&phase(3), "")).is_err());
+        // too few levels
+        assert!(epdiy::parse(&waveform_json("[[0, 0]]", "")).is_err());
+        // missing phase time
+        assert!(epdiy::parse(&waveform_json(&phase(1), r#", "phase_times": []"#)).is_err());
+        assert!(epdiy::parse(&waveform_json(&phase(1), r#", "phase_times": [50]"#)).is_ok());
+        // temperature range out of bounds
+        let json = waveform_json(&phase(1), "").replace("\"index\": 1", "\"index\": 2");
+        assert!(epdiy::parse(&json).is_err());
+        assert!(epdiy::parse("{}").is_err());
+    }
+}